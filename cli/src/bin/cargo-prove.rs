@@ -2,8 +2,9 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use sp1_cli::{
     commands::{
-        build::BuildCmd, build_toolchain::BuildToolchainCmd,
-        install_toolchain::InstallToolchainCmd, new::NewCmd, prove::ProveCmd,
+        build::BuildCmd, build_toolchain::BuildToolchainCmd, debug::DebugCmd,
+        install_toolchain::InstallToolchainCmd, new::NewCmd, profile::ProfileCmd, prove::ProveCmd,
+        server::ServerCmd, worker::WorkerCmd,
     },
     SP1_VERSION_MESSAGE,
 };
@@ -29,8 +30,12 @@ pub enum ProveCliCommands {
     New(NewCmd),
     Build(BuildCmd),
     Prove(ProveCmd),
+    Debug(DebugCmd),
+    Profile(ProfileCmd),
     BuildToolchain(BuildToolchainCmd),
     InstallToolchain(InstallToolchainCmd),
+    Worker(WorkerCmd),
+    Server(ServerCmd),
 }
 
 fn main() -> Result<()> {
@@ -40,7 +45,11 @@ fn main() -> Result<()> {
         ProveCliCommands::New(cmd) => cmd.run(),
         ProveCliCommands::Build(cmd) => cmd.run(),
         ProveCliCommands::Prove(cmd) => cmd.run(),
+        ProveCliCommands::Debug(cmd) => cmd.run(),
+        ProveCliCommands::Profile(cmd) => cmd.run(),
         ProveCliCommands::BuildToolchain(cmd) => cmd.run(),
         ProveCliCommands::InstallToolchain(cmd) => cmd.run(),
+        ProveCliCommands::Worker(cmd) => cmd.run(),
+        ProveCliCommands::Server(cmd) => cmd.run(),
     }
 }
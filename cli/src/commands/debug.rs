@@ -0,0 +1,73 @@
+use anyhow::Result;
+use clap::Parser;
+use sp1_build::{build_program, BuildArgs};
+use sp1_core::{
+    runtime::{GdbStub, Program, Runtime},
+    utils::{setup_logger, SP1CoreOpts},
+};
+use sp1_prover::SP1Stdin;
+use std::{env, path::PathBuf};
+
+use super::prove::Input;
+
+#[derive(Parser)]
+#[command(
+    name = "debug",
+    about = "Build a program and serve it to a GDB remote debugger"
+)]
+pub struct DebugCmd {
+    #[clap(long, value_parser)]
+    input: Option<Input>,
+
+    #[clap(
+        long,
+        action,
+        help = "Debug an existing ELF instead of building the program."
+    )]
+    elf: Option<PathBuf>,
+
+    #[clap(
+        long,
+        help = "The address to listen on for the debugger.",
+        default_value = "127.0.0.1:9001"
+    )]
+    addr: String,
+
+    #[clap(flatten)]
+    build_args: BuildArgs,
+}
+
+impl DebugCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf_path = match self.elf {
+            Some(ref path) => path.clone(),
+            None => build_program(&self.build_args, None)?.into(),
+        };
+
+        if env::var("RUST_LOG").is_err() {
+            env::set_var("RUST_LOG", "info");
+        }
+        setup_logger();
+
+        let mut stdin = SP1Stdin::new();
+        if let Some(ref input) = self.input {
+            input.write_to(&mut stdin)?;
+        }
+
+        let program = Program::from_elf(elf_path.to_str().unwrap());
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        runtime.write_vecs(&stdin.buffer);
+        for (proof, vkey) in stdin.proofs.iter() {
+            runtime.write_proof(proof.clone(), vkey.clone());
+        }
+
+        println!(
+            "Attach with: riscv32-unknown-elf-gdb {} -ex \"target remote {}\"",
+            elf_path.display(),
+            self.addr
+        );
+        GdbStub::new(runtime).listen(&self.addr)?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
 pub mod build;
 pub mod build_toolchain;
+pub mod debug;
 pub mod install_toolchain;
 pub mod new;
+pub mod profile;
 pub mod prove;
+pub mod server;
+pub mod worker;
@@ -0,0 +1,71 @@
+use anyhow::Result;
+use clap::Parser;
+use sp1_build::{build_program, BuildArgs};
+use sp1_core::{runtime::ProfileFormat, utils::setup_logger};
+use sp1_prover::SP1Stdin;
+use sp1_sdk::action::Execute;
+use std::{env, fs, path::PathBuf};
+
+use super::prove::Input;
+
+#[derive(Parser)]
+#[command(
+    name = "profile",
+    about = "Build a program and profile the cycles spent in each function"
+)]
+pub struct ProfileCmd {
+    #[clap(long, value_parser)]
+    input: Option<Input>,
+
+    #[clap(
+        long,
+        action,
+        help = "Profile an existing ELF instead of building the program."
+    )]
+    elf: Option<PathBuf>,
+
+    #[clap(
+        long,
+        help = "The path to write the profile to.",
+        default_value = "profile.folded"
+    )]
+    output: PathBuf,
+
+    #[clap(
+        long,
+        help = "The format of the profile, either `folded` or `pprof`.",
+        default_value = "folded"
+    )]
+    format: ProfileFormat,
+
+    #[clap(flatten)]
+    build_args: BuildArgs,
+}
+
+impl ProfileCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf_path = match self.elf {
+            Some(ref path) => path.clone(),
+            None => build_program(&self.build_args, None)?.into(),
+        };
+
+        if env::var("RUST_LOG").is_err() {
+            env::set_var("RUST_LOG", "info");
+        }
+        setup_logger();
+
+        let elf = fs::read(elf_path)?;
+        let mut stdin = SP1Stdin::new();
+        if let Some(ref input) = self.input {
+            input.write_to(&mut stdin)?;
+        }
+
+        let (_, report) = Execute::new(&elf, stdin)
+            .profile(&self.output, self.format)
+            .run()?;
+        println!("{}", report);
+        println!("Wrote profile to {}", self.output.display());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use std::{env, path::PathBuf, sync::Arc};
+
+use anyhow::Result;
+use clap::Parser;
+use sp1_core::utils::setup_logger;
+use sp1_sdk::{network::server::ProverServer, LocalProver};
+
+#[derive(Parser)]
+#[command(
+    name = "server",
+    about = "Serve the prover network API, proving the requested proofs on this machine"
+)]
+pub struct ServerCmd {
+    #[clap(
+        long,
+        help = "The address to listen on.",
+        default_value = "127.0.0.1:3000"
+    )]
+    listen: String,
+
+    #[clap(
+        long,
+        help = "The directory storing the queue of proof requests.",
+        default_value = "sp1-server"
+    )]
+    dir: PathBuf,
+
+    #[clap(
+        long,
+        help = "The URL clients reach the server at, defaults to http://<listen>/."
+    )]
+    public_url: Option<String>,
+}
+
+impl ServerCmd {
+    pub fn run(&self) -> Result<()> {
+        if env::var("RUST_LOG").is_err() {
+            env::set_var("RUST_LOG", "info");
+        }
+        setup_logger();
+
+        let public_url = self
+            .public_url
+            .clone()
+            .unwrap_or_else(|| format!("http://{}/", self.listen));
+        let server = ProverServer::new(&self.dir, public_url.parse()?)?;
+
+        let rt = tokio::runtime::Runtime::new()?;
+        rt.block_on(async {
+            let listener = tokio::net::TcpListener::bind(&self.listen).await?;
+            println!("Listening for proof requests on {}", public_url);
+            server.serve(listener, Arc::new(LocalProver::new())).await
+        })
+    }
+}
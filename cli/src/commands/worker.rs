@@ -0,0 +1,51 @@
+use anyhow::Result;
+use clap::Parser;
+use sp1_core::{
+    distributed::{WorkerAddress, WorkerListener, WorkerSecret},
+    stark::{MachineProver, RiscvAir},
+    utils::setup_logger,
+};
+use sp1_prover::{
+    components::{DefaultProverComponents, SP1ProverComponents},
+    CoreSC,
+};
+use std::env;
+
+#[derive(Parser)]
+#[command(
+    name = "worker",
+    about = "Serve a coordinator the commitments and proofs of the shards it sends"
+)]
+pub struct WorkerCmd {
+    #[clap(
+        long,
+        help = "The address to listen on, either `tcp://<host>:<port>` or `unix://<path>`.",
+        default_value = "tcp://127.0.0.1:3100"
+    )]
+    listen: WorkerAddress,
+
+    #[clap(
+        long,
+        env = "SP1_WORKER_SECRET",
+        hide_env_values = true,
+        help = "The passphrase coordinators authenticate with."
+    )]
+    secret: WorkerSecret,
+}
+
+impl WorkerCmd {
+    pub fn run(&self) -> Result<()> {
+        if env::var("RUST_LOG").is_err() {
+            env::set_var("RUST_LOG", "info");
+        }
+        setup_logger();
+
+        let listener = WorkerListener::bind(&self.listen, self.secret.clone())?;
+        println!("Listening for shards on {}", listener.address());
+        let machine = RiscvAir::machine(CoreSC::default());
+        let prover = <DefaultProverComponents as SP1ProverComponents>::CoreProver::new(machine);
+        listener.serve(&prover)?;
+
+        Ok(())
+    }
+}
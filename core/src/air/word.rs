@@ -15,6 +15,9 @@ use super::SP1AirBuilder;
 /// The size of a word in bytes.
 pub const WORD_SIZE: usize = 4;
 
+/// The size of a 64-bit value in bytes.
+pub const U64_SIZE: usize = 2 * WORD_SIZE;
+
 /// A word is a 32-bit value represented in an AIR.
 #[derive(
     AlignedBorrow, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize,
@@ -83,6 +86,17 @@ impl<V: Copy> Word<V> {
     }
 }
 
+/// Concatenates the limbs of the low and high words of a 64-bit value stored in memory.
+pub fn u64_limbs<T: Copy>(lo: Word<T>, hi: Word<T>) -> [T; U64_SIZE] {
+    core::array::from_fn(|i| {
+        if i < WORD_SIZE {
+            lo[i]
+        } else {
+            hi[i - WORD_SIZE]
+        }
+    })
+}
+
 impl<T> Index<usize> for Word<T> {
     type Output = T;
 
@@ -5,10 +5,7 @@ use std::borrow::BorrowMut;
 
 use p3_field::{PrimeField, PrimeField32};
 use p3_matrix::dense::RowMajorMatrix;
-use p3_maybe_rayon::prelude::IntoParallelRefMutIterator;
-use p3_maybe_rayon::prelude::ParallelBridge;
-use p3_maybe_rayon::prelude::ParallelIterator;
-use p3_maybe_rayon::prelude::ParallelSlice;
+use p3_maybe_rayon::prelude::*;
 use tracing::instrument;
 
 use super::columns::{CPU_COL_MAP, NUM_CPU_COLS};
@@ -44,9 +41,8 @@ impl<F: PrimeField32> MachineAir<F> for CpuChip {
 
         let chunk_size = std::cmp::max(input.cpu_events.len() / num_cpus::get(), 1);
         values
-            .chunks_mut(chunk_size * NUM_CPU_COLS)
+            .par_chunks_mut(chunk_size * NUM_CPU_COLS)
             .enumerate()
-            .par_bridge()
             .for_each(|(i, rows)| {
                 rows.chunks_mut(NUM_CPU_COLS)
                     .enumerate()
@@ -1,10 +1,12 @@
 mod elf;
 mod instruction;
+mod symbols;
 
 pub use elf::*;
 pub use instruction::*;
+pub use symbols::*;
 
-use std::{collections::BTreeMap, fs::File, io::Read};
+use std::{collections::BTreeMap, fs::File, io::Read, sync::Arc};
 
 use crate::runtime::{Instruction, Program};
 
@@ -16,6 +18,7 @@ impl Program {
             pc_start,
             pc_base,
             memory_image: BTreeMap::new(),
+            symbols: None,
         }
     }
 
@@ -33,6 +36,7 @@ impl Program {
             pc_start: elf.pc_start,
             pc_base: elf.pc_base,
             memory_image: elf.memory_image,
+            symbols: Some(Arc::new(SymbolTable::from_elf(input))),
         }
     }
 
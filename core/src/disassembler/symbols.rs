@@ -0,0 +1,117 @@
+use elf::abi::STT_FUNC;
+use elf::endian::LittleEndian;
+use elf::ElfBytes;
+use serde::{Deserialize, Serialize};
+
+/// A function symbol from the symbol table of an ELF.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Symbol {
+    /// The demangled name of the function.
+    pub name: String,
+
+    /// The address of the first instruction of the function.
+    pub address: u32,
+
+    /// The size of the function in bytes.
+    pub size: u32,
+}
+
+/// The function symbols of an ELF, sorted by address.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SymbolTable {
+    pub symbols: Vec<Symbol>,
+}
+
+impl SymbolTable {
+    /// Parse the function symbols from an ELF.
+    ///
+    /// Returns an empty table if the ELF cannot be parsed or has been stripped.
+    pub fn from_elf(input: &[u8]) -> Self {
+        let Ok(elf) = ElfBytes::<LittleEndian>::minimal_parse(input) else {
+            return Self::default();
+        };
+        let Ok(Some((symtab, strtab))) = elf.symbol_table() else {
+            return Self::default();
+        };
+
+        let mut symbols = symtab
+            .iter()
+            .filter(|symbol| symbol.st_symtype() == STT_FUNC && symbol.st_value != 0)
+            .filter_map(|symbol| {
+                let name = strtab.get(symbol.st_name as usize).ok()?;
+                Some(Symbol {
+                    name: format!("{:#}", rustc_demangle::demangle(name)),
+                    address: symbol.st_value.try_into().ok()?,
+                    size: symbol.st_size.try_into().ok()?,
+                })
+            })
+            .collect::<Vec<_>>();
+        symbols.sort_by_key(|symbol| symbol.address);
+        symbols.dedup_by_key(|symbol| symbol.address);
+
+        Self { symbols }
+    }
+
+    /// Find the index of the function containing the given address.
+    pub fn lookup(&self, address: u32) -> Option<usize> {
+        let idx = self
+            .symbols
+            .partition_point(|symbol| symbol.address <= address)
+            .checked_sub(1)?;
+        let symbol = &self.symbols[idx];
+        // Symbols without a size are assumed to extend up to the next symbol.
+        if symbol.size == 0 || address - symbol.address < symbol.size {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+
+    /// Find the name of the function containing the given address.
+    pub fn name(&self, address: u32) -> Option<&str> {
+        self.lookup(address)
+            .map(|idx| self.symbols[idx].name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Symbol, SymbolTable};
+    use crate::utils::tests::FIBONACCI_ELF;
+
+    #[test]
+    fn test_lookup() {
+        let table = SymbolTable {
+            symbols: vec![
+                Symbol {
+                    name: "a".to_string(),
+                    address: 0x100,
+                    size: 0x10,
+                },
+                Symbol {
+                    name: "b".to_string(),
+                    address: 0x120,
+                    size: 0,
+                },
+            ],
+        };
+        assert_eq!(table.name(0xfc), None);
+        assert_eq!(table.name(0x100), Some("a"));
+        assert_eq!(table.name(0x10c), Some("a"));
+        assert_eq!(table.name(0x110), None);
+        assert_eq!(table.name(0x200), Some("b"));
+    }
+
+    #[test]
+    fn test_from_elf() {
+        let table = SymbolTable::from_elf(FIBONACCI_ELF);
+        assert!(table
+            .symbols
+            .iter()
+            .any(|symbol| symbol.name.ends_with("main")));
+        assert!(table
+            .symbols
+            .windows(2)
+            .all(|pair| pair[0].address < pair[1].address));
+    }
+}
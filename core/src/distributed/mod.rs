@@ -0,0 +1,453 @@
+//! Proving the shards of a program on worker processes.
+//!
+//! The coordinator executes the program and traces the checkpoints into execution records as
+//! usual, but instead of committing to and proving the shards itself, it hands them out to
+//! workers listening on TCP or Unix sockets with a [WorkerPool]. Shards with cpu events are sent
+//! as the checkpoint they were traced from, which the workers re-execute to reconstruct their
+//! records, while shards of deferred events are sent as records. Workers, started with
+//! [WorkerListener::serve], generate the traces of the shards and commit to or prove them, and
+//! the coordinator assembles the shard proofs into the final proof.
+//!
+//! The coordinator and the workers authenticate each other with a [WorkerSecret] they share
+//! before exchanging any message.
+
+mod pool;
+mod worker;
+
+pub use pool::*;
+pub use worker::*;
+
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::{self, BufReader, BufWriter, Read, Write},
+    net::TcpStream,
+    path::PathBuf,
+    str::FromStr,
+};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    air::PublicValues,
+    runtime::{ExecutionRecord, ExecutionState, Program},
+    stark::{Com, ShardProof, StarkGenericConfig, Val},
+    utils::SP1CoreOpts,
+};
+
+/// The maximum size of a message, bounding the memory a peer can make the other end allocate.
+const MAX_MESSAGE_SIZE: u64 = 1 << 31;
+
+/// The context of the key derived from the passphrase of a [WorkerSecret].
+const SECRET_KEY_CONTEXT: &str = "sp1-core 2024-07-01 distributed proving worker secret";
+
+#[derive(Error, Debug)]
+pub enum WorkerError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] bincode::Error),
+    #[error("worker failed: {0}")]
+    Remote(String),
+    #[error("unexpected response from worker")]
+    UnexpectedResponse,
+    #[error("all workers failed")]
+    AllWorkersFailed,
+    #[error("invalid worker address {0}")]
+    InvalidAddress(String),
+    #[error("peer failed to authenticate")]
+    AuthenticationFailed,
+    #[error("no secret to authenticate to the workers with")]
+    MissingSecret,
+}
+
+/// The address of a worker, either `tcp://<host>:<port>` (or just `<host>:<port>`) or
+/// `unix://<path>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkerAddress {
+    Tcp(String),
+    Unix(PathBuf),
+}
+
+impl FromStr for WorkerAddress {
+    type Err = WorkerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(path) = s.strip_prefix("unix://") {
+            Ok(Self::Unix(path.into()))
+        } else {
+            let address = s.strip_prefix("tcp://").unwrap_or(s);
+            if address.rsplit_once(':').is_none() {
+                return Err(WorkerError::InvalidAddress(s.to_string()));
+            }
+            Ok(Self::Tcp(address.to_string()))
+        }
+    }
+}
+
+impl Display for WorkerAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Tcp(address) => write!(f, "tcp://{}", address),
+            Self::Unix(path) => write!(f, "unix://{}", path.display()),
+        }
+    }
+}
+
+impl WorkerAddress {
+    /// Connect to the worker at this address.
+    fn connect(&self) -> io::Result<Connection> {
+        let (reader, writer): (Box<dyn Read + Send>, Box<dyn Write + Send>) = match self {
+            Self::Tcp(address) => {
+                let stream = TcpStream::connect(address)?;
+                stream.set_nodelay(true)?;
+                (Box::new(stream.try_clone()?), Box::new(stream))
+            }
+            #[cfg(unix)]
+            Self::Unix(path) => {
+                let stream = UnixStream::connect(path)?;
+                (Box::new(stream.try_clone()?), Box::new(stream))
+            }
+            #[cfg(not(unix))]
+            Self::Unix(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "unix sockets are not supported on this platform",
+                ))
+            }
+        };
+        Ok(Connection::new(reader, writer))
+    }
+}
+
+/// The secret shared by the coordinator and its workers to authenticate each other, derived from
+/// a passphrase.
+///
+/// Each end of a connection proves it knows the secret by keyed hashing a random challenge of
+/// the other end, so the secret itself is never sent. Messages are not encrypted, so connections
+/// between hosts should still go through a private network or a tunnel.
+#[derive(Clone, PartialEq, Eq)]
+pub struct WorkerSecret([u8; 32]);
+
+impl WorkerSecret {
+    /// Derive the secret from `passphrase`.
+    pub fn new(passphrase: impl AsRef<[u8]>) -> Self {
+        Self(blake3::derive_key(SECRET_KEY_CONTEXT, passphrase.as_ref()))
+    }
+
+    /// The response of the coordinator, or of a worker if `worker` is set, to `challenge`.
+    fn respond(&self, worker: bool, challenge: &[u8; 32]) -> blake3::Hash {
+        let mut hasher = blake3::Hasher::new_keyed(&self.0);
+        hasher.update(&[worker as u8]);
+        hasher.update(challenge);
+        hasher.finalize()
+    }
+}
+
+impl FromStr for WorkerSecret {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl Debug for WorkerSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("WorkerSecret(..)")
+    }
+}
+
+/// Shards the coordinator sends a worker to commit to or prove.
+///
+/// Only a few of them exist at a time, so the size of the state inline is not worth boxing it.
+#[derive(Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+enum Shards<'a> {
+    /// Shards of a batch the worker traces by re-executing the program from the state of the
+    /// checkpoint the batch starts at, given by their index in the batch and their public values.
+    Checkpoint {
+        state: Cow<'a, ExecutionState>,
+        shards: Vec<(usize, PublicValues<u32, u32>)>,
+    },
+
+    /// A shard of events deferred from the other shards, which cannot be re-executed.
+    Deferred(Box<ExecutionRecord>),
+}
+
+/// A request from the coordinator to a worker.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+enum Request<'a, SC: StarkGenericConfig> {
+    /// Set up the proving key of the program the shards belong to, and the options to execute it
+    /// with.
+    Setup(Cow<'a, Program>, SP1CoreOpts),
+
+    /// Commit to the main traces of shards.
+    Commit(Shards<'a>),
+
+    /// Set the state of the challenger to the one after observing the commitments and public
+    /// values of every shard.
+    Observe(Cow<'a, [(Com<SC>, Vec<Val<SC>>)]>),
+
+    /// Commit to and prove shards.
+    Prove(Shards<'a>),
+}
+
+impl<'a, SC: StarkGenericConfig> Request<'a, SC> {
+    /// The number of shards to commit to or prove for the request.
+    fn num_shards(&self) -> usize {
+        match self {
+            Self::Commit(shards) | Self::Prove(shards) => shards.len(),
+            Self::Setup(..) | Self::Observe(_) => 0,
+        }
+    }
+}
+
+impl<'a> Shards<'a> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Checkpoint { shards, .. } => shards.len(),
+            Self::Deferred(_) => 1,
+        }
+    }
+}
+
+/// The response of a worker to a [Request].
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+enum Response<SC: StarkGenericConfig> {
+    Ready,
+    Commitments(Vec<Com<SC>>),
+    ShardProofs(Vec<ShardProof<SC>>),
+    Error(String),
+}
+
+/// A connection between the coordinator and a worker, exchanging bincode-encoded messages.
+struct Connection {
+    reader: BufReader<Box<dyn Read + Send>>,
+    writer: BufWriter<Box<dyn Write + Send>>,
+}
+
+impl Connection {
+    fn new(reader: Box<dyn Read + Send>, writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            writer: BufWriter::new(writer),
+        }
+    }
+
+    /// Authenticate the other end of the connection with `secret`, as a worker if `worker` is
+    /// set and as the coordinator otherwise, proving it knows the secret as well.
+    fn authenticate(&mut self, secret: &WorkerSecret, worker: bool) -> Result<(), WorkerError> {
+        let challenge = rand::random::<[u8; 32]>();
+        self.writer.write_all(&challenge)?;
+        self.writer.flush()?;
+        let mut peer_challenge = [0u8; 32];
+        self.reader.read_exact(&mut peer_challenge)?;
+
+        self.writer
+            .write_all(secret.respond(worker, &peer_challenge).as_bytes())?;
+        self.writer.flush()?;
+        let mut peer_response = [0u8; 32];
+        self.reader.read_exact(&mut peer_response)?;
+
+        // Comparing hashes takes constant time.
+        if blake3::Hash::from(peer_response) != secret.respond(!worker, &challenge) {
+            return Err(WorkerError::AuthenticationFailed);
+        }
+        Ok(())
+    }
+
+    fn send<T: Serialize>(&mut self, message: &T) -> Result<(), WorkerError> {
+        options().serialize_into(&mut self.writer, message)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Receive a message, or `None` if the other end closed the connection.
+    fn receive<T: for<'de> Deserialize<'de>>(&mut self) -> Result<Option<T>, WorkerError> {
+        match options().deserialize_from(&mut self.reader) {
+            Ok(message) => Ok(Some(message)),
+            Err(e) => match *e {
+                bincode::ErrorKind::Io(ref io_error)
+                    if io_error.kind() == io::ErrorKind::UnexpectedEof =>
+                {
+                    Ok(None)
+                }
+                _ => Err(e.into()),
+            },
+        }
+    }
+}
+
+/// The bincode options of the messages, limiting their size to [MAX_MESSAGE_SIZE].
+fn options() -> impl Options {
+    bincode::options().with_limit(MAX_MESSAGE_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        io::{BufRead, BufReader},
+        process::{Child, Command, Stdio},
+    };
+
+    use bincode::Options;
+
+    use super::{options, WorkerAddress, WorkerError, WorkerListener, WorkerPool, WorkerSecret};
+    use crate::runtime::tests::fibonacci_program;
+    use crate::runtime::SP1Context;
+    use crate::stark::{DefaultProver, MachineProver, RiscvAir, StarkGenericConfig};
+    use crate::utils::{prove_with_context, BabyBearPoseidon2, SP1CoreOpts};
+
+    /// The environment variable with the address a worker process listens on.
+    const WORKER_ADDRESS_VAR: &str = "SP1_TEST_WORKER_ADDRESS";
+
+    /// The passphrase of the secret of the worker processes.
+    const PASSPHRASE: &str = "test passphrase";
+
+    /// The line a worker process prints once it listens, followed by its address.
+    const LISTENING: &str = "worker listening on ";
+
+    #[test]
+    fn test_worker_address() {
+        assert_eq!(
+            "tcp://127.0.0.1:9000".parse::<WorkerAddress>().unwrap(),
+            WorkerAddress::Tcp("127.0.0.1:9000".to_string())
+        );
+        assert_eq!(
+            "localhost:9000".parse::<WorkerAddress>().unwrap(),
+            WorkerAddress::Tcp("localhost:9000".to_string())
+        );
+        assert_eq!(
+            "unix:///tmp/worker.sock".parse::<WorkerAddress>().unwrap(),
+            WorkerAddress::Unix("/tmp/worker.sock".into())
+        );
+        assert!("localhost".parse::<WorkerAddress>().is_err());
+        assert_eq!(
+            WorkerAddress::Unix("/tmp/worker.sock".into()).to_string(),
+            "unix:///tmp/worker.sock"
+        );
+    }
+
+    #[test]
+    fn test_message_size_limit() {
+        // A string claiming to be larger than the limit is rejected before allocating it.
+        let message = options().serialize(&u64::MAX).unwrap();
+        let result = options().deserialize_from::<_, String>(&message[..]);
+        assert!(matches!(
+            result.map_err(|e| *e),
+            Err(bincode::ErrorKind::SizeLimit)
+        ));
+    }
+
+    /// The worker processes started by the other tests, serving until they're killed.
+    #[test]
+    #[ignore = "only run as a worker process by the distributed proving tests"]
+    fn worker_process() {
+        let Ok(address) = env::var(WORKER_ADDRESS_VAR) else {
+            return;
+        };
+        let secret = WorkerSecret::new(PASSPHRASE);
+        let listener = WorkerListener::bind(&address.parse().unwrap(), secret).unwrap();
+        println!("{}{}", LISTENING, listener.address());
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let prover = DefaultProver::new(machine);
+        listener.serve(&prover).unwrap();
+    }
+
+    /// A worker process started from the test binary, killed when dropped.
+    struct WorkerProcess {
+        child: Child,
+        address: WorkerAddress,
+    }
+
+    impl WorkerProcess {
+        /// Start a worker process listening on `address`, and wait for it to listen.
+        fn spawn(address: &str) -> Self {
+            let mut child = Command::new(env::current_exe().unwrap())
+                .args([
+                    "distributed::tests::worker_process",
+                    "--exact",
+                    "--ignored",
+                    "--nocapture",
+                ])
+                .env(WORKER_ADDRESS_VAR, address)
+                .stdout(Stdio::piped())
+                .spawn()
+                .unwrap();
+            let stdout = BufReader::new(child.stdout.take().unwrap());
+            let address = stdout
+                .lines()
+                .map(Result::unwrap)
+                .find_map(|line| Some(line.split_once(LISTENING)?.1.parse().unwrap()))
+                .expect("the worker process exited before listening");
+            Self { child, address }
+        }
+    }
+
+    impl Drop for WorkerProcess {
+        fn drop(&mut self) {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+
+    #[test]
+    fn test_distributed_prove() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("worker.sock");
+        let workers = [
+            WorkerProcess::spawn("127.0.0.1:0"),
+            WorkerProcess::spawn(&format!("unix://{}", socket.display())),
+        ];
+
+        // Split the execution into several batches of several shards, so that the workers
+        // re-execute checkpoints in the middle of the program.
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 10;
+        opts.shard_batch_size = 2;
+
+        let addresses = workers.iter().map(|w| w.address.clone()).collect();
+        let context = SP1Context::builder()
+            .workers(addresses, WorkerSecret::new(PASSPHRASE))
+            .build();
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let prover = DefaultProver::new(machine);
+        let (proof, _, _) = prove_with_context::<_, _>(
+            &prover,
+            fibonacci_program(),
+            &Default::default(),
+            opts,
+            context,
+        )
+        .unwrap();
+        assert!(proof.shard_proofs.len() > 2 * opts.shard_batch_size);
+
+        let (_, vk) = prover.setup(&fibonacci_program());
+        let mut challenger = prover.config().challenger();
+        prover
+            .machine()
+            .verify(&vk, &proof, &mut challenger)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_authentication() {
+        let worker = WorkerProcess::spawn("127.0.0.1:0");
+        let result = WorkerPool::<BabyBearPoseidon2>::connect(
+            &[worker.address.clone()],
+            &WorkerSecret::new("wrong passphrase"),
+            &fibonacci_program(),
+            SP1CoreOpts::default(),
+        );
+        assert!(matches!(result, Err(WorkerError::AuthenticationFailed)));
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use super::{Connection, Request, Response, Shards, WorkerAddress, WorkerError, WorkerSecret};
+use crate::{
+    air::PublicValues,
+    runtime::{ExecutionRecord, ExecutionState, Program},
+    stark::{Com, OpeningProof, ShardProof, StarkGenericConfig, Val},
+    utils::SP1CoreOpts,
+};
+
+/// A batch of shards traced from a checkpoint, followed by shards of events deferred from them.
+pub struct ShardBatch<'a> {
+    /// The state of the checkpoint the batch was traced from.
+    pub checkpoint: &'a ExecutionState,
+
+    /// The shards traced from the checkpoint, by index in the batch, with their public values.
+    pub shards: Vec<(usize, PublicValues<u32, u32>)>,
+
+    /// The shards of deferred events.
+    pub deferred: Vec<ExecutionRecord>,
+}
+
+/// A worker connected to the coordinator.
+struct Worker {
+    address: WorkerAddress,
+    connection: Connection,
+    failed: bool,
+}
+
+impl Worker {
+    fn call<SC: StarkGenericConfig>(
+        &mut self,
+        request: &Request<SC>,
+    ) -> Result<Response<SC>, WorkerError> {
+        self.connection.send(request)?;
+        match self.connection.receive()? {
+            Some(Response::Error(message)) => Err(WorkerError::Remote(message)),
+            Some(response) => Ok(response),
+            None => Err(WorkerError::Io(std::io::ErrorKind::UnexpectedEof.into())),
+        }
+    }
+
+    fn fail(&mut self, error: WorkerError) {
+        tracing::warn!("worker {} failed: {}", self.address, error);
+        self.failed = true;
+    }
+}
+
+/// The workers committing to and proving the shards of a program for the coordinator.
+///
+/// Shards are handed out to the workers as they become idle. If a worker fails, e.g. because
+/// its connection dropped, its shards are handed out to the remaining workers.
+pub struct WorkerPool<SC: StarkGenericConfig> {
+    workers: Vec<Mutex<Worker>>,
+    _marker: std::marker::PhantomData<SC>,
+}
+
+impl<SC: StarkGenericConfig> WorkerPool<SC>
+where
+    Com<SC>: Send + Sync,
+    OpeningProof<SC>: Send,
+{
+    /// Connect to the workers at `addresses`, authenticating with `secret`, and set them up to
+    /// prove the shards of `program` executed with `opts`.
+    pub fn connect(
+        addresses: &[WorkerAddress],
+        secret: &WorkerSecret,
+        program: &Program,
+        opts: SP1CoreOpts,
+    ) -> Result<Self, WorkerError> {
+        let workers = addresses
+            .iter()
+            .map(|address| {
+                let mut connection = address.connect()?;
+                connection.authenticate(secret, false)?;
+                let mut worker = Worker {
+                    address: address.clone(),
+                    connection,
+                    failed: false,
+                };
+                worker.call::<SC>(&Request::Setup(Cow::Borrowed(program), opts))?;
+                Ok(Mutex::new(worker))
+            })
+            .collect::<Result<Vec<_>, WorkerError>>()?;
+        Ok(Self {
+            workers,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Commit to the main traces of the shards of `batch`, in order.
+    pub fn commit(&self, batch: ShardBatch) -> Result<Vec<Com<SC>>, WorkerError> {
+        let requests = self
+            .split(batch)
+            .into_iter()
+            .map(Request::Commit)
+            .collect::<Vec<_>>();
+        self.dispatch(&requests, |response| match response {
+            Response::Commitments(commitments) => Some(commitments),
+            _ => None,
+        })
+    }
+
+    /// Set the state of the challenger of the workers to the one after observing the
+    /// commitments and public values of every shard, before proving them.
+    pub fn observe(&self, observations: &[(Com<SC>, Vec<Val<SC>>)]) -> Result<(), WorkerError> {
+        let request = Request::<SC>::Observe(Cow::Borrowed(observations));
+        for worker in self.workers.iter() {
+            let mut worker = worker.lock().unwrap();
+            if !worker.failed {
+                if let Err(e) = worker.call(&request) {
+                    worker.fail(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Prove the shards of `batch`, in order.
+    pub fn prove(&self, batch: ShardBatch) -> Result<Vec<ShardProof<SC>>, WorkerError> {
+        let requests = self
+            .split(batch)
+            .into_iter()
+            .map(Request::Prove)
+            .collect::<Vec<_>>();
+        self.dispatch(&requests, |response| match response {
+            Response::ShardProofs(proofs) => Some(proofs),
+            _ => None,
+        })
+    }
+
+    /// Split `batch` into the shards to send in each request.
+    ///
+    /// The shards traced from the checkpoint are split evenly between the workers, each of them
+    /// re-executing the whole batch but only generating the traces of its own shards. The
+    /// program of each deferred shard is cleared before shipping it, since the workers already
+    /// have it.
+    fn split<'a>(&self, batch: ShardBatch<'a>) -> Vec<Shards<'a>> {
+        let nb_workers = self
+            .workers
+            .iter()
+            .filter(|w| !w.lock().unwrap().failed)
+            .count()
+            .max(1);
+        let chunk_size = batch.shards.len().div_ceil(nb_workers).max(1);
+        let empty = Arc::new(Program::default());
+        batch
+            .shards
+            .chunks(chunk_size)
+            .map(|shards| Shards::Checkpoint {
+                state: Cow::Borrowed(batch.checkpoint),
+                shards: shards.to_vec(),
+            })
+            .chain(batch.deferred.into_iter().map(|mut record| {
+                record.program = empty.clone();
+                Shards::Deferred(Box::new(record))
+            }))
+            .collect()
+    }
+
+    /// Send each request to an idle worker and collect the responses in order.
+    fn dispatch<T: Send>(
+        &self,
+        requests: &[Request<SC>],
+        extract: impl Fn(Response<SC>) -> Option<Vec<T>> + Sync,
+    ) -> Result<Vec<T>, WorkerError> {
+        let queue = Mutex::new((0..requests.len()).collect::<VecDeque<_>>());
+        let results = Mutex::new((0..requests.len()).map(|_| None).collect::<Vec<_>>());
+        let error = Mutex::new(None);
+
+        // Requests of workers failing after the others went idle are left in the queue, so
+        // hand them out again until they're all done or no worker is left.
+        while !queue.lock().unwrap().is_empty() {
+            if self.workers.iter().all(|w| w.lock().unwrap().failed) {
+                return Err(WorkerError::AllWorkersFailed);
+            }
+            std::thread::scope(|s| {
+                for worker in self.workers.iter() {
+                    s.spawn(|| {
+                        let mut worker = worker.lock().unwrap();
+                        while !worker.failed && error.lock().unwrap().is_none() {
+                            let Some(i) = queue.lock().unwrap().pop_front() else {
+                                break;
+                            };
+                            let expected = requests[i].num_shards();
+                            let result = worker.call(&requests[i]).map(|response| {
+                                extract(response).filter(|result| result.len() == expected)
+                            });
+                            match result {
+                                Ok(Some(result)) => results.lock().unwrap()[i] = Some(result),
+                                Ok(None) => {
+                                    *error.lock().unwrap() = Some(WorkerError::UnexpectedResponse)
+                                }
+                                // A worker failing to prove is not recoverable.
+                                Err(e @ WorkerError::Remote(_)) => *error.lock().unwrap() = Some(e),
+                                Err(e) => {
+                                    queue.lock().unwrap().push_back(i);
+                                    worker.fail(e);
+                                }
+                            }
+                        }
+                    });
+                }
+            });
+            if let Some(e) = error.lock().unwrap().take() {
+                return Err(e);
+            }
+        }
+
+        Ok(results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .flat_map(Option::unwrap)
+            .collect())
+    }
+}
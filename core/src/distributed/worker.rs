@@ -0,0 +1,212 @@
+use std::{io, net::TcpListener, sync::Arc};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+
+use p3_field::PrimeField32;
+
+use super::{Connection, Request, Response, Shards, WorkerAddress, WorkerError, WorkerSecret};
+use crate::{
+    runtime::{ExecutionRecord, Program},
+    stark::{MachineProver, RiscvAir, StarkGenericConfig, StarkProvingKey},
+    utils::{trace_state, SP1CoreOpts},
+};
+
+/// A worker listening for a coordinator to connect and send it shards to commit to and prove.
+pub struct WorkerListener {
+    address: WorkerAddress,
+    listener: Listener,
+    secret: WorkerSecret,
+}
+
+enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+impl WorkerListener {
+    /// Listen on `address`, only serving coordinators authenticating with `secret`.
+    ///
+    /// A TCP address with port 0 is assigned a free port, see [WorkerListener::address].
+    pub fn bind(address: &WorkerAddress, secret: WorkerSecret) -> Result<Self, WorkerError> {
+        let (address, listener) = match address {
+            WorkerAddress::Tcp(address) => {
+                let listener = TcpListener::bind(address)?;
+                let address = WorkerAddress::Tcp(listener.local_addr()?.to_string());
+                (address, Listener::Tcp(listener))
+            }
+            #[cfg(unix)]
+            WorkerAddress::Unix(path) => {
+                (address.clone(), Listener::Unix(UnixListener::bind(path)?))
+            }
+            #[cfg(not(unix))]
+            WorkerAddress::Unix(_) => {
+                return Err(WorkerError::Io(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "unix sockets are not supported on this platform",
+                )))
+            }
+        };
+        Ok(Self {
+            address,
+            listener,
+            secret,
+        })
+    }
+
+    /// The address the worker listens on.
+    pub fn address(&self) -> &WorkerAddress {
+        &self.address
+    }
+
+    /// Serve the coordinators connecting to the worker with `prover`, each in its own thread.
+    ///
+    /// Only returns if accepting a connection fails.
+    pub fn serve<SC, P>(&self, prover: &P) -> Result<(), WorkerError>
+    where
+        SC: StarkGenericConfig,
+        SC::Val: PrimeField32,
+        SC::Challenger: Clone,
+        P: MachineProver<SC, RiscvAir<SC::Val>>,
+    {
+        tracing::info!("listening for shards on {}", self.address);
+        std::thread::scope(|s| loop {
+            let connection = self.accept()?;
+            s.spawn(|| {
+                if let Err(e) = handle_connection::<SC, P>(prover, &self.secret, connection) {
+                    tracing::warn!("connection to coordinator failed: {}", e);
+                }
+            });
+        })
+    }
+
+    fn accept(&self) -> io::Result<Connection> {
+        Ok(match self.listener {
+            Listener::Tcp(ref listener) => {
+                let (stream, _) = listener.accept()?;
+                stream.set_nodelay(true)?;
+                Connection::new(Box::new(stream.try_clone()?), Box::new(stream))
+            }
+            #[cfg(unix)]
+            Listener::Unix(ref listener) => {
+                let (stream, _) = listener.accept()?;
+                Connection::new(Box::new(stream.try_clone()?), Box::new(stream))
+            }
+        })
+    }
+}
+
+/// The program the shards of a coordinator belong to, set up by [Request::Setup].
+struct Setup<SC: StarkGenericConfig> {
+    program: Arc<Program>,
+    opts: SP1CoreOpts,
+    pk: StarkProvingKey<SC>,
+    challenger: Option<SC::Challenger>,
+}
+
+impl<SC: StarkGenericConfig> Setup<SC> {
+    /// Reconstruct the records of `shards`, re-executing the program if they were traced from a
+    /// checkpoint.
+    fn records<P>(&self, prover: &P, shards: Shards) -> Result<Vec<ExecutionRecord>, String>
+    where
+        SC::Val: PrimeField32,
+        P: MachineProver<SC, RiscvAir<SC::Val>>,
+    {
+        let (state, shards) = match shards {
+            Shards::Checkpoint { state, shards } => (state, shards),
+            Shards::Deferred(mut record) => {
+                record.program = self.program.clone();
+                return Ok(vec![*record]);
+            }
+        };
+        let (mut traced, _) =
+            trace_state(Program::clone(&self.program), state.into_owned(), self.opts)
+                .map_err(|e| e.to_string())?;
+
+        // Process the records the same way the coordinator did before sending the shards.
+        let mut records = shards
+            .into_iter()
+            .map(|(index, public_values)| {
+                let record = traced
+                    .get_mut(index)
+                    .ok_or_else(|| format!("the batch has no shard {}", index))?;
+                let mut record = std::mem::take(record);
+                record.public_values = public_values;
+                Ok(record)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        prover
+            .machine()
+            .generate_dependencies(&mut records, &self.opts);
+        for record in records.iter_mut() {
+            record.defer();
+        }
+        Ok(records)
+    }
+}
+
+/// Authenticate a coordinator with `secret` and handle its requests until it disconnects.
+fn handle_connection<SC, P>(
+    prover: &P,
+    secret: &WorkerSecret,
+    mut connection: Connection,
+) -> Result<(), WorkerError>
+where
+    SC: StarkGenericConfig,
+    SC::Val: PrimeField32,
+    SC::Challenger: Clone,
+    P: MachineProver<SC, RiscvAir<SC::Val>>,
+{
+    connection.authenticate(secret, true)?;
+    let mut setup: Option<Setup<SC>> = None;
+    while let Some(request) = connection.receive::<Request<SC>>()? {
+        let response = match (request, &mut setup) {
+            (Request::Setup(program, opts), _) => {
+                let program = Arc::new(program.into_owned());
+                let (pk, _) = prover.setup(&program);
+                setup = Some(Setup {
+                    program,
+                    opts,
+                    pk,
+                    challenger: None,
+                });
+                Response::Ready
+            }
+            (Request::Commit(shards), Some(setup)) => match setup.records(prover, shards) {
+                Ok(records) => Response::Commitments(
+                    records.iter().map(|record| prover.commit(record)).collect(),
+                ),
+                Err(e) => Response::Error(e),
+            },
+            (Request::Observe(observations), Some(setup)) => {
+                let mut challenger = prover.config().challenger();
+                setup.pk.observe_into(&mut challenger);
+                for (commitment, public_values) in observations.iter() {
+                    prover.update(&mut challenger, commitment.clone(), public_values);
+                }
+                setup.challenger = Some(challenger);
+                Response::Ready
+            }
+            (Request::Prove(shards), Some(setup)) => match setup.challenger {
+                Some(ref challenger) => setup
+                    .records(prover, shards)
+                    .and_then(|records| {
+                        records
+                            .into_iter()
+                            .map(|record| {
+                                prover
+                                    .commit_and_open(&setup.pk, record, &mut challenger.clone())
+                                    .map_err(|e| e.to_string())
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .map_or_else(Response::Error, Response::ShardProofs),
+                None => Response::Error("the challenger was not set".to_string()),
+            },
+            (_, None) => Response::Error("the program was not set up".to_string()),
+        };
+        connection.send(&response)?;
+    }
+    Ok(())
+}
@@ -18,6 +18,7 @@ pub mod alu;
 pub mod bytes;
 pub mod cpu;
 pub mod disassembler;
+pub mod distributed;
 pub mod io;
 pub mod lookup;
 pub mod memory;
@@ -0,0 +1,93 @@
+use crate::air::SP1AirBuilder;
+use crate::air::U64_SIZE;
+use crate::bytes::event::ByteRecord;
+
+use p3_air::AirBuilder;
+use p3_field::AbstractField;
+use p3_field::Field;
+use sp1_derive::AlignedBorrow;
+
+/// A set of columns needed to compute the add of two 64-bit values.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct AddU64Operation<T> {
+    /// The result of `a + b`.
+    pub value: [T; U64_SIZE],
+
+    /// Trace.
+    pub carry: [T; U64_SIZE - 1],
+}
+
+impl<F: Field> AddU64Operation<F> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u32,
+        a_u64: u64,
+        b_u64: u64,
+    ) -> u64 {
+        let expected = a_u64.wrapping_add(b_u64);
+        self.value = expected.to_le_bytes().map(F::from_canonical_u8);
+        let a = a_u64.to_le_bytes();
+        let b = b_u64.to_le_bytes();
+
+        let mut carry = 0u32;
+        for i in 0..U64_SIZE - 1 {
+            carry = ((a[i] as u32) + (b[i] as u32) + carry) >> 8;
+            self.carry[i] = F::from_canonical_u32(carry);
+        }
+
+        // Range check
+        {
+            record.add_u8_range_checks(shard, channel, &a);
+            record.add_u8_range_checks(shard, channel, &b);
+            record.add_u8_range_checks(shard, channel, &expected.to_le_bytes());
+        }
+        expected
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        a: [AB::Var; U64_SIZE],
+        b: [AB::Var; U64_SIZE],
+        cols: AddU64Operation<AB::Var>,
+        shard: AB::Var,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: AB::Expr,
+    ) {
+        let one = AB::Expr::one();
+        let base = AB::F::from_canonical_u32(256);
+
+        let mut builder_is_real = builder.when(is_real.clone());
+
+        // For each limb, assert that difference between the carried result and the non-carried
+        // result is either zero or the base.
+        for i in 0..U64_SIZE {
+            let mut overflow = a[i] + b[i] - cols.value[i];
+            if i > 0 {
+                overflow += cols.carry[i - 1].into();
+            }
+            builder_is_real.assert_zero(overflow.clone() * (overflow.clone() - base));
+
+            if i < U64_SIZE - 1 {
+                // If the carry is one, then the overflow must be the base.
+                builder_is_real.assert_zero(cols.carry[i] * (overflow.clone() - base));
+
+                // If the carry is not one, then the overflow must be zero.
+                builder_is_real.assert_zero((cols.carry[i] - one.clone()) * overflow);
+
+                // Assert that the carry is either zero or one.
+                builder_is_real.assert_bool(cols.carry[i]);
+            }
+        }
+        builder_is_real.assert_bool(is_real.clone());
+
+        // Range check each byte.
+        {
+            builder.slice_range_check_u8(&a, shard, channel.clone(), is_real.clone());
+            builder.slice_range_check_u8(&b, shard, channel.clone(), is_real.clone());
+            builder.slice_range_check_u8(&cols.value, shard, channel.clone(), is_real);
+        }
+    }
+}
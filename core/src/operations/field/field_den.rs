@@ -111,10 +111,10 @@ where
     ) where
         V: Into<AB::Expr>,
     {
-        let p_a = Polynomial::from(*a);
-        let p_b = (*b).into();
-        let p_result = self.result.into();
-        let p_carry = self.carry.into();
+        let p_a: Polynomial<AB::Expr> = (*a).into();
+        let p_b: Polynomial<AB::Expr> = (*b).into();
+        let p_result: Polynomial<AB::Expr> = self.result.into();
+        let p_carry: Polynomial<AB::Expr> = self.carry.into();
 
         // Compute the vanishing polynomial:
         //      lhs(x) = sign * (b(x) * result(x) + result(x)) + (1 - sign) * (b(x) * result(x) + a(x))
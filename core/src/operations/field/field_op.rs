@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use num::{BigUint, Zero};
 use p3_air::AirBuilder;
 use p3_field::PrimeField32;
+use serde::{Deserialize, Serialize};
 use sp1_derive::AlignedBorrow;
 
 use super::params::{FieldParameters, Limbs};
@@ -14,7 +15,7 @@ use crate::bytes::event::ByteRecord;
 use typenum::Unsigned;
 
 /// Airthmetic operation for emulating modular arithmetic.
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Serialize, Deserialize)]
 pub enum FieldOperation {
     Add,
     Mul,
@@ -214,9 +215,66 @@ impl<V: Copy, P: FieldParameters> FieldOpCols<V, P> {
         };
         let p_op_minus_result: Polynomial<AB::Expr> = p_op - &p_result;
         let p_vanishing = p_op_minus_result - &(&p_carry * &p_modulus);
+        self.eval_vanishing(builder, &p_vanishing, shard, channel, is_real);
+    }
+
+    /// Evaluate an addition, subtraction or multiplication, where the operation is selected by the
+    /// flags `is_add`, `is_sub` and `is_mul`. At most one of the flags may be set in each row.
+    ///
+    /// This allows a single table to serve several operations without increasing the degree of
+    /// the constraints beyond three.
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval_variable<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        b: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        modulus: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        is_add: impl Into<AB::Expr> + Clone,
+        is_sub: impl Into<AB::Expr> + Clone,
+        is_mul: impl Into<AB::Expr> + Clone,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+        Limbs<V, P::Limbs>: Copy,
+    {
+        let p_a: Polynomial<AB::Expr> = (a).clone().into();
+        let p_b: Polynomial<AB::Expr> = (b).clone().into();
+        let p_modulus: Polynomial<AB::Expr> = (modulus).clone().into();
+        let p_result: Polynomial<AB::Expr> = self.result.into();
+        let p_carry: Polynomial<AB::Expr> = self.carry.into();
+
+        let is_add: AB::Expr = is_add.into();
+        let is_sub: AB::Expr = is_sub.into();
+        let is_mul: AB::Expr = is_mul.into();
+
+        // As in `eval_with_modulus`, a subtraction `a - b = result` is checked as the addition
+        // `result + b = a`.
+        let p_op = (&p_a + &p_b) * is_add.clone()
+            + (&p_result + &p_b) * is_sub.clone()
+            + (&p_a * &p_b) * is_mul.clone();
+        let p_op_result = &p_result * (is_add + is_mul) + &p_a * is_sub;
+        let p_vanishing = p_op - &p_op_result - &(&p_carry * &p_modulus);
+        self.eval_vanishing(builder, &p_vanishing, shard, channel, is_real);
+    }
+
+    /// Check that `p_vanishing` vanishes at the base using the witness columns, and range check
+    /// the result, carry and witness limbs.
+    fn eval_vanishing<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        p_vanishing: &Polynomial<AB::Expr>,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
         let p_witness_low = self.witness_low.0.iter().into();
         let p_witness_high = self.witness_high.0.iter().into();
-        eval_field_operation::<AB, P>(builder, &p_vanishing, &p_witness_low, &p_witness_high);
+        eval_field_operation::<AB, P>(builder, p_vanishing, &p_witness_low, &p_witness_high);
 
         // Range checks for the result, carry, and witness columns.
         builder.slice_range_check_u8(
@@ -84,6 +84,18 @@ pub fn limbs_from_vec<E: From<F>, N: ArrayLength, F: Field>(limbs: Vec<F>) -> Li
     Limbs(result)
 }
 
+/// The fields whose arithmetic is accelerated by the `fptower` precompiles.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FieldType {
+    Bn254,
+    Bls12381,
+}
+
+/// A field with precompiles for its base field and quadratic extension arithmetic.
+pub trait FpOpField: FieldParameters + NumWords {
+    const FIELD_TYPE: FieldType;
+}
+
 /// Trait that holds the typenum values for # of limbs and # of witness limbs.
 pub trait NumLimbs: Clone + Debug {
     type Limbs: ArrayLength + Debug;
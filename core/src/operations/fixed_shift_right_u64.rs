@@ -0,0 +1,158 @@
+use p3_field::AbstractField;
+use p3_field::Field;
+use sp1_derive::AlignedBorrow;
+
+use crate::air::SP1AirBuilder;
+use crate::air::U64_SIZE;
+use crate::bytes::event::ByteRecord;
+use crate::bytes::utils::shr_carry;
+use crate::bytes::ByteLookupEvent;
+use crate::bytes::ByteOpcode;
+
+/// A set of columns needed to compute `>>` of a 64-bit value with a fixed offset R.
+///
+/// Note that we decompose shifts into a byte shift and a bit shift.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct FixedShiftRightU64Operation<T> {
+    /// The output value.
+    pub value: [T; U64_SIZE],
+
+    /// The shift output of `shrcarry` on each byte of the input.
+    pub shift: [T; U64_SIZE],
+
+    /// The carry output of `shrcarry` on each byte of the input.
+    pub carry: [T; U64_SIZE],
+}
+
+impl<F: Field> FixedShiftRightU64Operation<F> {
+    pub const fn nb_bytes_to_shift(rotation: usize) -> usize {
+        rotation / 8
+    }
+
+    pub const fn nb_bits_to_shift(rotation: usize) -> usize {
+        rotation % 8
+    }
+
+    pub const fn carry_multiplier(rotation: usize) -> u32 {
+        let nb_bits_to_shift = Self::nb_bits_to_shift(rotation);
+        1 << (8 - nb_bits_to_shift)
+    }
+
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u32,
+        input: u64,
+        rotation: usize,
+    ) -> u64 {
+        let input_bytes = input.to_le_bytes();
+        let expected = input >> rotation;
+
+        // Compute some constants with respect to the rotation needed for the rotation.
+        let nb_bytes_to_shift = Self::nb_bytes_to_shift(rotation);
+        let nb_bits_to_shift = Self::nb_bits_to_shift(rotation);
+        let carry_multiplier = F::from_canonical_u32(Self::carry_multiplier(rotation));
+
+        // Perform the byte shift.
+        let input_bytes_rotated: [u8; U64_SIZE] = core::array::from_fn(|i| {
+            if i + nb_bytes_to_shift < U64_SIZE {
+                input_bytes[i + nb_bytes_to_shift]
+            } else {
+                0
+            }
+        });
+
+        // For each byte, calculate the shift and carry. If it's not the first byte, calculate the
+        // new byte value using the current shifted byte and the last carry.
+        let mut first_shift = F::zero();
+        let mut last_carry = F::zero();
+        for i in (0..U64_SIZE).rev() {
+            let b = input_bytes_rotated[i];
+            let c = nb_bits_to_shift as u8;
+            let (shift, carry) = shr_carry(b, c);
+            let byte_event = ByteLookupEvent {
+                shard,
+                channel,
+                opcode: ByteOpcode::ShrCarry,
+                a1: shift as u32,
+                a2: carry as u32,
+                b: b as u32,
+                c: c as u32,
+            };
+            record.add_byte_lookup_event(byte_event);
+
+            self.shift[i] = F::from_canonical_u8(shift);
+            self.carry[i] = F::from_canonical_u8(carry);
+
+            if i == U64_SIZE - 1 {
+                first_shift = self.shift[i];
+            } else {
+                self.value[i] = self.shift[i] + last_carry * carry_multiplier;
+            }
+
+            last_carry = self.carry[i];
+        }
+
+        // For the first byte, we don't move over the carry as this is a shift, not a rotate.
+        self.value[U64_SIZE - 1] = first_shift;
+
+        // Assert the answer is correct.
+        debug_assert_eq!(self.value, expected.to_le_bytes().map(F::from_canonical_u8));
+
+        expected
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        input: [AB::Var; U64_SIZE],
+        rotation: usize,
+        cols: FixedShiftRightU64Operation<AB::Var>,
+        shard: impl Into<AB::Expr> + Copy,
+        channel: impl Into<AB::Expr> + Copy,
+        is_real: AB::Var,
+    ) {
+        // Compute some constants with respect to the rotation needed for the rotation.
+        let nb_bytes_to_shift = Self::nb_bytes_to_shift(rotation);
+        let nb_bits_to_shift = Self::nb_bits_to_shift(rotation);
+        let carry_multiplier = AB::F::from_canonical_u32(Self::carry_multiplier(rotation));
+
+        // Perform the byte shift.
+        let input_bytes_rotated: [AB::Expr; U64_SIZE] = core::array::from_fn(|i| {
+            if i + nb_bytes_to_shift < U64_SIZE {
+                input[i + nb_bytes_to_shift].into()
+            } else {
+                AB::Expr::zero()
+            }
+        });
+
+        // For each byte, calculate the shift and carry. If it's not the first byte, calculate the
+        // new byte value using the current shifted byte and the last carry.
+        let mut first_shift = AB::Expr::zero();
+        let mut last_carry = AB::Expr::zero();
+        for i in (0..U64_SIZE).rev() {
+            builder.send_byte_pair(
+                AB::F::from_canonical_u32(ByteOpcode::ShrCarry as u32),
+                cols.shift[i],
+                cols.carry[i],
+                input_bytes_rotated[i].clone(),
+                AB::F::from_canonical_usize(nb_bits_to_shift),
+                shard,
+                channel,
+                is_real,
+            );
+
+            if i == U64_SIZE - 1 {
+                first_shift = cols.shift[i].into();
+            } else {
+                builder.assert_eq(cols.value[i], cols.shift[i] + last_carry * carry_multiplier);
+            }
+
+            last_carry = cols.carry[i].into();
+        }
+
+        // For the first byte, we don't move over the carry as this is a shift, not a rotate.
+        builder.assert_eq(cols.value[U64_SIZE - 1], first_shift);
+    }
+}
@@ -6,33 +6,49 @@
 
 mod add;
 mod add4;
+mod add4_u64;
 mod add5;
+mod add5_u64;
+mod add_u64;
 mod and;
+mod and_u64;
 mod baby_bear_range;
 mod baby_bear_word;
 pub mod field;
 mod fixed_rotate_right;
+mod fixed_rotate_right_u64;
 mod fixed_shift_right;
+mod fixed_shift_right_u64;
 mod is_equal_word;
 mod is_zero;
 mod is_zero_word;
 mod lt;
 mod not;
+mod not_u64;
 mod or;
 mod xor;
+mod xor_u64;
 
 pub use add::*;
 pub use add4::*;
+pub use add4_u64::*;
 pub use add5::*;
+pub use add5_u64::*;
+pub use add_u64::*;
 pub use and::*;
+pub use and_u64::*;
 pub use baby_bear_range::*;
 pub use baby_bear_word::*;
 pub use fixed_rotate_right::*;
+pub use fixed_rotate_right_u64::*;
 pub use fixed_shift_right::*;
+pub use fixed_shift_right_u64::*;
 pub use is_equal_word::*;
 pub use is_zero::*;
 pub use is_zero_word::*;
 pub use lt::*;
 pub use not::*;
+pub use not_u64::*;
 pub use or::*;
 pub use xor::*;
+pub use xor_u64::*;
@@ -0,0 +1,70 @@
+use p3_field::AbstractField;
+use p3_field::Field;
+use sp1_derive::AlignedBorrow;
+
+use crate::air::SP1AirBuilder;
+use crate::air::U64_SIZE;
+use crate::bytes::event::ByteRecord;
+use crate::bytes::ByteLookupEvent;
+use crate::bytes::ByteOpcode;
+
+/// A set of columns needed to compute the xor of two 64-bit values.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XorU64Operation<T> {
+    /// The result of `x ^ y`.
+    pub value: [T; U64_SIZE],
+}
+
+impl<F: Field> XorU64Operation<F> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u32,
+        x: u64,
+        y: u64,
+    ) -> u64 {
+        let expected = x ^ y;
+        let x_bytes = x.to_le_bytes();
+        let y_bytes = y.to_le_bytes();
+        for i in 0..U64_SIZE {
+            let xor = x_bytes[i] ^ y_bytes[i];
+            self.value[i] = F::from_canonical_u8(xor);
+
+            let byte_event = ByteLookupEvent {
+                shard,
+                channel,
+                opcode: ByteOpcode::XOR,
+                a1: xor as u32,
+                a2: 0,
+                b: x_bytes[i] as u32,
+                c: y_bytes[i] as u32,
+            };
+            record.add_byte_lookup_event(byte_event);
+        }
+        expected
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        a: [impl Into<AB::Expr> + Clone; U64_SIZE],
+        b: [impl Into<AB::Expr> + Clone; U64_SIZE],
+        cols: XorU64Operation<AB::Var>,
+        shard: AB::Var,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: AB::Var,
+    ) {
+        for i in 0..U64_SIZE {
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                cols.value[i],
+                a[i].clone(),
+                b[i].clone(),
+                shard,
+                channel.clone(),
+                is_real,
+            );
+        }
+    }
+}
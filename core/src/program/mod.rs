@@ -210,6 +210,7 @@ mod tests {
                 pc_start: 0,
                 pc_base: 0,
                 memory_image: BTreeMap::new(),
+                symbols: None,
             }),
             ..Default::default()
         };
@@ -0,0 +1,198 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use super::{Instruction, Opcode, Register};
+use crate::disassembler::SymbolTable;
+
+/// The maximum number of bytes of stderr output kept to recover the panic message from.
+const STDERR_TAIL_SIZE: usize = 1 << 14;
+
+/// Details about a program that halted with a non-zero exit code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HaltInfo {
+    /// The panic message printed by the program before halting, if any.
+    pub message: Option<String>,
+
+    /// The program counter of the halt.
+    pub pc: u32,
+
+    /// The cycle of the halt.
+    pub cycle: u64,
+
+    /// The call stack at the halt, empty unless recording it is enabled.
+    pub backtrace: Backtrace,
+}
+
+impl Display for HaltInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "at pc 0x{:08x} (cycle {})", self.pc, self.cycle)?;
+        if let Some(ref message) = self.message {
+            write!(f, "\n{}", message)?;
+        }
+        if !self.backtrace.frames.is_empty() {
+            write!(f, "\nbacktrace:\n{}", self.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+/// A call stack of the program, innermost frame first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Backtrace {
+    pub frames: Vec<BacktraceFrame>,
+}
+
+/// A frame of a [Backtrace].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BacktraceFrame {
+    /// The program counter of the frame, i.e. the call site for all but the innermost frame.
+    pub pc: u32,
+
+    /// The name of the function containing `pc`, if the ELF has symbols for it.
+    pub function: Option<String>,
+}
+
+impl Display for Backtrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (i, frame) in self.frames.iter().enumerate() {
+            let function = frame.function.as_deref().unwrap_or("<unknown>");
+            writeln!(f, "{:>4}: 0x{:08x} - {}", i, frame.pc, function)?;
+        }
+        Ok(())
+    }
+}
+
+/// The call stack of the program, reconstructed from the jumps it executes.
+///
+/// Jumps writing the return address to `ra` (or `t0`) are calls and jumps through them are
+/// returns, following the RISC-V calling convention.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CallStack {
+    call_sites: Vec<u32>,
+}
+
+impl CallStack {
+    /// Update the call stack with the instruction executed at `pc`.
+    pub(crate) fn record(&mut self, pc: u32, instruction: &Instruction) {
+        let is_link =
+            |register: u32| register == Register::X1 as u32 || register == Register::X5 as u32;
+        match instruction.opcode {
+            Opcode::JAL | Opcode::JALR if is_link(instruction.op_a) => self.call_sites.push(pc),
+            Opcode::JALR
+                if instruction.op_a == Register::X0 as u32 && is_link(instruction.op_b) =>
+            {
+                self.call_sites.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// The backtrace of the program at `pc`, symbolized with `symbols`.
+    pub(crate) fn backtrace(&self, pc: u32, symbols: &SymbolTable) -> Backtrace {
+        let frames = std::iter::once(pc)
+            .chain(self.call_sites.iter().rev().copied())
+            .map(|pc| BacktraceFrame {
+                pc,
+                function: symbols.name(pc).map(str::to_string),
+            })
+            .collect();
+        Backtrace { frames }
+    }
+}
+
+/// Append `s` to the tail of the stderr output, keeping at most [STDERR_TAIL_SIZE] bytes.
+pub(crate) fn append_stderr_tail(tail: &mut String, s: &str) {
+    tail.push_str(s);
+    if tail.len() > STDERR_TAIL_SIZE {
+        let mut start = tail.len() - STDERR_TAIL_SIZE;
+        while !tail.is_char_boundary(start) {
+            start += 1;
+        }
+        tail.drain(..start);
+    }
+}
+
+/// Extract the message of the last panic from the tail of the stderr output.
+///
+/// The panic hook of the program prints `thread '<name>' panicked at <location>:` followed by
+/// the panic payload and a note about backtraces, which is left out.
+pub(crate) fn panic_message(stderr_tail: &str) -> Option<String> {
+    let start = stderr_tail.rfind("panicked at ")?;
+    let message = &stderr_tail[start..];
+    let end = message.find("\nstack backtrace:").unwrap_or(message.len());
+    Some(message[..end].trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{append_stderr_tail, panic_message, CallStack, STDERR_TAIL_SIZE};
+    use crate::disassembler::{Symbol, SymbolTable};
+    use crate::runtime::{Instruction, Opcode};
+
+    #[test]
+    fn test_call_stack() {
+        let symbols = SymbolTable {
+            symbols: vec![
+                Symbol {
+                    name: "main".to_string(),
+                    address: 0x100,
+                    size: 0x100,
+                },
+                Symbol {
+                    name: "f".to_string(),
+                    address: 0x200,
+                    size: 0x100,
+                },
+            ],
+        };
+        let call = Instruction::new(Opcode::JAL, 1, 0x100, 0, true, true);
+        let ret = Instruction::new(Opcode::JALR, 0, 1, 0, false, true);
+
+        let mut stack = CallStack::default();
+        stack.record(0x104, &call);
+        stack.record(0x208, &call);
+        stack.record(0x20c, &ret);
+        stack.record(0x108, &call);
+
+        let backtrace = stack.backtrace(0x210, &symbols);
+        let frames = backtrace
+            .frames
+            .iter()
+            .map(|frame| (frame.pc, frame.function.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            frames,
+            [
+                (0x210, Some("f")),
+                (0x108, Some("main")),
+                (0x104, Some("main"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let mut tail = String::new();
+        for chunk in [
+            "thread '",
+            "<unnamed>",
+            "' panicked at ",
+            "src/main.rs",
+            ":5:5:\n",
+            "assertion `left == right` failed\n  left: 0\n right: 1",
+            "\n",
+            "stack backtrace:\n",
+            "assertion `left == right` failed\n  left: 0\n right: 1",
+        ] {
+            append_stderr_tail(&mut tail, chunk);
+        }
+        assert_eq!(
+            panic_message(&tail).unwrap(),
+            "panicked at src/main.rs:5:5:\nassertion `left == right` failed\n  left: 0\n right: 1"
+        );
+        assert_eq!(panic_message("error: no panic"), None);
+
+        let mut tail = String::new();
+        append_stderr_tail(&mut tail, &"é".repeat(STDERR_TAIL_SIZE));
+        assert!(tail.len() <= STDERR_TAIL_SIZE);
+    }
+}
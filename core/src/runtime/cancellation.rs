@@ -0,0 +1,119 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use thiserror::Error;
+
+/// A token to cancel proving from another thread.
+///
+/// Proving checks the token between shards, between the layers of the recursion tree and before
+/// wrapping, and stops with [ProvingCancelled::Cancelled] once it is cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the proving runs using this token, or any clone of it.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The error returned when proving stops before completing.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProvingCancelled {
+    #[error("proving was cancelled")]
+    Cancelled,
+    #[error("proving exceeded its deadline")]
+    DeadlineExceeded,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{CancellationToken, ProvingCancelled};
+    use crate::runtime::tests::{fibonacci_program, simple_program};
+    use crate::runtime::{ProgressEvent, SP1Context};
+    use crate::stark::{DefaultProver, MachineProver, RiscvAir};
+    use crate::utils::{prove_with_context, BabyBearPoseidon2, SP1CoreOpts, SP1CoreProverError};
+
+    fn prove(context: SP1Context) -> Result<u64, SP1CoreProverError> {
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let prover = DefaultProver::new(machine);
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 8;
+        opts.shard_batch_size = 1;
+        prove_with_context::<_, _>(
+            &prover,
+            fibonacci_program(),
+            &Default::default(),
+            opts,
+            context,
+        )
+        .map(|(_, _, cycles)| cycles)
+    }
+
+    #[test]
+    fn test_cancelled_before_execution() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let context = SP1Context::builder().cancellation_token(token).build();
+        assert!(matches!(
+            prove(context),
+            Err(SP1CoreProverError::Cancelled(ProvingCancelled::Cancelled))
+        ));
+
+        let context = SP1Context::builder().timeout(Duration::ZERO).build();
+        assert!(matches!(
+            prove(context),
+            Err(SP1CoreProverError::Cancelled(
+                ProvingCancelled::DeadlineExceeded
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_cancel_prove() {
+        // Cancel once the first shard was committed to.
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        let context = SP1Context::builder()
+            .cancellation_token(token)
+            .progress_handler(move |event| {
+                if let ProgressEvent::ShardCommitted { .. } = event {
+                    cancel.cancel();
+                }
+            })
+            .build();
+        assert!(matches!(
+            prove(context),
+            Err(SP1CoreProverError::Cancelled(ProvingCancelled::Cancelled))
+        ));
+
+        // A program small enough to complete is unaffected by a distant deadline.
+        let context = SP1Context::builder()
+            .timeout(Duration::from_secs(3600))
+            .build();
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let prover = DefaultProver::new(machine);
+        prove_with_context::<_, _>(
+            &prover,
+            simple_program(),
+            &Default::default(),
+            SP1CoreOpts::default(),
+            context,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,285 @@
+use std::io;
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{ExecutionState, MemoryRecord, Program};
+use crate::{io::SP1Stdin, utils::SP1CoreOpts};
+
+/// The magic bytes at the start of every checkpoint file.
+#[cfg(not(target_arch = "wasm32"))]
+const CHECKPOINT_MAGIC: [u8; 8] = *b"SP1CKPT\0";
+
+/// The version of the checkpoint file format, bumped whenever [ExecutionState] changes.
+pub const CHECKPOINT_VERSION: u32 = 2;
+
+/// The zstd compression level of checkpoint files.
+#[cfg(not(target_arch = "wasm32"))]
+const CHECKPOINT_COMPRESSION_LEVEL: i32 = 3;
+
+#[derive(Error, Debug)]
+pub enum CheckpointError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("not a checkpoint file")]
+    InvalidMagic,
+    #[error("unsupported checkpoint version {0}, expected {CHECKPOINT_VERSION}")]
+    UnsupportedVersion(u32),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] bincode::Error),
+    #[error("checkpoint was taken from a different program")]
+    ProgramMismatch,
+    #[error("checkpoint was taken with shard size {0} and shard batch size {1}")]
+    OptsMismatch(usize, usize),
+}
+
+/// A snapshot of the execution of a program, from which execution or proving can be resumed.
+///
+/// A checkpoint keeps the state of the runtime where execution stopped, with only the memory that
+/// differs from the memory image of the program. Proving from a checkpoint executes the program
+/// from its start on the same input again, since the shards before the checkpoint have to be
+/// traced as well.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionCheckpoint {
+    program_digest: [u8; 32],
+    shard_size: usize,
+    shard_batch_size: usize,
+    input_len: usize,
+    state: ExecutionState,
+}
+
+impl ExecutionCheckpoint {
+    /// Create a checkpoint of `state`, reached by executing `program` on the first `input_len`
+    /// entries of its input stream, the later ones being written by hooks.
+    pub(crate) fn new(
+        program: &Program,
+        opts: SP1CoreOpts,
+        input_len: usize,
+        mut state: ExecutionState,
+    ) -> Self {
+        state.memory.retain(|addr, record| {
+            program.memory_image.get(addr) != Some(&record.value)
+                || record.shard != 0
+                || record.timestamp != 0
+        });
+        Self {
+            program_digest: program_digest(program),
+            shard_size: opts.shard_size,
+            shard_batch_size: opts.shard_batch_size,
+            input_len,
+            state,
+        }
+    }
+
+    /// Write the checkpoint to `path`, compressed.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CheckpointError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(&CHECKPOINT_MAGIC)?;
+        writer.write_all(&CHECKPOINT_VERSION.to_le_bytes())?;
+        let mut encoder = zstd::Encoder::new(writer, CHECKPOINT_COMPRESSION_LEVEL)?;
+        bincode::serialize_into(&mut encoder, self)?;
+        encoder.finish()?.flush()?;
+        Ok(())
+    }
+
+    /// Read a checkpoint written by [ExecutionCheckpoint::save] from `path`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CheckpointError> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if magic != CHECKPOINT_MAGIC {
+            return Err(CheckpointError::InvalidMagic);
+        }
+        let mut version = [0u8; 4];
+        reader.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+        if version != CHECKPOINT_VERSION {
+            return Err(CheckpointError::UnsupportedVersion(version));
+        }
+        let decoder = zstd::Decoder::with_buffer(reader)?;
+        Ok(bincode::deserialize_from(decoder)?)
+    }
+
+    /// The state of the runtime to resume execution of `program` from, with the memory image of
+    /// the program restored.
+    pub fn state(&self, program: &Program) -> ExecutionState {
+        let mut state = self.state.clone();
+        for (addr, value) in program.memory_image.iter() {
+            state.memory.entry(*addr).or_insert(MemoryRecord {
+                value: *value,
+                shard: 0,
+                timestamp: 0,
+            });
+        }
+        state
+    }
+
+    /// The program counter to resume execution from.
+    pub fn pc(&self) -> u32 {
+        self.state.pc
+    }
+
+    /// The number of entries of the input stream the program was executed with.
+    pub(crate) fn input_len(&self) -> usize {
+        self.input_len
+    }
+
+    /// The number of cycles executed before the checkpoint.
+    pub fn global_clk(&self) -> u64 {
+        self.state.global_clk
+    }
+
+    /// The input the program was executed with.
+    pub fn stdin(&self) -> SP1Stdin {
+        let mut stdin = SP1Stdin::new();
+        stdin.buffer = self.state.input_stream[..self.input_len].to_vec();
+        stdin.proofs.clone_from(&self.state.proof_stream);
+        stdin
+    }
+
+    /// Check that the checkpoint was taken from `program`.
+    pub fn validate(&self, program: &Program) -> Result<(), CheckpointError> {
+        if self.program_digest != program_digest(program) {
+            return Err(CheckpointError::ProgramMismatch);
+        }
+        Ok(())
+    }
+
+    /// Check that the checkpoint was taken from `program` and can be proven with `opts`, which
+    /// requires the shards to be split the same way.
+    pub fn validate_opts(
+        &self,
+        program: &Program,
+        opts: SP1CoreOpts,
+    ) -> Result<(), CheckpointError> {
+        self.validate(program)?;
+        if self.shard_size != opts.shard_size || self.shard_batch_size != opts.shard_batch_size {
+            return Err(CheckpointError::OptsMismatch(
+                self.shard_size,
+                self.shard_batch_size,
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The digest identifying a program in a checkpoint.
+fn program_digest(program: &Program) -> [u8; 32] {
+    let bytes = bincode::serialize(program).expect("failed to serialize program");
+    *blake3::hash(&bytes).as_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tempfile::NamedTempFile;
+
+    use super::{CheckpointError, ExecutionCheckpoint};
+    use crate::runtime::tests::{fibonacci_program, simple_program};
+    use crate::runtime::{Runtime, SP1Context};
+    use crate::stark::{DefaultProver, MachineProver, RiscvAir, StarkGenericConfig};
+    use crate::utils::{prove_with_context, BabyBearPoseidon2, SP1CoreOpts};
+
+    fn opts() -> SP1CoreOpts {
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 8;
+        opts.shard_batch_size = 1;
+        opts
+    }
+
+    fn checkpoint(cycles: u64) -> ExecutionCheckpoint {
+        let mut runtime = Runtime::new(fibonacci_program(), opts());
+        runtime.execute_until(cycles).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_checkpoint_save_load() {
+        let checkpoint = checkpoint(1000);
+        assert!(checkpoint.global_clk() >= 1000);
+
+        let file = NamedTempFile::new().unwrap();
+        checkpoint.save(file.path()).unwrap();
+        let loaded = ExecutionCheckpoint::load(file.path()).unwrap();
+        assert_eq!(loaded.global_clk(), checkpoint.global_clk());
+        assert_eq!(loaded.pc(), checkpoint.pc());
+        loaded.validate_opts(&fibonacci_program(), opts()).unwrap();
+
+        assert!(matches!(
+            loaded.validate(&simple_program()),
+            Err(CheckpointError::ProgramMismatch)
+        ));
+        assert!(matches!(
+            loaded.validate_opts(&fibonacci_program(), SP1CoreOpts::default()),
+            Err(CheckpointError::OptsMismatch(..))
+        ));
+
+        std::fs::write(file.path(), b"not a checkpoint").unwrap();
+        assert!(matches!(
+            ExecutionCheckpoint::load(file.path()),
+            Err(CheckpointError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn test_resume_execution() {
+        let mut runtime = Runtime::new(fibonacci_program(), opts());
+        runtime.run_untraced().unwrap();
+
+        // Resume twice, to check that the memory image is restored for the second checkpoint.
+        let first = checkpoint(1000);
+        let context = SP1Context::builder()
+            .checkpoint(Arc::new(first.clone()))
+            .build();
+        let mut resumed = Runtime::with_context(fibonacci_program(), opts(), context);
+        let second = resumed.execute_until(2000).unwrap().unwrap();
+        assert!(second.global_clk() >= 2000);
+        assert!(second.state.memory.len() < resumed.state.memory.len());
+
+        let context = SP1Context::builder().checkpoint(Arc::new(second)).build();
+        let mut resumed = Runtime::with_context(fibonacci_program(), opts(), context);
+        resumed.run_untraced().unwrap();
+        assert_eq!(resumed.state.global_clk, runtime.state.global_clk);
+        assert_eq!(resumed.state.pc, runtime.state.pc);
+        assert_eq!(
+            resumed.state.public_values_stream,
+            runtime.state.public_values_stream
+        );
+    }
+
+    #[test]
+    fn test_resume_prove() {
+        let context = SP1Context::builder()
+            .checkpoint(Arc::new(checkpoint(1000)))
+            .build();
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let prover = DefaultProver::new(machine);
+        let (proof, _, cycles) = prove_with_context::<_, _>(
+            &prover,
+            fibonacci_program(),
+            &Default::default(),
+            opts(),
+            context,
+        )
+        .unwrap();
+
+        let mut runtime = Runtime::new(fibonacci_program(), opts());
+        runtime.run_untraced().unwrap();
+        assert_eq!(cycles, runtime.state.global_clk);
+
+        let (_, vk) = prover.setup(&fibonacci_program());
+        let mut challenger = prover.config().challenger();
+        prover
+            .machine()
+            .verify(&vk, &proof, &mut challenger)
+            .unwrap();
+    }
+}
@@ -1,7 +1,15 @@
 use core::mem::take;
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
-use super::{hookify, BoxedHook, HookEnv, HookRegistry, SubproofVerifier};
+use web_time::Instant;
+
+use crate::distributed::{WorkerAddress, WorkerSecret};
+
+use super::{
+    hookify, BoxedHook, CancellationToken, ExecutionCheckpoint, HookEnv, HookRegistry, OutputMode,
+    Profiler, ProgressEvent, ProgressHandler, ProvingCancelled, SubproofVerifier, SyscallContext,
+    UnsupportedSyscallHandler,
+};
 
 /// Context to run a program inside SP1.
 #[derive(Clone, Default)]
@@ -16,6 +24,92 @@ pub struct SP1Context<'a> {
 
     /// The maximum number of cpu cycles to use for execution.
     pub max_cycles: Option<u64>,
+
+    /// The profiler to record the cycles spent in each function with, if any.
+    pub profiler: Option<Profiler>,
+
+    /// Whether to record the call stack of the program, to report a backtrace if it halts with a
+    /// non-zero exit code.
+    pub backtrace: bool,
+
+    /// How the output of the program to stdout is handled.
+    pub stdout: OutputMode,
+
+    /// How the output of the program to stderr is handled.
+    pub stderr: OutputMode,
+
+    /// The handler for syscalls unknown to the runtime, if any.
+    pub unsupported_syscall_handler: Option<Arc<dyn UnsupportedSyscallHandler + 'a>>,
+
+    /// The checkpoint to resume execution from, if any.
+    pub checkpoint: Option<Arc<ExecutionCheckpoint>>,
+
+    /// The directory to persist the progress of proving to, if any.
+    pub work_dir: Option<PathBuf>,
+
+    /// The workers to commit to and prove the shards on. If empty, they're proven locally.
+    pub workers: Vec<WorkerAddress>,
+
+    /// The secret to authenticate to the workers with.
+    pub worker_secret: Option<WorkerSecret>,
+
+    /// The handler to report the progress of proving to, if any.
+    pub progress_handler: Option<Arc<dyn ProgressHandler + 'a>>,
+
+    /// The token to cancel proving with, if any.
+    pub cancellation_token: Option<CancellationToken>,
+
+    /// The instant after which proving is cancelled, if any.
+    pub deadline: Option<Instant>,
+}
+
+impl SP1Context<'static> {
+    /// Shorten the lifetime of the context, e.g. to pass a context moved to another thread to a
+    /// prover borrowed from that thread.
+    pub fn scoped<'a>(self) -> SP1Context<'a> {
+        let SP1Context {
+            hook_registry,
+            subproof_verifier,
+            max_cycles,
+            profiler,
+            backtrace,
+            stdout,
+            stderr,
+            unsupported_syscall_handler,
+            checkpoint,
+            work_dir,
+            workers,
+            worker_secret,
+            progress_handler,
+            cancellation_token,
+            deadline,
+        } = self;
+        let hook_registry = hook_registry.map(|registry| HookRegistry {
+            table: registry
+                .table
+                .into_iter()
+                .map(|(fd, hook)| (fd, hook as BoxedHook<'a>))
+                .collect(),
+        });
+        SP1Context {
+            hook_registry,
+            subproof_verifier: subproof_verifier.map(|v| v as Arc<dyn SubproofVerifier + 'a>),
+            max_cycles,
+            profiler,
+            backtrace,
+            stdout,
+            stderr,
+            unsupported_syscall_handler: unsupported_syscall_handler
+                .map(|h| h as Arc<dyn UnsupportedSyscallHandler + 'a>),
+            checkpoint,
+            work_dir,
+            workers,
+            worker_secret,
+            progress_handler: progress_handler.map(|h| h as Arc<dyn ProgressHandler + 'a>),
+            cancellation_token,
+            deadline,
+        }
+    }
 }
 
 #[derive(Clone, Default)]
@@ -24,6 +118,18 @@ pub struct SP1ContextBuilder<'a> {
     hook_registry_entries: Vec<(u32, BoxedHook<'a>)>,
     subproof_verifier: Option<Arc<dyn SubproofVerifier + 'a>>,
     max_cycles: Option<u64>,
+    profiler: Option<Profiler>,
+    backtrace: bool,
+    stdout: OutputMode,
+    stderr: OutputMode,
+    unsupported_syscall_handler: Option<Arc<dyn UnsupportedSyscallHandler + 'a>>,
+    checkpoint: Option<Arc<ExecutionCheckpoint>>,
+    work_dir: Option<PathBuf>,
+    workers: Vec<WorkerAddress>,
+    worker_secret: Option<WorkerSecret>,
+    progress_handler: Option<Arc<dyn ProgressHandler + 'a>>,
+    cancellation_token: Option<CancellationToken>,
+    deadline: Option<Instant>,
 }
 
 impl<'a> SP1Context<'a> {
@@ -31,6 +137,40 @@ impl<'a> SP1Context<'a> {
     pub fn builder() -> SP1ContextBuilder<'a> {
         SP1ContextBuilder::new()
     }
+
+    /// Report `event` to the progress handler, if any.
+    pub fn report_progress(&self, event: ProgressEvent) {
+        if let Some(ref handler) = self.progress_handler {
+            handler.on_progress(event);
+        }
+    }
+
+    /// Check whether proving must stop, because the cancellation token was cancelled or the
+    /// deadline passed.
+    pub fn check_cancelled(&self) -> Result<(), ProvingCancelled> {
+        if let Some(ref token) = self.cancellation_token {
+            if token.is_cancelled() {
+                return Err(ProvingCancelled::Cancelled);
+            }
+        }
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                return Err(ProvingCancelled::DeadlineExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    /// A context with only the parts of this one still in use once the program was executed: the
+    /// progress handler, the cancellation token and the deadline.
+    pub fn proving_context(&self) -> SP1Context<'a> {
+        SP1Context {
+            progress_handler: self.progress_handler.clone(),
+            cancellation_token: self.cancellation_token.clone(),
+            deadline: self.deadline,
+            ..Default::default()
+        }
+    }
 }
 
 impl<'a> SP1ContextBuilder<'a> {
@@ -60,10 +200,34 @@ impl<'a> SP1ContextBuilder<'a> {
             });
         let subproof_verifier = take(&mut self.subproof_verifier);
         let cycle_limit = take(&mut self.max_cycles);
+        let profiler = take(&mut self.profiler);
+        let backtrace = take(&mut self.backtrace);
+        let stdout = take(&mut self.stdout);
+        let stderr = take(&mut self.stderr);
+        let unsupported_syscall_handler = take(&mut self.unsupported_syscall_handler);
+        let checkpoint = take(&mut self.checkpoint);
+        let work_dir = take(&mut self.work_dir);
+        let workers = take(&mut self.workers);
+        let worker_secret = take(&mut self.worker_secret);
+        let progress_handler = take(&mut self.progress_handler);
+        let cancellation_token = take(&mut self.cancellation_token);
+        let deadline = take(&mut self.deadline);
         SP1Context {
             hook_registry,
             subproof_verifier,
             max_cycles: cycle_limit,
+            profiler,
+            backtrace,
+            stdout,
+            stderr,
+            unsupported_syscall_handler,
+            checkpoint,
+            work_dir,
+            workers,
+            worker_secret,
+            progress_handler,
+            cancellation_token,
+            deadline,
         }
     }
 
@@ -106,13 +270,109 @@ impl<'a> SP1ContextBuilder<'a> {
         self.max_cycles = Some(max_cycles);
         self
     }
+
+    /// Profile the execution, writing the cycles spent in each function to the output of the
+    /// [Profiler] once execution finishes.
+    pub fn profiler(&mut self, profiler: Profiler) -> &mut Self {
+        self.profiler = Some(profiler);
+        self
+    }
+
+    /// Record the call stack of the program while executing it, so that the
+    /// [HaltInfo](super::HaltInfo) of a halt with a non-zero exit code includes a backtrace.
+    ///
+    /// This slows down execution, so it is disabled by default.
+    pub fn backtrace(&mut self) -> &mut Self {
+        self.backtrace = true;
+        self
+    }
+
+    /// Set how the output of the program to stdout is handled.
+    pub fn stdout(&mut self, mode: OutputMode) -> &mut Self {
+        self.stdout = mode;
+        self
+    }
+
+    /// Set how the output of the program to stderr is handled.
+    pub fn stderr(&mut self, mode: OutputMode) -> &mut Self {
+        self.stderr = mode;
+        self
+    }
+
+    /// Handle syscalls unknown to the runtime with `f` instead of failing with
+    /// [ExecutionError::UnsupportedSyscall](super::ExecutionError::UnsupportedSyscall).
+    ///
+    /// The handler is called with the syscall id and the values of registers X10 and X11, and
+    /// returns the value to write to register t0, if any. It is only invoked when executing the
+    /// program without generating a proof, since unknown syscalls cannot be proven.
+    pub fn unsupported_syscall_handler(
+        &mut self,
+        f: impl Fn(&mut SyscallContext, u32, u32, u32) -> Option<u32> + Send + Sync + 'a,
+    ) -> &mut Self {
+        self.unsupported_syscall_handler = Some(Arc::new(f));
+        self
+    }
+
+    /// Resume execution from `checkpoint` instead of the start of the program.
+    ///
+    /// The state of the checkpoint, including the input of the program, replaces the state of
+    /// the runtime when execution starts. Proving executes the program from its start on the input
+    /// of the checkpoint instead, since every shard has to be proven.
+    pub fn checkpoint(&mut self, checkpoint: Arc<ExecutionCheckpoint>) -> &mut Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Persist the progress of proving to the directory at `path`, so that proving the same
+    /// program on the same input can be interrupted and restarted without proving the shards
+    /// that were already proven again.
+    pub fn work_dir(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.work_dir = Some(path.into());
+        self
+    }
+
+    /// Commit to and prove the shards on the workers listening at `workers`, see
+    /// [WorkerListener](crate::distributed::WorkerListener), instead of locally, authenticating
+    /// to them with `secret`.
+    ///
+    /// The program is still executed and traced into shards locally, and the workers re-execute
+    /// it from the checkpoints the shards were traced from.
+    pub fn workers(&mut self, workers: Vec<WorkerAddress>, secret: WorkerSecret) -> &mut Self {
+        self.workers = workers;
+        self.worker_secret = Some(secret);
+        self
+    }
+
+    /// Report the progress of proving to `handler`, either a closure or the sending half of a
+    /// channel receiving [ProgressEvent]s.
+    pub fn progress_handler(&mut self, handler: impl ProgressHandler + 'a) -> &mut Self {
+        self.progress_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Stop proving with [ProvingCancelled::Cancelled] once `token` is cancelled.
+    pub fn cancellation_token(&mut self, token: CancellationToken) -> &mut Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Stop proving with [ProvingCancelled::DeadlineExceeded] once `deadline` passed.
+    pub fn deadline(&mut self, deadline: Instant) -> &mut Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stop proving with [ProvingCancelled::DeadlineExceeded] once `timeout` elapsed from now.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.deadline(Instant::now() + timeout)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use crate::runtime::{DefaultSubproofVerifier, SP1Context};
+    use crate::runtime::{DefaultSubproofVerifier, OutputMode, SP1Context};
 
     #[test]
     fn defaults() {
@@ -120,10 +380,34 @@ mod tests {
             hook_registry,
             subproof_verifier,
             max_cycles: cycle_limit,
+            profiler,
+            backtrace,
+            stdout,
+            stderr,
+            unsupported_syscall_handler,
+            checkpoint,
+            work_dir,
+            workers,
+            worker_secret,
+            progress_handler,
+            cancellation_token,
+            deadline,
         } = SP1Context::builder().build();
         assert!(hook_registry.is_none());
         assert!(subproof_verifier.is_none());
         assert!(cycle_limit.is_none());
+        assert!(profiler.is_none());
+        assert!(!backtrace);
+        assert!(matches!(stdout, OutputMode::Passthrough));
+        assert!(matches!(stderr, OutputMode::Passthrough));
+        assert!(unsupported_syscall_handler.is_none());
+        assert!(checkpoint.is_none());
+        assert!(work_dir.is_none());
+        assert!(workers.is_empty());
+        assert!(worker_secret.is_none());
+        assert!(progress_handler.is_none());
+        assert!(cancellation_token.is_none());
+        assert!(deadline.is_none());
     }
 
     #[test]
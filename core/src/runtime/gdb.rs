@@ -0,0 +1,589 @@
+//! A GDB remote serial protocol (RSP) stub for debugging guest programs.
+//!
+//! The stub wraps a [Runtime] in execute-only mode and serves a single debugger connection over
+//! TCP, supporting single-stepping, breakpoints on the program counter, register and memory reads,
+//! and continuing until the program halts. For example:
+//!
+//! ```text
+//! riscv32-unknown-elf-gdb program.elf -ex "target remote localhost:9001"
+//! ```
+//!
+//! Reference: https://sourceware.org/gdb/current/onlinedocs/gdb.html/Remote-Protocol.html
+
+use std::collections::BTreeSet;
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+
+use super::{ExecutionError, Opcode, Register, Runtime};
+
+/// The number of cycles executed between checks for an interrupt request from the debugger.
+const INTERRUPT_POLL_INTERVAL: u64 = 1 << 16;
+
+/// The maximum number of bytes returned for a single memory read.
+const MAX_MEMORY_READ: usize = 4096;
+
+/// The ABI names of the general purpose registers, in register number order.
+const REGISTER_NAMES: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "fp", "s1", "a0", "a1", "a2", "a3", "a4",
+    "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+    "t5", "t6",
+];
+
+/// The register number GDB uses for the program counter.
+const PC_REGNUM: usize = 32;
+
+const SIGINT: u8 = 2;
+const SIGILL: u8 = 4;
+const SIGTRAP: u8 = 5;
+const SIGSEGV: u8 = 11;
+const SIGXCPU: u8 = 24;
+const SIGSYS: u8 = 31;
+
+/// The reason execution stopped and control was handed back to the debugger.
+#[derive(Debug)]
+enum StopReason {
+    /// A single step completed.
+    Step,
+    /// The program counter reached a breakpoint set by the debugger.
+    Breakpoint,
+    /// The guest executed an `ebreak` instruction.
+    Ebreak,
+    /// The debugger requested an interrupt.
+    Interrupted,
+    /// The program halted with the given exit code.
+    Exited(u32),
+    /// Execution failed with an error.
+    Fault(ExecutionError),
+}
+
+impl StopReason {
+    /// The stop reply packet reported to the debugger.
+    fn reply(&self) -> String {
+        let signal = match self {
+            StopReason::Step | StopReason::Breakpoint | StopReason::Ebreak => SIGTRAP,
+            StopReason::Interrupted => SIGINT,
+            StopReason::Exited(code) => return format!("W{:02x}", code & 0xff),
+            StopReason::Fault(ExecutionError::InvalidMemoryAccess(..)) => SIGSEGV,
+            StopReason::Fault(ExecutionError::UnsupportedSyscall(..)) => SIGSYS,
+            StopReason::Fault(ExecutionError::ExceededCycleLimit(..)) => SIGXCPU,
+            StopReason::Fault(_) => SIGILL,
+        };
+        format!("S{:02x}", signal)
+    }
+}
+
+/// A GDB remote serial protocol stub wrapping a [Runtime].
+pub struct GdbStub<'a> {
+    runtime: Runtime<'a>,
+    breakpoints: BTreeSet<u32>,
+    last_stop: StopReason,
+    no_ack: bool,
+}
+
+impl<'a> GdbStub<'a> {
+    /// Create a new stub for the given runtime. Execution starts when the debugger resumes it.
+    pub fn new(runtime: Runtime<'a>) -> Self {
+        Self {
+            runtime,
+            breakpoints: BTreeSet::new(),
+            last_stop: StopReason::Step,
+            no_ack: false,
+        }
+    }
+
+    /// Get a reference to the wrapped runtime.
+    pub fn runtime(&self) -> &Runtime<'a> {
+        &self.runtime
+    }
+
+    /// Consume the stub, returning the wrapped runtime.
+    pub fn into_runtime(self) -> Runtime<'a> {
+        self.runtime
+    }
+
+    /// Listen on the given address and serve the first debugger that connects.
+    pub fn listen(&mut self, addr: impl ToSocketAddrs) -> io::Result<()> {
+        let listener = TcpListener::bind(addr)?;
+        log::info!("waiting for gdb on {}", listener.local_addr()?);
+        let (stream, peer) = listener.accept()?;
+        log::info!("gdb connected from {}", peer);
+        self.serve(stream)
+    }
+
+    /// Serve a debugger connection until it detaches, kills the program, or disconnects.
+    pub fn serve(&mut self, mut stream: TcpStream) -> io::Result<()> {
+        stream.set_nodelay(true)?;
+        while let Some(packet) = self.read_packet(&mut stream)? {
+            let packet = String::from_utf8_lossy(&packet).into_owned();
+            match self.handle_packet(&packet, &mut stream)? {
+                Some(response) => {
+                    self.write_packet(&mut stream, &response)?;
+                    if packet == "QStartNoAckMode" {
+                        self.no_ack = true;
+                    }
+                    if packet.starts_with('D') {
+                        break;
+                    }
+                }
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle a single packet, returning the response or `None` if the session should end.
+    fn handle_packet(
+        &mut self,
+        packet: &str,
+        stream: &mut TcpStream,
+    ) -> io::Result<Option<String>> {
+        let response = match packet.as_bytes().first() {
+            Some(b'?') => self.last_stop.reply(),
+            Some(b'g') => self.read_registers(),
+            Some(b'p') => self.read_register(&packet[1..]),
+            Some(b'm') => self.read_memory(&packet[1..]),
+            Some(b'Z') => self.update_breakpoint(&packet[1..], true),
+            Some(b'z') => self.update_breakpoint(&packet[1..], false),
+            Some(b's') => {
+                self.last_stop = self.resume(stream, true)?;
+                self.last_stop.reply()
+            }
+            Some(b'c') => {
+                self.last_stop = self.resume(stream, false)?;
+                self.last_stop.reply()
+            }
+            Some(b'H') | Some(b'T') => "OK".to_string(),
+            Some(b'D') => "OK".to_string(),
+            Some(b'k') => return Ok(None),
+            Some(b'q') | Some(b'Q') => self.handle_query(packet),
+            _ => String::new(),
+        };
+        Ok(Some(response))
+    }
+
+    /// Handle a general query packet.
+    fn handle_query(&self, packet: &str) -> String {
+        if packet.starts_with("qSupported") {
+            "PacketSize=4000;QStartNoAckMode+;qXfer:features:read+;swbreak+;hwbreak+".to_string()
+        } else if packet == "QStartNoAckMode" {
+            "OK".to_string()
+        } else if let Some(annex) = packet.strip_prefix("qXfer:features:read:target.xml:") {
+            read_chunk(&target_xml(), annex)
+        } else if packet == "qAttached" {
+            "1".to_string()
+        } else if packet == "qC" {
+            "QC1".to_string()
+        } else if packet == "qfThreadInfo" {
+            "m1".to_string()
+        } else if packet == "qsThreadInfo" {
+            "l".to_string()
+        } else if packet.starts_with("qSymbol") {
+            "OK".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Resume execution, returning why it stopped.
+    fn resume(&mut self, stream: &mut TcpStream, single_step: bool) -> io::Result<StopReason> {
+        if let StopReason::Exited(code) = self.last_stop {
+            return Ok(StopReason::Exited(code));
+        }
+
+        // An `ebreak` does not advance the program counter, so step over it when resuming.
+        if matches!(self.last_stop, StopReason::Ebreak) && self.current_opcode() == Opcode::EBREAK {
+            self.runtime.state.pc = self.runtime.state.pc.wrapping_add(4);
+        }
+
+        let mut cycles = 0u64;
+        loop {
+            match self.runtime.step() {
+                Ok(true) => return Ok(StopReason::Exited(0)),
+                Ok(false) => {}
+                Err(ExecutionError::Breakpoint()) => return Ok(StopReason::Ebreak),
+                Err(ExecutionError::HaltWithNonZeroExitCode(code)) => {
+                    return Ok(StopReason::Exited(code))
+                }
+                Err(e) => {
+                    log::warn!(
+                        "execution stopped at pc 0x{:x}: {}",
+                        self.runtime.state.pc,
+                        e
+                    );
+                    return Ok(StopReason::Fault(e));
+                }
+            }
+
+            if single_step {
+                return Ok(StopReason::Step);
+            }
+            if self.breakpoints.contains(&self.runtime.state.pc) {
+                return Ok(StopReason::Breakpoint);
+            }
+
+            cycles += 1;
+            if cycles % INTERRUPT_POLL_INTERVAL == 0 && interrupt_requested(stream)? {
+                return Ok(StopReason::Interrupted);
+            }
+        }
+    }
+
+    /// The opcode of the instruction at the current program counter.
+    fn current_opcode(&self) -> Opcode {
+        let program = &self.runtime.program;
+        let idx = (self.runtime.state.pc.wrapping_sub(program.pc_base) / 4) as usize;
+        program
+            .instructions
+            .get(idx)
+            .map(|instruction| instruction.opcode)
+            .unwrap_or(Opcode::UNIMP)
+    }
+
+    /// Read all registers, in GDB register number order.
+    fn read_registers(&self) -> String {
+        let mut response = String::new();
+        for value in self.runtime.registers() {
+            response.push_str(&hex::encode(value.to_le_bytes()));
+        }
+        response.push_str(&hex::encode(self.runtime.state.pc.to_le_bytes()));
+        response
+    }
+
+    /// Read a single register, given its GDB register number in hex.
+    fn read_register(&self, args: &str) -> String {
+        let value = match usize::from_str_radix(args, 16) {
+            Ok(regnum) if regnum < PC_REGNUM => {
+                self.runtime.register(Register::from_u32(regnum as u32))
+            }
+            Ok(PC_REGNUM) => self.runtime.state.pc,
+            _ => return "E01".to_string(),
+        };
+        hex::encode(value.to_le_bytes())
+    }
+
+    /// Read memory, given arguments of the form `addr,length` in hex.
+    fn read_memory(&self, args: &str) -> String {
+        let Some((addr, len)) = parse_pair(args) else {
+            return "E01".to_string();
+        };
+        let len = (len as usize).min(MAX_MEMORY_READ);
+        let bytes = (0..len as u32)
+            .map(|i| self.runtime.byte(addr.wrapping_add(i)))
+            .collect::<Vec<_>>();
+        hex::encode(bytes)
+    }
+
+    /// Insert or remove a breakpoint, given arguments of the form `type,addr,kind`.
+    fn update_breakpoint(&mut self, args: &str, insert: bool) -> String {
+        let mut parts = args.splitn(2, ',');
+        let kind = parts.next();
+        let addr = parts.next().and_then(parse_pair);
+        match (kind, addr) {
+            // Software and hardware breakpoints are treated the same way.
+            (Some("0") | Some("1"), Some((addr, _))) => {
+                if insert {
+                    self.breakpoints.insert(addr);
+                } else {
+                    self.breakpoints.remove(&addr);
+                }
+                "OK".to_string()
+            }
+            // Watchpoints are not supported.
+            _ => String::new(),
+        }
+    }
+
+    /// Read the next packet from the debugger, acknowledging it unless in no-ack mode.
+    ///
+    /// A stray interrupt byte is returned as a `?` packet, so the debugger gets the last stop
+    /// reason. Returns `None` when the connection is closed.
+    fn read_packet(&self, stream: &mut TcpStream) -> io::Result<Option<Vec<u8>>> {
+        loop {
+            let Some(byte) = read_byte(stream)? else {
+                return Ok(None);
+            };
+            match byte {
+                b'$' => {}
+                0x03 => return Ok(Some(b"?".to_vec())),
+                _ => continue,
+            }
+
+            let mut data = Vec::new();
+            loop {
+                match read_byte(stream)? {
+                    Some(b'#') => break,
+                    Some(b) => data.push(b),
+                    None => return Ok(None),
+                }
+            }
+            let mut checksum = [0u8; 2];
+            stream.read_exact(&mut checksum)?;
+
+            let expected = u8::from_str_radix(&String::from_utf8_lossy(&checksum), 16).ok();
+            if !self.no_ack {
+                if expected == Some(checksum_of(&data)) {
+                    stream.write_all(b"+")?;
+                } else {
+                    stream.write_all(b"-")?;
+                    continue;
+                }
+            }
+            return Ok(Some(unescape(&data)));
+        }
+    }
+
+    /// Write a packet to the debugger, retransmitting until it is acknowledged.
+    fn write_packet(&self, stream: &mut TcpStream, data: &str) -> io::Result<()> {
+        let data = escape(data.as_bytes());
+        let mut packet = Vec::with_capacity(data.len() + 4);
+        packet.push(b'$');
+        packet.extend_from_slice(&data);
+        packet.push(b'#');
+        packet.extend_from_slice(format!("{:02x}", checksum_of(&data)).as_bytes());
+
+        loop {
+            stream.write_all(&packet)?;
+            stream.flush()?;
+            if self.no_ack {
+                return Ok(());
+            }
+            match read_byte(stream)? {
+                Some(b'-') => continue,
+                _ => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Check, without blocking, whether the debugger sent an interrupt request.
+fn interrupt_requested(stream: &mut TcpStream) -> io::Result<bool> {
+    stream.set_nonblocking(true)?;
+    let mut buf = [0u8; 1];
+    let result = match stream.read(&mut buf) {
+        Ok(1) => Ok(buf[0] == 0x03),
+        // The debugger disconnected, so stop running.
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::WouldBlock => Ok(false),
+        Err(e) => Err(e),
+    };
+    stream.set_nonblocking(false)?;
+    result
+}
+
+/// Read a single byte from the stream, returning `None` at end of stream.
+fn read_byte(stream: &mut TcpStream) -> io::Result<Option<u8>> {
+    let mut buf = [0u8; 1];
+    match stream.read(&mut buf)? {
+        0 => Ok(None),
+        _ => Ok(Some(buf[0])),
+    }
+}
+
+/// The modulo 256 sum of the packet data.
+fn checksum_of(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |acc, b| acc.wrapping_add(*b))
+}
+
+/// Escape the characters that may not appear in packet data.
+fn escape(data: &[u8]) -> Vec<u8> {
+    let mut escaped = Vec::with_capacity(data.len());
+    for &b in data {
+        if matches!(b, b'#' | b'$' | b'}' | b'*') {
+            escaped.push(b'}');
+            escaped.push(b ^ 0x20);
+        } else {
+            escaped.push(b);
+        }
+    }
+    escaped
+}
+
+/// Undo the escaping applied to packet data.
+fn unescape(data: &[u8]) -> Vec<u8> {
+    let mut unescaped = Vec::with_capacity(data.len());
+    let mut iter = data.iter();
+    while let Some(&b) = iter.next() {
+        if b == b'}' {
+            if let Some(&next) = iter.next() {
+                unescaped.push(next ^ 0x20);
+            }
+        } else {
+            unescaped.push(b);
+        }
+    }
+    unescaped
+}
+
+/// Parse arguments of the form `a,b`, both in hex.
+fn parse_pair(args: &str) -> Option<(u32, u32)> {
+    let (a, b) = args.split_once(',')?;
+    let a = u32::from_str_radix(a, 16).ok()?;
+    let b = u32::from_str_radix(b, 16).ok()?;
+    Some((a, b))
+}
+
+/// Return the chunk of `document` requested by a `qXfer` annex of the form `offset,length`.
+fn read_chunk(document: &str, annex: &str) -> String {
+    let Some((offset, len)) = parse_pair(annex) else {
+        return "E01".to_string();
+    };
+    let bytes = document.as_bytes();
+    let start = (offset as usize).min(bytes.len());
+    let end = start.saturating_add(len as usize).min(bytes.len());
+    let marker = if end < bytes.len() { 'm' } else { 'l' };
+    format!("{}{}", marker, String::from_utf8_lossy(&bytes[start..end]))
+}
+
+/// The target description, listing the general purpose registers and the program counter.
+fn target_xml() -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\"?><!DOCTYPE target SYSTEM \"gdb-target.dtd\"><target version=\"1.0\">\
+         <architecture>riscv:rv32</architecture><feature name=\"org.gnu.gdb.riscv.cpu\">",
+    );
+    for (i, name) in REGISTER_NAMES.iter().enumerate() {
+        let ty = match i {
+            1 => "code_ptr",
+            2 | 3 | 4 | 8 => "data_ptr",
+            _ => "int",
+        };
+        xml.push_str(&format!(
+            "<reg name=\"{}\" bitsize=\"32\" type=\"{}\" regnum=\"{}\"/>",
+            name, ty, i
+        ));
+    }
+    xml.push_str(&format!(
+        "<reg name=\"pc\" bitsize=\"32\" type=\"code_ptr\" regnum=\"{}\"/>",
+        PC_REGNUM
+    ));
+    xml.push_str("</feature></target>");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    use super::{checksum_of, GdbStub};
+    use crate::runtime::tests::{fibonacci_program, simple_program};
+    use crate::runtime::{Instruction, Opcode, Program, Register, Runtime};
+    use crate::utils::SP1CoreOpts;
+
+    /// A minimal debugger client speaking the remote serial protocol.
+    struct Client(TcpStream);
+
+    impl Client {
+        fn request(&mut self, data: &str) -> String {
+            let packet = format!("${}#{:02x}", data, checksum_of(data.as_bytes()));
+            self.0.write_all(packet.as_bytes()).unwrap();
+
+            let mut ack = [0u8; 1];
+            self.0.read_exact(&mut ack).unwrap();
+            assert_eq!(ack[0], b'+');
+
+            let mut byte = [0u8; 1];
+            self.0.read_exact(&mut byte).unwrap();
+            assert_eq!(byte[0], b'$');
+            let mut response = Vec::new();
+            loop {
+                self.0.read_exact(&mut byte).unwrap();
+                if byte[0] == b'#' {
+                    break;
+                }
+                response.push(byte[0]);
+            }
+            let mut checksum = [0u8; 2];
+            self.0.read_exact(&mut checksum).unwrap();
+            self.0.write_all(b"+").unwrap();
+            String::from_utf8(response).unwrap()
+        }
+    }
+
+    /// Serve `program` on a local port and connect a client to it.
+    fn connect(program: Program) -> (Client, thread::JoinHandle<Runtime<'static>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = thread::spawn(move || {
+            let mut stub = GdbStub::new(Runtime::new(program, SP1CoreOpts::default()));
+            let (stream, _) = listener.accept().unwrap();
+            stub.serve(stream).unwrap();
+            stub.into_runtime()
+        });
+        (Client(TcpStream::connect(addr).unwrap()), handle)
+    }
+
+    fn register(response: &str, regnum: usize) -> u32 {
+        let bytes = hex::decode(&response[regnum * 8..regnum * 8 + 8]).unwrap();
+        u32::from_le_bytes(bytes.try_into().unwrap())
+    }
+
+    #[test]
+    fn test_step_and_read_registers() {
+        let (mut client, handle) = connect(simple_program());
+        assert!(client.request("qSupported:swbreak+").contains("PacketSize"));
+        assert_eq!(client.request("?"), "S05");
+        assert_eq!(client.request("s"), "S05");
+        assert_eq!(client.request("s"), "S05");
+
+        let registers = client.request("g");
+        assert_eq!(register(&registers, 29), 5);
+        assert_eq!(register(&registers, 30), 37);
+        assert_eq!(register(&registers, 32), 8);
+        assert_eq!(client.request("p1e"), hex::encode(37u32.to_le_bytes()));
+
+        assert_eq!(client.request("c"), "W00");
+        assert_eq!(client.request("D"), "OK");
+        let runtime = handle.join().unwrap();
+        assert_eq!(runtime.register(Register::X31), 42);
+    }
+
+    #[test]
+    fn test_breakpoint() {
+        let (mut client, handle) = connect(simple_program());
+        assert_eq!(client.request("Z0,8,4"), "OK");
+        assert_eq!(client.request("c"), "S05");
+        assert_eq!(register(&client.request("g"), 32), 8);
+        assert_eq!(client.request("z0,8,4"), "OK");
+        assert_eq!(client.request("c"), "W00");
+        assert_eq!(client.request("D"), "OK");
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_ebreak() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::EBREAK, 0, 0, 0, false, false),
+            Instruction::new(Opcode::ADD, 30, 29, 37, false, true),
+        ];
+        let (mut client, handle) = connect(Program::new(instructions, 0, 0));
+        assert_eq!(client.request("c"), "S05");
+        assert_eq!(register(&client.request("g"), 32), 4);
+        assert_eq!(client.request("c"), "W00");
+        assert_eq!(client.request("D"), "OK");
+        let runtime = handle.join().unwrap();
+        assert_eq!(runtime.register(Register::X30), 42);
+    }
+
+    #[test]
+    fn test_read_memory_and_target_xml() {
+        let program = fibonacci_program();
+        let entry = program.pc_start;
+        let word = *program.memory_image.get(&entry).unwrap();
+        let (mut client, handle) = connect(program);
+
+        let xml = client.request("qXfer:features:read:target.xml:0,ffff");
+        assert!(xml.starts_with('l'));
+        assert!(xml.contains("org.gnu.gdb.riscv.cpu"));
+
+        assert_eq!(client.request("s"), "S05");
+        assert_eq!(
+            client.request(&format!("m{:x},4", entry)),
+            hex::encode(word.to_le_bytes())
+        );
+        assert_eq!(client.request("c"), "W00");
+        assert_eq!(client.request("D"), "OK");
+        handle.join().unwrap();
+    }
+}
@@ -1,10 +1,17 @@
+mod backtrace;
+mod cancellation;
+mod checkpoint;
 mod context;
+mod gdb;
 mod hooks;
 mod instruction;
 mod io;
 mod memory;
 mod opcode;
+mod output;
+mod profiler;
 mod program;
+mod progress;
 mod record;
 mod register;
 mod report;
@@ -14,12 +21,19 @@ mod syscall;
 mod utils;
 mod subproof;
 
+pub use backtrace::*;
+pub use cancellation::*;
+pub use checkpoint::*;
 pub use context::*;
+pub use gdb::*;
 pub use hooks::*;
 pub use instruction::*;
 pub use memory::*;
 pub use opcode::*;
+pub use output::*;
+pub use profiler::*;
 pub use program::*;
+pub use progress::*;
 pub use record::*;
 pub use register::*;
 pub use report::*;
@@ -72,12 +86,24 @@ pub struct Runtime<'a> {
 
     pub shard_batch_size: u32,
 
-    /// A counter for the number of cycles that have been executed in certain functions.
-    pub cycle_tracker: HashMap<String, (u64, u32)>,
+    /// The stack of open cycle-tracker spans, with the cycle at which each one started.
+    pub cycle_tracker: Vec<(String, u64)>,
 
     /// A buffer for stdout and stderr IO.
     pub io_buf: HashMap<u32, String>,
 
+    /// The tail of the output of the program to stderr, used to recover panic messages.
+    pub(crate) stderr_tail: String,
+
+    /// The call stack of the program, used to build backtraces, if recording it is enabled.
+    pub(crate) call_stack: Option<CallStack>,
+
+    /// How the output of the program to stdout is handled.
+    pub stdout: OutputMode,
+
+    /// How the output of the program to stderr is handled.
+    pub stderr: OutputMode,
+
     /// A buffer for writing trace events to a file.
     pub trace_buf: Option<BufWriter<File>>,
 
@@ -111,11 +137,20 @@ pub struct Runtime<'a> {
     /// Registry of hooks, to be invoked by writing to certain file descriptors.
     pub hook_registry: HookRegistry<'a>,
 
+    /// Handler for syscalls unknown to the runtime, invoked only when not emitting events.
+    pub unsupported_syscall_handler: Option<Arc<dyn UnsupportedSyscallHandler + 'a>>,
+
     // The options for the runtime.
     pub opts: SP1CoreOpts,
 
     /// The maximum number of cpu cycles to use for execution.
     pub max_cycles: Option<u64>,
+
+    /// The profiler recording the cycles spent in each function, if profiling is enabled.
+    pub profiler: Option<Profiler>,
+
+    /// The checkpoint execution resumes from, if any.
+    pub checkpoint: Option<Arc<ExecutionCheckpoint>>,
 }
 
 #[derive(Error, Debug)]
@@ -180,8 +215,12 @@ impl<'a> Runtime<'a> {
             memory_accesses: MemoryAccessRecord::default(),
             shard_size: (opts.shard_size as u32) * 4,
             shard_batch_size: opts.shard_batch_size as u32,
-            cycle_tracker: HashMap::new(),
+            cycle_tracker: Vec::new(),
             io_buf: HashMap::new(),
+            stderr_tail: String::new(),
+            call_stack: context.backtrace.then(CallStack::default),
+            stdout: context.stdout,
+            stderr: context.stderr,
             trace_buf,
             unconstrained: false,
             unconstrained_state: ForkState::default(),
@@ -192,8 +231,11 @@ impl<'a> Runtime<'a> {
             print_report: false,
             subproof_verifier,
             hook_registry,
+            unsupported_syscall_handler: context.unsupported_syscall_handler,
             opts,
             max_cycles: context.max_cycles,
+            profiler: context.profiler,
+            checkpoint: context.checkpoint,
         }
     }
 
@@ -218,6 +260,24 @@ impl<'a> Runtime<'a> {
         runtime
     }
 
+    /// Get the details about the current state of the program, e.g. after execution failed with
+    /// [ExecutionError::HaltWithNonZeroExitCode].
+    ///
+    /// The backtrace is only recorded if enabled with [SP1ContextBuilder::backtrace].
+    pub fn halt_info(&self) -> HaltInfo {
+        let symbols = self.program.symbols.clone().unwrap_or_default();
+        HaltInfo {
+            message: panic_message(&self.stderr_tail),
+            pc: self.state.pc,
+            cycle: self.state.global_clk,
+            backtrace: self
+                .call_stack
+                .as_ref()
+                .map(|stack| stack.backtrace(self.state.pc, &symbols))
+                .unwrap_or_default(),
+        }
+    }
+
     /// Get the current values of the registers.
     pub fn registers(&self) -> [u32; 32] {
         let mut registers = [0; 32];
@@ -831,44 +891,63 @@ impl<'a> Runtime<'a> {
                 let syscall_id = self.register(t0);
                 c = self.rr(Register::X11, MemoryAccessPosition::C);
                 b = self.rr(Register::X10, MemoryAccessPosition::B);
-                let syscall = SyscallCode::from_u32(syscall_id);
-
-                if self.print_report && !self.unconstrained {
-                    self.report
-                        .syscall_counts
-                        .entry(syscall)
-                        .and_modify(|c| *c += 1)
-                        .or_insert(1);
+                let syscall = SyscallCode::try_from_u32(syscall_id);
+
+                if let Some(syscall) = syscall {
+                    if self.print_report && !self.unconstrained {
+                        self.report
+                            .syscall_counts
+                            .entry(syscall)
+                            .and_modify(|c| *c += 1)
+                            .or_insert(1);
+                    }
                 }
 
-                let syscall_impl = self.get_syscall(syscall).cloned();
+                let syscall_impl = syscall.and_then(|syscall| self.get_syscall(syscall).cloned());
+                // Unknown syscalls can only be handled by the user if no proof is generated.
+                let unsupported_syscall_handler = self
+                    .unsupported_syscall_handler
+                    .clone()
+                    .filter(|_| !self.emit_events);
                 let mut precompile_rt = SyscallContext::new(self);
                 precompile_rt.syscall_lookup_id = syscall_lookup_id;
                 let (precompile_next_pc, precompile_cycles, returned_exit_code) =
-                    if let Some(syscall_impl) = syscall_impl {
-                        // Executing a syscall optionally returns a value to write to the t0 register.
-                        // If it returns None, we just keep the syscall_id in t0.
-                        let res = syscall_impl.execute(&mut precompile_rt, b, c);
-                        if let Some(val) = res {
-                            a = val;
-                        } else {
-                            a = syscall_id;
-                        }
-
-                        // If the syscall is `HALT` and the exit code is non-zero, return an error.
-                        if syscall == SyscallCode::HALT && precompile_rt.exit_code != 0 {
-                            return Err(ExecutionError::HaltWithNonZeroExitCode(
+                    match (syscall, syscall_impl, unsupported_syscall_handler) {
+                        (Some(syscall), Some(syscall_impl), _) => {
+                            // Executing a syscall optionally returns a value to write to the t0
+                            // register. If it returns None, we just keep the syscall_id in t0.
+                            let res = syscall_impl.execute(&mut precompile_rt, b, c);
+                            if let Some(val) = res {
+                                a = val;
+                            } else {
+                                a = syscall_id;
+                            }
+
+                            // If the syscall is `HALT` and the exit code is non-zero, return an
+                            // error.
+                            if syscall == SyscallCode::HALT && precompile_rt.exit_code != 0 {
+                                let exit_code = precompile_rt.exit_code;
+                                log::error!(
+                                    "program halted with exit code {} {}",
+                                    exit_code,
+                                    self.halt_info()
+                                );
+                                return Err(ExecutionError::HaltWithNonZeroExitCode(exit_code));
+                            }
+
+                            (
+                                precompile_rt.next_pc,
+                                syscall_impl.num_extra_cycles(),
                                 precompile_rt.exit_code,
-                            ));
+                            )
                         }
-
-                        (
-                            precompile_rt.next_pc,
-                            syscall_impl.num_extra_cycles(),
-                            precompile_rt.exit_code,
-                        )
-                    } else {
-                        return Err(ExecutionError::UnsupportedSyscall(syscall_id));
+                        (None, _, Some(handler)) => {
+                            a = handler
+                                .execute(&mut precompile_rt, syscall_id, b, c)
+                                .unwrap_or(syscall_id);
+                            (precompile_rt.next_pc, 0, precompile_rt.exit_code)
+                        }
+                        _ => return Err(ExecutionError::UnsupportedSyscall(syscall_id)),
                     };
 
                 // Allow the syscall impl to modify state.clk/pc (exit unconstrained does this)
@@ -880,23 +959,35 @@ impl<'a> Runtime<'a> {
                 self.state.clk += precompile_cycles;
                 exit_code = returned_exit_code;
 
-                // Update the syscall counts.
-                let syscall_count = self.state.syscall_counts.entry(syscall).or_insert(0);
-                let (threshold, multiplier) = match syscall {
-                    SyscallCode::KECCAK_PERMUTE => {
-                        (self.opts.split_opts.keccak_split_threshold, 24)
-                    }
-                    SyscallCode::SHA_EXTEND => {
-                        (self.opts.split_opts.sha_extend_split_threshold, 48)
-                    }
-                    SyscallCode::SHA_COMPRESS => {
-                        (self.opts.split_opts.sha_compress_split_threshold, 80)
-                    }
-                    _ => (self.opts.split_opts.deferred_shift_threshold, 1),
-                };
-                let nonce = (((*syscall_count as usize) % threshold) * multiplier) as u32;
-                self.record.nonce_lookup.insert(syscall_lookup_id, nonce);
-                *syscall_count += 1;
+                // Update the syscall counts. Unknown syscalls are never proven, so they need no
+                // nonce.
+                if let Some(syscall) = syscall {
+                    let syscall_count = self.state.syscall_counts.entry(syscall).or_insert(0);
+                    let (threshold, multiplier) = match syscall {
+                        SyscallCode::KECCAK_PERMUTE => {
+                            (self.opts.split_opts.keccak_split_threshold, 24)
+                        }
+                        SyscallCode::SHA_EXTEND => {
+                            (self.opts.split_opts.sha_extend_split_threshold, 48)
+                        }
+                        SyscallCode::SHA_COMPRESS => {
+                            (self.opts.split_opts.sha_compress_split_threshold, 80)
+                        }
+                        SyscallCode::SHA512_EXTEND => {
+                            (self.opts.split_opts.sha512_extend_split_threshold, 64)
+                        }
+                        SyscallCode::SHA512_COMPRESS => {
+                            (self.opts.split_opts.sha512_compress_split_threshold, 96)
+                        }
+                        SyscallCode::BLAKE2B_COMPRESS => {
+                            (self.opts.split_opts.blake2b_compress_split_threshold, 12)
+                        }
+                        _ => (self.opts.split_opts.deferred_shift_threshold, 1),
+                    };
+                    let nonce = (((*syscall_count as usize) % threshold) * multiplier) as u32;
+                    self.record.nonce_lookup.insert(syscall_lookup_id, nonce);
+                    *syscall_count += 1;
+                }
             }
             Opcode::EBREAK => {
                 return Err(ExecutionError::Breakpoint());
@@ -1010,9 +1101,38 @@ impl<'a> Runtime<'a> {
         // Log the current state of the runtime.
         self.log(&instruction);
 
+        // Capture the state needed to track the call stack and profile the instruction, skipping
+        // unconstrained blocks.
+        let constrained = !self.unconstrained;
+        let profile = self.profiler.is_some() && constrained;
+        let pc = self.state.pc;
+        let syscall_id =
+            (profile && instruction.opcode == Opcode::ECALL).then(|| self.register(Register::X5));
+
         // Execute the instruction.
         self.execute_instruction(instruction)?;
 
+        // Update the call stack.
+        if let Some(call_stack) = self.call_stack.as_mut().filter(|_| constrained) {
+            call_stack.record(pc, &instruction);
+        }
+
+        // Record the instruction in the profiler.
+        if profile {
+            let syscall = syscall_id
+                .and_then(SyscallCode::try_from_u32)
+                .map(|syscall| {
+                    let extra_cycles = self
+                        .get_syscall(syscall)
+                        .map_or(0, |syscall_impl| syscall_impl.num_extra_cycles());
+                    (syscall, extra_cycles)
+                });
+            let next_pc = self.state.pc;
+            if let Some(profiler) = self.profiler.as_mut() {
+                profiler.record(pc, &instruction, next_pc, syscall);
+            }
+        }
+
         // Increment the clock.
         self.state.global_clk += 1;
 
@@ -1057,11 +1177,54 @@ impl<'a> Runtime<'a> {
     pub fn execute_state(&mut self) -> Result<(ExecutionState, bool), ExecutionError> {
         self.emit_events = false;
         self.print_report = false;
+        // Resume from the checkpoint first, so that its state is the returned prestate.
+        self.restore_checkpoint();
         let state = self.state.clone();
         let done = self.execute()?;
         Ok((state, done))
     }
 
+    /// Execute batches of `self.shard_batch_size` shards until at least `cycles` cycles have been
+    /// executed, returning a checkpoint to resume execution from, or `None` if the program
+    /// finished first.
+    pub fn execute_until(
+        &mut self,
+        cycles: u64,
+    ) -> Result<Option<ExecutionCheckpoint>, ExecutionError> {
+        self.emit_events = false;
+        self.print_report = false;
+        self.restore_checkpoint();
+
+        // Hooks append to the input stream, so remember where the input of the program ends.
+        let input_len = match self.checkpoint {
+            Some(ref checkpoint) => checkpoint.input_len(),
+            None => self.state.input_stream.len(),
+        };
+        loop {
+            if self.execute()? {
+                return Ok(None);
+            }
+            if self.state.global_clk >= cycles {
+                break;
+            }
+        }
+        Ok(Some(ExecutionCheckpoint::new(
+            &self.program,
+            self.opts,
+            input_len,
+            self.state.clone(),
+        )))
+    }
+
+    /// If execution hasn't started yet, restore the state of the checkpoint to resume from.
+    fn restore_checkpoint(&mut self) {
+        if self.state.global_clk == 0 {
+            if let Some(ref checkpoint) = self.checkpoint {
+                self.state = checkpoint.state(&self.program);
+            }
+        }
+    }
+
     fn initialize(&mut self) {
         self.state.clk = 0;
         self.state.channel = 0;
@@ -1098,19 +1261,40 @@ impl<'a> Runtime<'a> {
         while !self.execute().unwrap() {}
     }
 
+    /// Executes a single cycle of the program without emitting events, returning whether the
+    /// program has finished.
+    ///
+    /// This is useful for driving the runtime interactively, e.g. from a debugger.
+    pub fn step(&mut self) -> Result<bool, ExecutionError> {
+        self.emit_events = false;
+
+        // If it's the first cycle, initialize the program.
+        if self.state.global_clk == 0 {
+            self.initialize();
+        }
+
+        let done = self.execute_cycle()?;
+
+        // No events are emitted, so the records bumped at shard boundaries can be dropped.
+        self.records.clear();
+
+        Ok(done)
+    }
+
     /// Executes up to `self.shard_batch_size` cycles of the program, returning whether the program has finished.
     fn execute(&mut self) -> Result<bool, ExecutionError> {
         // Get the program.
         let program = self.program.clone();
 
-        // Get the current shard.
-        let start_shard = self.state.current_shard;
-
-        // If it's the first cycle, initialize the program.
+        // If it's the first cycle, resume from the checkpoint or initialize the program.
+        self.restore_checkpoint();
         if self.state.global_clk == 0 {
             self.initialize();
         }
 
+        // Get the current shard.
+        let start_shard = self.state.current_shard;
+
         // Loop until we've executed `self.shard_batch_size` shards if `self.shard_batch_size` is set.
         let mut done = false;
         let mut current_shard = self.state.current_shard;
@@ -1191,6 +1375,18 @@ impl<'a> Runtime<'a> {
             buf.flush().unwrap();
         }
 
+        // Write the profile.
+        if let Some(ref profiler) = self.profiler {
+            match profiler.write() {
+                Ok(()) => log::info!("wrote profile to {}", profiler.output().display()),
+                Err(e) => log::error!(
+                    "failed to write profile to {}: {}",
+                    profiler.output().display(),
+                    e
+                ),
+            }
+        }
+
         // Ensure that all proofs and input bytes were read, otherwise warn the user.
         if self.state.proof_stream_ptr != self.state.proof_stream.len() {
             panic!(
@@ -1260,13 +1456,15 @@ pub mod tests {
 
     use crate::{
         runtime::Register,
+        stark::{DefaultProver, MachineProver, RiscvAir},
         utils::{
+            prove_with_context,
             tests::{FIBONACCI_ELF, KECCAK_PERMUTE_ELF, PANIC_ELF},
-            SP1CoreOpts,
+            BabyBearPoseidon2, SP1CoreOpts, SP1CoreProverError,
         },
     };
 
-    use super::{Instruction, Opcode, Program, Runtime};
+    use super::{ExecutionError, Instruction, Opcode, OutputMode, Program, Runtime, SP1Context};
 
     pub fn simple_program() -> Program {
         let instructions = vec![
@@ -1312,6 +1510,91 @@ pub mod tests {
         runtime.run().unwrap();
     }
 
+    #[test]
+    fn test_panic_halt_info() {
+        let program = panic_program();
+        let context = SP1Context::builder()
+            .backtrace()
+            .stderr(OutputMode::Discard)
+            .build();
+        let mut runtime = Runtime::with_context(program, SP1CoreOpts::default(), context);
+        let Err(ExecutionError::HaltWithNonZeroExitCode(1)) = runtime.run_untraced() else {
+            panic!("expected the program to halt with exit code 1");
+        };
+
+        let info = runtime.halt_info();
+        assert_eq!(info.pc, runtime.state.pc);
+        assert_eq!(info.cycle, runtime.state.global_clk);
+        let message = info.message.as_deref().unwrap();
+        assert!(message.starts_with("panicked at src/main.rs:5:5:"));
+        assert!(message.ends_with("assertion `left == right` failed\n  left: 0\n right: 1"));
+        let functions = info
+            .backtrace
+            .frames
+            .iter()
+            .map(|frame| frame.function.as_deref().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert!(functions[0].ends_with("syscall_halt"));
+        assert!(functions.iter().any(|function| function.ends_with("main")));
+    }
+
+    fn unsupported_syscall_program() -> Program {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, 0xFF, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 7, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 31, 5, 0, false, true),
+        ];
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_unsupported_syscall() {
+        let program = unsupported_syscall_program();
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        assert!(matches!(
+            runtime.run_untraced(),
+            Err(ExecutionError::UnsupportedSyscall(0xFF))
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_syscall_handler() {
+        let context = SP1Context::builder()
+            .unsupported_syscall_handler(|_, syscall_id, arg1, _| Some(syscall_id + arg1))
+            .build();
+
+        // The handler is invoked when only executing the program.
+        let program = unsupported_syscall_program();
+        let mut runtime = Runtime::with_context(program, SP1CoreOpts::default(), context.clone());
+        runtime.run_untraced().unwrap();
+        assert_eq!(runtime.register(Register::X31), 0xFF + 7);
+
+        // The syscall cannot be proven, so it fails when emitting events.
+        let program = unsupported_syscall_program();
+        let mut runtime = Runtime::with_context(program, SP1CoreOpts::default(), context.clone());
+        assert!(matches!(
+            runtime.run(),
+            Err(ExecutionError::UnsupportedSyscall(0xFF))
+        ));
+
+        // Proving fails with the same error instead of handling the syscall.
+        let prover = DefaultProver::new(RiscvAir::machine(BabyBearPoseidon2::new()));
+        let result = prove_with_context::<_, _>(
+            &prover,
+            unsupported_syscall_program(),
+            &Default::default(),
+            SP1CoreOpts::default(),
+            context,
+        );
+        assert!(matches!(
+            result,
+            Err(SP1CoreProverError::ExecutionError(
+                ExecutionError::UnsupportedSyscall(0xFF)
+            ))
+        ));
+    }
+
     #[test]
     fn test_add() {
         // main:
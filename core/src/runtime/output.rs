@@ -0,0 +1,59 @@
+use std::sync::{Arc, Mutex};
+
+/// How the runtime handles the output of the program to stdout or stderr.
+#[derive(Debug, Clone, Default)]
+pub enum OutputMode {
+    /// Print each line of the output to the host's stdout, prefixed by the name of the stream.
+    #[default]
+    Passthrough,
+
+    /// Append the output to the given buffer.
+    Capture(OutputBuffer),
+
+    /// Ignore the output.
+    Discard,
+}
+
+impl OutputMode {
+    /// Capture the output into a new, empty buffer.
+    pub fn capture() -> Self {
+        Self::Capture(OutputBuffer::default())
+    }
+
+    /// The contents of the buffer, if the output is captured.
+    pub fn captured(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Capture(buffer) => Some(buffer.contents()),
+            _ => None,
+        }
+    }
+}
+
+/// A buffer capturing the output of the program, shared between its clones.
+///
+/// The buffer can be handed to the runtime through an [OutputMode::Capture] and read from once
+/// execution finishes.
+#[derive(Debug, Clone, Default)]
+pub struct OutputBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl OutputBuffer {
+    /// Create a new, empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append bytes to the buffer.
+    pub fn write(&self, bytes: &[u8]) {
+        self.0.lock().unwrap().extend_from_slice(bytes);
+    }
+
+    /// A copy of the bytes written to the buffer so far.
+    pub fn contents(&self) -> Vec<u8> {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Take the bytes written to the buffer so far, leaving it empty.
+    pub fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
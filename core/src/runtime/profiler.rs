@@ -0,0 +1,464 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use prost::Message;
+use strum_macros::EnumString;
+
+use super::{Instruction, Opcode, Register, SyscallCode};
+use crate::disassembler::SymbolTable;
+
+/// The output format of a [Profiler].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum ProfileFormat {
+    /// Folded stacks, one `frame;frame;frame count` line per stack, as consumed by
+    /// `flamegraph.pl` and `inferno-flamegraph`.
+    #[default]
+    Folded,
+
+    /// A protobuf encoded profile, as consumed by `go tool pprof`.
+    Pprof,
+}
+
+/// A frame of the reconstructed call stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Frame {
+    /// The function at the given index in the symbol table.
+    Function(usize),
+    /// Code not covered by any function symbol.
+    Unknown,
+    /// A syscall invoked from the parent frame.
+    Syscall(SyscallCode),
+}
+
+/// A node in the tree of call stacks.
+#[derive(Debug, Clone)]
+struct Node {
+    parent: usize,
+    frame: Frame,
+    /// The number of cycles spent in this frame, excluding its callees.
+    cycles: u64,
+}
+
+/// The index of the root node, which does not correspond to any frame.
+const ROOT: usize = 0;
+
+/// A profiler attributing every executed cycle to a call stack of functions from the ELF.
+///
+/// The call stack is reconstructed from the `jal`/`jalr` instructions executed by the program:
+/// jumps writing the return address to `ra` are calls and jumps through `ra` are returns. Other
+/// jumps into a different function (e.g. tail calls) are resolved against the current stack.
+/// Syscalls are attributed to a `[syscall NAME]` frame under their caller.
+#[derive(Debug, Clone)]
+pub struct Profiler {
+    symbols: Arc<SymbolTable>,
+    nodes: Vec<Node>,
+    children: HashMap<(usize, Frame), usize>,
+    stack: Vec<usize>,
+    output: PathBuf,
+    format: ProfileFormat,
+}
+
+impl Profiler {
+    /// Create a profiler for the given ELF, writing the profile to `output` in the given format
+    /// when execution finishes.
+    pub fn new(elf: &[u8], output: impl Into<PathBuf>, format: ProfileFormat) -> Self {
+        Self {
+            symbols: Arc::new(SymbolTable::from_elf(elf)),
+            nodes: vec![Node {
+                parent: ROOT,
+                frame: Frame::Unknown,
+                cycles: 0,
+            }],
+            children: HashMap::new(),
+            stack: Vec::new(),
+            output: output.into(),
+            format,
+        }
+    }
+
+    /// The path the profile is written to.
+    pub fn output(&self) -> &Path {
+        &self.output
+    }
+
+    /// The total number of cycles recorded, including the extra cycles of syscalls.
+    pub fn total_cycles(&self) -> u64 {
+        self.nodes.iter().map(|node| node.cycles).sum()
+    }
+
+    /// Record the execution of `instruction` at `pc`, which moved the program counter to
+    /// `next_pc`. `syscall` is the syscall invoked, if the instruction is an `ecall`, along with
+    /// the number of extra cycles it takes.
+    pub fn record(
+        &mut self,
+        pc: u32,
+        instruction: &Instruction,
+        next_pc: u32,
+        syscall: Option<(SyscallCode, u32)>,
+    ) {
+        if self.stack.is_empty() {
+            let frame = self.frame_at(pc);
+            let node = self.child(ROOT, frame);
+            self.stack.push(node);
+        }
+
+        // Attribute the cycle to the current stack.
+        let top = *self.stack.last().unwrap();
+        match syscall {
+            Some((code, extra_cycles)) => {
+                let node = self.child(top, Frame::Syscall(code));
+                self.nodes[node].cycles += 1 + extra_cycles as u64;
+            }
+            None => self.nodes[top].cycles += 1,
+        }
+
+        // Update the stack according to the control flow of the instruction.
+        let is_link = |register: Register| matches!(register, Register::X1 | Register::X5);
+        match instruction.opcode {
+            Opcode::JAL | Opcode::JALR if is_link(Register::from_u32(instruction.op_a)) => {
+                let frame = self.frame_at(next_pc);
+                let node = self.child(top, frame);
+                self.stack.push(node);
+            }
+            Opcode::JALR
+                if instruction.op_a == Register::X0 as u32
+                    && is_link(Register::from_u32(instruction.op_b)) =>
+            {
+                if self.stack.len() > 1 {
+                    self.stack.pop();
+                }
+                self.resync(next_pc);
+            }
+            Opcode::JAL
+            | Opcode::JALR
+            | Opcode::ECALL
+            | Opcode::BEQ
+            | Opcode::BNE
+            | Opcode::BLT
+            | Opcode::BGE
+            | Opcode::BLTU
+            | Opcode::BGEU => self.resync(next_pc),
+            _ => {}
+        }
+    }
+
+    /// Make sure the top of the stack is the function containing `pc`, unwinding to the closest
+    /// frame of that function or replacing the top frame if there is none.
+    fn resync(&mut self, pc: u32) {
+        let frame = self.frame_at(pc);
+        let top = *self.stack.last().unwrap();
+        if self.nodes[top].frame == frame {
+            return;
+        }
+        match self
+            .stack
+            .iter()
+            .rposition(|node| self.nodes[*node].frame == frame)
+        {
+            Some(idx) => self.stack.truncate(idx + 1),
+            None => {
+                let parent = self.nodes[top].parent;
+                let node = self.child(parent, frame);
+                *self.stack.last_mut().unwrap() = node;
+            }
+        }
+    }
+
+    /// The frame of the function containing `pc`.
+    fn frame_at(&self, pc: u32) -> Frame {
+        match self.symbols.lookup(pc) {
+            Some(idx) => Frame::Function(idx),
+            None => Frame::Unknown,
+        }
+    }
+
+    /// Get or create the child of `parent` with the given frame.
+    fn child(&mut self, parent: usize, frame: Frame) -> usize {
+        let nodes = &mut self.nodes;
+        *self.children.entry((parent, frame)).or_insert_with(|| {
+            nodes.push(Node {
+                parent,
+                frame,
+                cycles: 0,
+            });
+            nodes.len() - 1
+        })
+    }
+
+    /// The name of a frame.
+    fn frame_name(&self, frame: Frame) -> String {
+        match frame {
+            Frame::Function(idx) => self.symbols.symbols[idx].name.clone(),
+            Frame::Unknown => "[unknown]".to_string(),
+            Frame::Syscall(code) => format!("[syscall {}]", code),
+        }
+    }
+
+    /// The nodes from the root to the given node, excluding the root.
+    fn path(&self, mut node: usize) -> Vec<usize> {
+        let mut path = Vec::new();
+        while node != ROOT {
+            path.push(node);
+            node = self.nodes[node].parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Write the profile to the configured output in the configured format.
+    pub fn write(&self) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&self.output)?);
+        match self.format {
+            ProfileFormat::Folded => self.write_folded(&mut writer)?,
+            ProfileFormat::Pprof => self.write_pprof(&mut writer)?,
+        }
+        writer.flush()
+    }
+
+    /// Write the profile as folded stacks.
+    pub fn write_folded(&self, mut writer: impl Write) -> io::Result<()> {
+        for (idx, node) in self.nodes.iter().enumerate() {
+            if node.cycles == 0 {
+                continue;
+            }
+            let stack = self
+                .path(idx)
+                .into_iter()
+                .map(|node| self.frame_name(self.nodes[node].frame))
+                .collect::<Vec<_>>()
+                .join(";");
+            writeln!(writer, "{} {}", stack, node.cycles)?;
+        }
+        Ok(())
+    }
+
+    /// Write the profile in the pprof protobuf format.
+    pub fn write_pprof(&self, mut writer: impl Write) -> io::Result<()> {
+        let mut strings = pprof::StringTable::default();
+        let sample_type = pprof::ValueType {
+            r#type: strings.intern("cycles"),
+            unit: strings.intern("count"),
+        };
+
+        // Every frame gets its own function and location.
+        let mut locations = HashMap::new();
+        let mut profile = pprof::Profile {
+            sample_type: vec![sample_type.clone()],
+            period_type: Some(sample_type),
+            period: 1,
+            ..Default::default()
+        };
+        for (idx, node) in self.nodes.iter().enumerate() {
+            if node.cycles == 0 {
+                continue;
+            }
+            let mut location_ids = Vec::new();
+            for node in self.path(idx).into_iter().rev() {
+                let frame = self.nodes[node].frame;
+                let next_id = locations.len() as u64 + 1;
+                let id = *locations.entry(frame).or_insert_with(|| {
+                    let name = strings.intern(&self.frame_name(frame));
+                    let address = match frame {
+                        Frame::Function(idx) => self.symbols.symbols[idx].address as u64,
+                        _ => 0,
+                    };
+                    profile.function.push(pprof::Function {
+                        id: next_id,
+                        name,
+                        system_name: name,
+                        ..Default::default()
+                    });
+                    profile.location.push(pprof::Location {
+                        id: next_id,
+                        address,
+                        line: vec![pprof::Line {
+                            function_id: next_id,
+                            line: 0,
+                        }],
+                        ..Default::default()
+                    });
+                    next_id
+                });
+                location_ids.push(id);
+            }
+            profile.sample.push(pprof::Sample {
+                location_id: location_ids,
+                value: vec![node.cycles as i64],
+            });
+        }
+        profile.string_table = strings.strings;
+
+        writer.write_all(&profile.encode_to_vec())
+    }
+}
+
+/// The subset of the pprof protobuf schema written by the profiler.
+///
+/// Reference: https://github.com/google/pprof/blob/main/proto/profile.proto
+mod pprof {
+    use std::collections::HashMap;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Profile {
+        #[prost(message, repeated, tag = "1")]
+        pub sample_type: Vec<ValueType>,
+        #[prost(message, repeated, tag = "2")]
+        pub sample: Vec<Sample>,
+        #[prost(message, repeated, tag = "4")]
+        pub location: Vec<Location>,
+        #[prost(message, repeated, tag = "5")]
+        pub function: Vec<Function>,
+        #[prost(string, repeated, tag = "6")]
+        pub string_table: Vec<String>,
+        #[prost(message, optional, tag = "11")]
+        pub period_type: Option<ValueType>,
+        #[prost(int64, tag = "12")]
+        pub period: i64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ValueType {
+        #[prost(int64, tag = "1")]
+        pub r#type: i64,
+        #[prost(int64, tag = "2")]
+        pub unit: i64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Sample {
+        /// The stack of locations, leaf first.
+        #[prost(uint64, repeated, tag = "1")]
+        pub location_id: Vec<u64>,
+        #[prost(int64, repeated, tag = "2")]
+        pub value: Vec<i64>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Location {
+        #[prost(uint64, tag = "1")]
+        pub id: u64,
+        #[prost(uint64, tag = "2")]
+        pub mapping_id: u64,
+        #[prost(uint64, tag = "3")]
+        pub address: u64,
+        #[prost(message, repeated, tag = "4")]
+        pub line: Vec<Line>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Line {
+        #[prost(uint64, tag = "1")]
+        pub function_id: u64,
+        #[prost(int64, tag = "2")]
+        pub line: i64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Function {
+        #[prost(uint64, tag = "1")]
+        pub id: u64,
+        #[prost(int64, tag = "2")]
+        pub name: i64,
+        #[prost(int64, tag = "3")]
+        pub system_name: i64,
+        #[prost(int64, tag = "4")]
+        pub filename: i64,
+    }
+
+    /// A string table, where the first entry must be the empty string.
+    pub struct StringTable {
+        pub strings: Vec<String>,
+        indices: HashMap<String, i64>,
+    }
+
+    impl Default for StringTable {
+        fn default() -> Self {
+            Self {
+                strings: vec![String::new()],
+                indices: HashMap::from([(String::new(), 0)]),
+            }
+        }
+    }
+
+    impl StringTable {
+        /// Get the index of a string, adding it to the table if needed.
+        pub fn intern(&mut self, s: &str) -> i64 {
+            if let Some(idx) = self.indices.get(s) {
+                return *idx;
+            }
+            let idx = self.strings.len() as i64;
+            self.strings.push(s.to_string());
+            self.indices.insert(s.to_string(), idx);
+            idx
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::{pprof, ProfileFormat, Profiler};
+    use crate::runtime::{Instruction, Opcode, Program, Runtime, SP1Context, SyscallCode};
+    use crate::utils::tests::FIBONACCI_ELF;
+    use crate::utils::SP1CoreOpts;
+
+    fn profile_fibonacci(format: ProfileFormat) -> (Profiler, tempfile::NamedTempFile) {
+        let output = tempfile::NamedTempFile::new().unwrap();
+        let profiler = Profiler::new(FIBONACCI_ELF, output.path(), format);
+        let context = SP1Context::builder().profiler(profiler).build();
+        let mut runtime = Runtime::with_context(
+            Program::from(FIBONACCI_ELF),
+            SP1CoreOpts::default(),
+            context,
+        );
+        runtime.run_untraced().unwrap();
+        let profiler = runtime.profiler.take().unwrap();
+        assert_eq!(profiler.total_cycles(), runtime.state.global_clk);
+        (profiler, output)
+    }
+
+    #[test]
+    fn test_folded() {
+        let (profiler, _output) = profile_fibonacci(ProfileFormat::Folded);
+        let folded = std::fs::read_to_string(profiler.output()).unwrap();
+
+        let mut total = 0;
+        for line in folded.lines() {
+            let (_, count) = line.rsplit_once(' ').unwrap();
+            total += count.parse::<u64>().unwrap();
+        }
+        assert_eq!(total, profiler.total_cycles());
+        assert!(folded.contains("main;"));
+        assert!(folded.contains("[syscall HALT]"));
+    }
+
+    #[test]
+    fn test_pprof() {
+        let (profiler, _output) = profile_fibonacci(ProfileFormat::Pprof);
+        let bytes = std::fs::read(profiler.output()).unwrap();
+        let profile = pprof::Profile::decode(bytes.as_slice()).unwrap();
+
+        assert_eq!(profile.string_table[0], "");
+        let total = profile
+            .sample
+            .iter()
+            .map(|sample| sample.value[0] as u64)
+            .sum::<u64>();
+        assert_eq!(total, profiler.total_cycles());
+        assert_eq!(profile.location.len(), profile.function.len());
+    }
+
+    #[test]
+    fn test_syscall_extra_cycles() {
+        let mut profiler = Profiler::new(FIBONACCI_ELF, "unused", ProfileFormat::Folded);
+        let ecall = Instruction::new(Opcode::ECALL, 5, 10, 11, false, false);
+        let extra_cycles = SyscallCode::SHA_EXTEND.num_cycles();
+        profiler.record(0, &ecall, 4, Some((SyscallCode::SHA_EXTEND, extra_cycles)));
+        assert_eq!(profiler.total_cycles(), 1 + extra_cycles as u64);
+    }
+}
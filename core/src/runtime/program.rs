@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 use super::Instruction;
+use crate::disassembler::SymbolTable;
 
 /// A program that can be executed by the VM.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -17,4 +19,8 @@ pub struct Program {
 
     /// The initial memory image, useful for global constants.
     pub memory_image: BTreeMap<u32, u32>,
+
+    /// The function symbols of the ELF, used to symbolize backtraces.
+    #[serde(skip)]
+    pub symbols: Option<Arc<SymbolTable>>,
 }
@@ -0,0 +1,100 @@
+use std::sync::mpsc::{Sender, SyncSender};
+
+/// An event reported as proving a program progresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// The program finished executing after `cycles` cycles.
+    ExecutionFinished { cycles: u64 },
+
+    /// The main trace of the shard at index `shard` was committed to.
+    ShardCommitted { shard: usize },
+
+    /// The shard at index `shard` was proven, out of `total` shards.
+    ///
+    /// Shards are proven in parallel, so they are not necessarily reported in order.
+    ShardProven { shard: usize, total: usize },
+
+    /// The layer `layer` of the recursion tree compressing the shard proofs was completed,
+    /// leaving `proofs` proofs to compress.
+    ///
+    /// The first layer proves the shard proofs and the deferred proofs, and the last layer leaves
+    /// a single proof.
+    RecursionLayerCompleted { layer: usize, proofs: usize },
+
+    /// Wrapping the compressed proof into a SNARK started.
+    WrapStarted,
+}
+
+/// A handler for the [ProgressEvent]s reported while proving.
+///
+/// Implemented for closures and for the sending half of channels, which drop the events once the
+/// receiver is gone.
+pub trait ProgressHandler: Send + Sync {
+    fn on_progress(&self, event: ProgressEvent);
+}
+
+impl<F> ProgressHandler for F
+where
+    F: Fn(ProgressEvent) + Send + Sync,
+{
+    fn on_progress(&self, event: ProgressEvent) {
+        self(event)
+    }
+}
+
+impl ProgressHandler for Sender<ProgressEvent> {
+    fn on_progress(&self, event: ProgressEvent) {
+        let _ = self.send(event);
+    }
+}
+
+impl ProgressHandler for SyncSender<ProgressEvent> {
+    fn on_progress(&self, event: ProgressEvent) {
+        let _ = self.send(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+
+    use super::ProgressEvent;
+    use crate::runtime::tests::simple_program;
+    use crate::runtime::SP1Context;
+    use crate::stark::{DefaultProver, MachineProver, RiscvAir};
+    use crate::utils::{prove_with_context, BabyBearPoseidon2, SP1CoreOpts};
+
+    #[test]
+    fn test_prove_progress() {
+        let (tx, rx) = channel();
+        let context = SP1Context::builder().progress_handler(tx).build();
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let prover = DefaultProver::new(machine);
+        let (proof, _, cycles) = prove_with_context::<_, _>(
+            &prover,
+            simple_program(),
+            &Default::default(),
+            SP1CoreOpts::default(),
+            context,
+        )
+        .unwrap();
+
+        let events = rx.try_iter().collect::<Vec<_>>();
+        let total = proof.shard_proofs.len();
+        assert_eq!(events[0], ProgressEvent::ExecutionFinished { cycles });
+        let committed = events[1..=total].to_vec();
+        let expected = (0..total)
+            .map(|shard| ProgressEvent::ShardCommitted { shard })
+            .collect::<Vec<_>>();
+        assert_eq!(committed, expected);
+        let mut proven = events[total + 1..].to_vec();
+        proven.sort_by_key(|event| match event {
+            ProgressEvent::ShardProven { shard, .. } => *shard,
+            _ => usize::MAX,
+        });
+        let expected = (0..total)
+            .map(|shard| ProgressEvent::ShardProven { shard, total })
+            .collect::<Vec<_>>();
+        assert_eq!(proven, expected);
+    }
+}
@@ -17,9 +17,12 @@ use crate::cpu::CpuEvent;
 use crate::runtime::MemoryInitializeFinalizeEvent;
 use crate::runtime::MemoryRecordEnum;
 use crate::stark::MachineRecord;
+use crate::syscall::precompiles::blake2b::Blake2bCompressEvent;
 use crate::syscall::precompiles::edwards::EdDecompressEvent;
+use crate::syscall::precompiles::fptower::{Fp2AddSubEvent, Fp2MulEvent, FpOpEvent};
 use crate::syscall::precompiles::keccak256::KeccakPermuteEvent;
 use crate::syscall::precompiles::sha256::{ShaCompressEvent, ShaExtendEvent};
+use crate::syscall::precompiles::sha512::{Sha512CompressEvent, Sha512ExtendEvent};
 use crate::syscall::precompiles::uint256::Uint256MulEvent;
 use crate::syscall::precompiles::ECDecompressEvent;
 use crate::syscall::precompiles::{ECAddEvent, ECDoubleEvent};
@@ -70,6 +73,12 @@ pub struct ExecutionRecord {
 
     pub sha_compress_events: Vec<ShaCompressEvent>,
 
+    pub sha512_extend_events: Vec<Sha512ExtendEvent>,
+
+    pub sha512_compress_events: Vec<Sha512CompressEvent>,
+
+    pub blake2b_compress_events: Vec<Blake2bCompressEvent>,
+
     pub keccak_permute_events: Vec<KeccakPermuteEvent>,
 
     pub ed_add_events: Vec<ECAddEvent>,
@@ -98,18 +107,39 @@ pub struct ExecutionRecord {
 
     pub bls12381_decompress_events: Vec<ECDecompressEvent>,
 
+    pub secp256r1_add_events: Vec<ECAddEvent>,
+
+    pub secp256r1_double_events: Vec<ECDoubleEvent>,
+
+    pub secp256r1_decompress_events: Vec<ECDecompressEvent>,
+
+    pub bn254_fp_events: Vec<FpOpEvent>,
+
+    pub bn254_fp2_addsub_events: Vec<Fp2AddSubEvent>,
+
+    pub bn254_fp2_mul_events: Vec<Fp2MulEvent>,
+
+    pub bls12381_fp_events: Vec<FpOpEvent>,
+
+    pub bls12381_fp2_addsub_events: Vec<Fp2AddSubEvent>,
+
+    pub bls12381_fp2_mul_events: Vec<Fp2MulEvent>,
+
     /// The public values.
     pub public_values: PublicValues<u32, u32>,
 
     pub nonce_lookup: HashMap<u128, u32>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SplitOpts {
     pub deferred_shift_threshold: usize,
     pub keccak_split_threshold: usize,
     pub sha_extend_split_threshold: usize,
     pub sha_compress_split_threshold: usize,
+    pub sha512_extend_split_threshold: usize,
+    pub sha512_compress_split_threshold: usize,
+    pub blake2b_compress_split_threshold: usize,
     pub memory_split_threshold: usize,
 }
 
@@ -120,6 +150,9 @@ impl SplitOpts {
             keccak_split_threshold: deferred_shift_threshold / 24,
             sha_extend_split_threshold: deferred_shift_threshold / 48,
             sha_compress_split_threshold: deferred_shift_threshold / 80,
+            sha512_extend_split_threshold: deferred_shift_threshold / 64,
+            sha512_compress_split_threshold: deferred_shift_threshold / 96,
+            blake2b_compress_split_threshold: deferred_shift_threshold / 12,
             memory_split_threshold: deferred_shift_threshold,
         }
     }
@@ -153,6 +186,18 @@ impl MachineRecord for ExecutionRecord {
             "sha_compress_events".to_string(),
             self.sha_compress_events.len(),
         );
+        stats.insert(
+            "sha512_extend_events".to_string(),
+            self.sha512_extend_events.len(),
+        );
+        stats.insert(
+            "sha512_compress_events".to_string(),
+            self.sha512_compress_events.len(),
+        );
+        stats.insert(
+            "blake2b_compress_events".to_string(),
+            self.blake2b_compress_events.len(),
+        );
         stats.insert(
             "keccak_permute_events".to_string(),
             self.keccak_permute_events.len(),
@@ -195,6 +240,39 @@ impl MachineRecord for ExecutionRecord {
             "bls12381_decompress_events".to_string(),
             self.bls12381_decompress_events.len(),
         );
+        stats.insert(
+            "secp256r1_add_events".to_string(),
+            self.secp256r1_add_events.len(),
+        );
+        stats.insert(
+            "secp256r1_double_events".to_string(),
+            self.secp256r1_double_events.len(),
+        );
+        stats.insert(
+            "secp256r1_decompress_events".to_string(),
+            self.secp256r1_decompress_events.len(),
+        );
+        stats.insert("bn254_fp_events".to_string(), self.bn254_fp_events.len());
+        stats.insert(
+            "bn254_fp2_addsub_events".to_string(),
+            self.bn254_fp2_addsub_events.len(),
+        );
+        stats.insert(
+            "bn254_fp2_mul_events".to_string(),
+            self.bn254_fp2_mul_events.len(),
+        );
+        stats.insert(
+            "bls12381_fp_events".to_string(),
+            self.bls12381_fp_events.len(),
+        );
+        stats.insert(
+            "bls12381_fp2_addsub_events".to_string(),
+            self.bls12381_fp2_addsub_events.len(),
+        );
+        stats.insert(
+            "bls12381_fp2_mul_events".to_string(),
+            self.bls12381_fp2_mul_events.len(),
+        );
         stats.insert(
             "memory_initialize_events".to_string(),
             self.memory_initialize_events.len(),
@@ -229,6 +307,12 @@ impl MachineRecord for ExecutionRecord {
         self.sha_extend_events.append(&mut other.sha_extend_events);
         self.sha_compress_events
             .append(&mut other.sha_compress_events);
+        self.sha512_extend_events
+            .append(&mut other.sha512_extend_events);
+        self.sha512_compress_events
+            .append(&mut other.sha512_compress_events);
+        self.blake2b_compress_events
+            .append(&mut other.blake2b_compress_events);
         self.keccak_permute_events
             .append(&mut other.keccak_permute_events);
         self.ed_add_events.append(&mut other.ed_add_events);
@@ -251,6 +335,23 @@ impl MachineRecord for ExecutionRecord {
             .append(&mut other.uint256_mul_events);
         self.bls12381_decompress_events
             .append(&mut other.bls12381_decompress_events);
+        self.secp256r1_add_events
+            .append(&mut other.secp256r1_add_events);
+        self.secp256r1_double_events
+            .append(&mut other.secp256r1_double_events);
+        self.secp256r1_decompress_events
+            .append(&mut other.secp256r1_decompress_events);
+        self.bn254_fp_events.append(&mut other.bn254_fp_events);
+        self.bn254_fp2_addsub_events
+            .append(&mut other.bn254_fp2_addsub_events);
+        self.bn254_fp2_mul_events
+            .append(&mut other.bn254_fp2_mul_events);
+        self.bls12381_fp_events
+            .append(&mut other.bls12381_fp_events);
+        self.bls12381_fp2_addsub_events
+            .append(&mut other.bls12381_fp2_addsub_events);
+        self.bls12381_fp2_mul_events
+            .append(&mut other.bls12381_fp2_mul_events);
 
         if self.byte_lookups.is_empty() {
             self.byte_lookups = std::mem::take(&mut other.byte_lookups);
@@ -379,11 +480,23 @@ impl ExecutionRecord {
             bls12381_double_events: std::mem::take(&mut self.bls12381_double_events),
             sha_extend_events: std::mem::take(&mut self.sha_extend_events),
             sha_compress_events: std::mem::take(&mut self.sha_compress_events),
+            sha512_extend_events: std::mem::take(&mut self.sha512_extend_events),
+            sha512_compress_events: std::mem::take(&mut self.sha512_compress_events),
+            blake2b_compress_events: std::mem::take(&mut self.blake2b_compress_events),
             ed_add_events: std::mem::take(&mut self.ed_add_events),
             ed_decompress_events: std::mem::take(&mut self.ed_decompress_events),
             k256_decompress_events: std::mem::take(&mut self.k256_decompress_events),
             uint256_mul_events: std::mem::take(&mut self.uint256_mul_events),
             bls12381_decompress_events: std::mem::take(&mut self.bls12381_decompress_events),
+            secp256r1_add_events: std::mem::take(&mut self.secp256r1_add_events),
+            secp256r1_double_events: std::mem::take(&mut self.secp256r1_double_events),
+            secp256r1_decompress_events: std::mem::take(&mut self.secp256r1_decompress_events),
+            bn254_fp_events: std::mem::take(&mut self.bn254_fp_events),
+            bn254_fp2_addsub_events: std::mem::take(&mut self.bn254_fp2_addsub_events),
+            bn254_fp2_mul_events: std::mem::take(&mut self.bn254_fp2_mul_events),
+            bls12381_fp_events: std::mem::take(&mut self.bls12381_fp_events),
+            bls12381_fp2_addsub_events: std::mem::take(&mut self.bls12381_fp2_addsub_events),
+            bls12381_fp2_mul_events: std::mem::take(&mut self.bls12381_fp2_mul_events),
             memory_initialize_events: std::mem::take(&mut self.memory_initialize_events),
             memory_finalize_events: std::mem::take(&mut self.memory_finalize_events),
             ..Default::default()
@@ -485,6 +598,27 @@ impl ExecutionRecord {
             opts.sha_compress_split_threshold,
             last
         );
+        split_events!(
+            self,
+            sha512_extend_events,
+            shards,
+            opts.sha512_extend_split_threshold,
+            last
+        );
+        split_events!(
+            self,
+            sha512_compress_events,
+            shards,
+            opts.sha512_compress_split_threshold,
+            last
+        );
+        split_events!(
+            self,
+            blake2b_compress_events,
+            shards,
+            opts.blake2b_compress_split_threshold,
+            last
+        );
         split_events!(
             self,
             ed_add_events,
@@ -520,6 +654,69 @@ impl ExecutionRecord {
             opts.deferred_shift_threshold,
             last
         );
+        split_events!(
+            self,
+            secp256r1_add_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
+        split_events!(
+            self,
+            secp256r1_double_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
+        split_events!(
+            self,
+            secp256r1_decompress_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
+        split_events!(
+            self,
+            bn254_fp_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
+        split_events!(
+            self,
+            bn254_fp2_addsub_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
+        split_events!(
+            self,
+            bn254_fp2_mul_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
+        split_events!(
+            self,
+            bls12381_fp_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
+        split_events!(
+            self,
+            bls12381_fp2_addsub_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
+        split_events!(
+            self,
+            bls12381_fp2_mul_events,
+            shards,
+            opts.deferred_shift_threshold,
+            last
+        );
 
         if last {
             self.memory_initialize_events
@@ -10,9 +10,78 @@ use super::*;
 pub struct ExecutionReport {
     pub opcode_counts: HashMap<Opcode, u64>,
     pub syscall_counts: HashMap<SyscallCode, u64>,
+    /// The spans delimited by `cycle-tracker-start`/`cycle-tracker-end`, in order of first start.
+    pub cycle_tracker: Vec<CycleTrackerSpan>,
+}
+
+/// The cycles spent in a cycle-tracker span, aggregated over all of its invocations at the same
+/// depth.
+///
+/// Spans with the same name that are nested within each other (e.g. in a recursive function) are
+/// tracked separately for each depth, so that their cycles are not counted twice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleTrackerSpan {
+    /// The label of the span.
+    pub name: String,
+    /// The number of spans enclosing this span.
+    pub depth: u32,
+    /// The number of times the span was entered.
+    pub count: u64,
+    /// The total number of cycles spent in the span.
+    pub total_cycles: u64,
+    /// The minimum number of cycles spent in a single invocation of the span.
+    pub min_cycles: u64,
+    /// The maximum number of cycles spent in a single invocation of the span.
+    pub max_cycles: u64,
+}
+
+impl CycleTrackerSpan {
+    /// The average number of cycles spent in a single invocation of the span.
+    pub fn avg_cycles(&self) -> u64 {
+        self.total_cycles / self.count.max(1)
+    }
+
+    /// Merge the invocations of `other` into `self`.
+    fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.total_cycles += other.total_cycles;
+        self.min_cycles = self.min_cycles.min(other.min_cycles);
+        self.max_cycles = self.max_cycles.max(other.max_cycles);
+    }
 }
 
 impl ExecutionReport {
+    /// Record an invocation of the cycle-tracker span `name` at `depth` which took `cycles`.
+    pub fn record_cycle_tracker_span(&mut self, name: &str, depth: u32, cycles: u64) {
+        self.merge_cycle_tracker_span(CycleTrackerSpan {
+            name: name.to_string(),
+            depth,
+            count: 1,
+            total_cycles: cycles,
+            min_cycles: cycles,
+            max_cycles: cycles,
+        });
+    }
+
+    fn merge_cycle_tracker_span(&mut self, span: CycleTrackerSpan) {
+        match self
+            .cycle_tracker
+            .iter_mut()
+            .find(|s| s.name == span.name && s.depth == span.depth)
+        {
+            Some(existing) => existing.merge(&span),
+            None => self.cycle_tracker.push(span),
+        }
+    }
+
+    /// Get the outermost cycle-tracker span with the given name.
+    pub fn cycle_tracker_span(&self, name: &str) -> Option<&CycleTrackerSpan> {
+        self.cycle_tracker
+            .iter()
+            .filter(|span| span.name == name)
+            .min_by_key(|span| span.depth)
+    }
+
     /// Compute the total number of instructions run during the execution.
     pub fn total_instruction_count(&self) -> u64 {
         self.opcode_counts.values().sum()
@@ -76,6 +145,9 @@ impl AddAssign for ExecutionReport {
     fn add_assign(&mut self, rhs: Self) {
         hashmap_add_assign(&mut self.opcode_counts, rhs.opcode_counts);
         hashmap_add_assign(&mut self.syscall_counts, rhs.syscall_counts);
+        for span in rhs.cycle_tracker {
+            self.merge_cycle_tracker_span(span);
+        }
     }
 }
 
@@ -108,6 +180,47 @@ impl Display for ExecutionReport {
             writeln!(f, "  {line}")?;
         }
 
+        if !self.cycle_tracker.is_empty() {
+            writeln!(f, "cycle tracker spans:")?;
+            for span in self.cycle_tracker.iter() {
+                let padding = "  ".repeat(span.depth as usize);
+                writeln!(
+                    f,
+                    "  {padding}{}: {} cycles over {} invocations (min {}, max {})",
+                    span.name, span.total_cycles, span.count, span.min_cycles, span.max_cycles
+                )?;
+            }
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ExecutionReport;
+
+    #[test]
+    fn test_cycle_tracker_spans() {
+        let mut report = ExecutionReport::default();
+        report.record_cycle_tracker_span("a", 0, 10);
+        report.record_cycle_tracker_span("a", 1, 4);
+        report.record_cycle_tracker_span("a", 0, 20);
+
+        let mut other = ExecutionReport::default();
+        other.record_cycle_tracker_span("b", 0, 7);
+        other.record_cycle_tracker_span("a", 0, 5);
+        report += other;
+
+        assert_eq!(report.cycle_tracker.len(), 3);
+        let a = report.cycle_tracker_span("a").unwrap();
+        assert_eq!(a.depth, 0);
+        assert_eq!(a.count, 3);
+        assert_eq!(a.total_cycles, 35);
+        assert_eq!(a.min_cycles, 5);
+        assert_eq!(a.max_cycles, 20);
+        assert_eq!(a.avg_cycles(), 11);
+        assert_eq!(report.cycle_tracker_span("b").unwrap().count, 1);
+        assert!(report.cycle_tracker_span("c").is_none());
+    }
+}
@@ -5,11 +5,15 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
+use crate::operations::field::field_op::FieldOperation;
 use crate::runtime::{Register, Runtime};
+use crate::syscall::precompiles::blake2b::Blake2bCompressChip;
 use crate::syscall::precompiles::edwards::EdAddAssignChip;
 use crate::syscall::precompiles::edwards::EdDecompressChip;
+use crate::syscall::precompiles::fptower::{Fp2AddSubSyscall, Fp2MulAssignChip, FpOpSyscall};
 use crate::syscall::precompiles::keccak256::KeccakPermuteChip;
 use crate::syscall::precompiles::sha256::{ShaCompressChip, ShaExtendChip};
+use crate::syscall::precompiles::sha512::{Sha512CompressChip, Sha512ExtendChip};
 use crate::syscall::precompiles::uint256::Uint256MulChip;
 use crate::syscall::precompiles::weierstrass::WeierstrassAddAssignChip;
 use crate::syscall::precompiles::weierstrass::WeierstrassDecompressChip;
@@ -19,8 +23,9 @@ use crate::syscall::{
     SyscallHalt, SyscallHintLen, SyscallHintRead, SyscallVerifySP1Proof, SyscallWrite,
 };
 use crate::utils::ec::edwards::ed25519::{Ed25519, Ed25519Parameters};
-use crate::utils::ec::weierstrass::bls12_381::Bls12381;
-use crate::utils::ec::weierstrass::{bn254::Bn254, secp256k1::Secp256k1};
+use crate::utils::ec::weierstrass::bls12_381::{Bls12381, Bls12381BaseField};
+use crate::utils::ec::weierstrass::bn254::Bn254BaseField;
+use crate::utils::ec::weierstrass::{bn254::Bn254, secp256k1::Secp256k1, secp256r1::Secp256r1};
 use crate::{runtime::ExecutionRecord, runtime::MemoryReadRecord, runtime::MemoryWriteRecord};
 
 /// A system call is invoked by the the `ecall` instruction with a specific value in register t0.
@@ -102,12 +107,73 @@ pub enum SyscallCode {
 
     /// Executes the `BLS12381_DOUBLE` precompile.
     BLS12381_DOUBLE = 0x00_00_01_1F,
+
+    /// Executes the `SHA512_EXTEND` precompile.
+    SHA512_EXTEND = 0x00_40_01_20,
+
+    /// Executes the `SHA512_COMPRESS` precompile.
+    SHA512_COMPRESS = 0x00_01_01_21,
+
+    /// Executes the `BLAKE2B_COMPRESS` precompile.
+    BLAKE2B_COMPRESS = 0x00_01_01_22,
+
+    /// Executes the `SECP256R1_ADD` precompile.
+    SECP256R1_ADD = 0x00_01_01_23,
+
+    /// Executes the `SECP256R1_DOUBLE` precompile.
+    SECP256R1_DOUBLE = 0x00_00_01_24,
+
+    /// Executes the `SECP256R1_DECOMPRESS` precompile.
+    SECP256R1_DECOMPRESS = 0x00_00_01_25,
+
+    /// Executes the `BN254_FP_ADD` precompile.
+    BN254_FP_ADD = 0x00_01_01_26,
+
+    /// Executes the `BN254_FP_SUB` precompile.
+    BN254_FP_SUB = 0x00_01_01_27,
+
+    /// Executes the `BN254_FP_MUL` precompile.
+    BN254_FP_MUL = 0x00_01_01_28,
+
+    /// Executes the `BN254_FP2_ADD` precompile.
+    BN254_FP2_ADD = 0x00_01_01_29,
+
+    /// Executes the `BN254_FP2_SUB` precompile.
+    BN254_FP2_SUB = 0x00_01_01_2A,
+
+    /// Executes the `BN254_FP2_MUL` precompile.
+    BN254_FP2_MUL = 0x00_01_01_2B,
+
+    /// Executes the `BLS12381_FP_ADD` precompile.
+    BLS12381_FP_ADD = 0x00_01_01_2C,
+
+    /// Executes the `BLS12381_FP_SUB` precompile.
+    BLS12381_FP_SUB = 0x00_01_01_2D,
+
+    /// Executes the `BLS12381_FP_MUL` precompile.
+    BLS12381_FP_MUL = 0x00_01_01_2E,
+
+    /// Executes the `BLS12381_FP2_ADD` precompile.
+    BLS12381_FP2_ADD = 0x00_01_01_2F,
+
+    /// Executes the `BLS12381_FP2_SUB` precompile.
+    BLS12381_FP2_SUB = 0x00_01_01_30,
+
+    /// Executes the `BLS12381_FP2_MUL` precompile.
+    BLS12381_FP2_MUL = 0x00_01_01_31,
 }
 
 impl SyscallCode {
     /// Create a syscall from a u32.
+    ///
+    /// Panics if the value is not a known syscall code, see [Self::try_from_u32].
     pub fn from_u32(value: u32) -> Self {
-        match value {
+        Self::try_from_u32(value).unwrap_or_else(|| panic!("invalid syscall number: {}", value))
+    }
+
+    /// Create a syscall from a u32, returning `None` if the value is not a known syscall code.
+    pub fn try_from_u32(value: u32) -> Option<Self> {
+        let syscall = match value {
             0x00_00_00_00 => SyscallCode::HALT,
             0x00_00_00_02 => SyscallCode::WRITE,
             0x00_00_00_03 => SyscallCode::ENTER_UNCONSTRAINED,
@@ -131,8 +197,27 @@ impl SyscallCode {
             0x00_00_00_F1 => SyscallCode::HINT_READ,
             0x00_01_01_1D => SyscallCode::UINT256_MUL,
             0x00_00_01_1C => SyscallCode::BLS12381_DECOMPRESS,
-            _ => panic!("invalid syscall number: {}", value),
-        }
+            0x00_40_01_20 => SyscallCode::SHA512_EXTEND,
+            0x00_01_01_21 => SyscallCode::SHA512_COMPRESS,
+            0x00_01_01_22 => SyscallCode::BLAKE2B_COMPRESS,
+            0x00_01_01_23 => SyscallCode::SECP256R1_ADD,
+            0x00_00_01_24 => SyscallCode::SECP256R1_DOUBLE,
+            0x00_00_01_25 => SyscallCode::SECP256R1_DECOMPRESS,
+            0x00_01_01_26 => SyscallCode::BN254_FP_ADD,
+            0x00_01_01_27 => SyscallCode::BN254_FP_SUB,
+            0x00_01_01_28 => SyscallCode::BN254_FP_MUL,
+            0x00_01_01_29 => SyscallCode::BN254_FP2_ADD,
+            0x00_01_01_2A => SyscallCode::BN254_FP2_SUB,
+            0x00_01_01_2B => SyscallCode::BN254_FP2_MUL,
+            0x00_01_01_2C => SyscallCode::BLS12381_FP_ADD,
+            0x00_01_01_2D => SyscallCode::BLS12381_FP_SUB,
+            0x00_01_01_2E => SyscallCode::BLS12381_FP_MUL,
+            0x00_01_01_2F => SyscallCode::BLS12381_FP2_ADD,
+            0x00_01_01_30 => SyscallCode::BLS12381_FP2_SUB,
+            0x00_01_01_31 => SyscallCode::BLS12381_FP2_MUL,
+            _ => return None,
+        };
+        Some(syscall)
     }
 
     pub fn syscall_id(&self) -> u32 {
@@ -154,6 +239,39 @@ impl fmt::Display for SyscallCode {
     }
 }
 
+/// A handler for syscalls that are unknown to the runtime.
+///
+/// Handlers are only invoked when the runtime does not emit events (i.e. when only executing the
+/// program), since unknown syscalls cannot be proven. Otherwise, unknown syscalls result in
+/// [ExecutionError::UnsupportedSyscall](super::ExecutionError::UnsupportedSyscall).
+pub trait UnsupportedSyscallHandler: Send + Sync {
+    /// Execute the syscall with the id `syscall_id` and return the resulting value of register
+    /// t0, or `None` to keep the syscall id in t0. `arg1` and `arg2` are the values in registers
+    /// X10 and X11, respectively.
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_id: u32,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32>;
+}
+
+impl<F> UnsupportedSyscallHandler for F
+where
+    F: Fn(&mut SyscallContext, u32, u32, u32) -> Option<u32> + Send + Sync,
+{
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_id: u32,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        self(ctx, syscall_id, arg1, arg2)
+    }
+}
+
 pub trait Syscall: Send + Sync {
     /// Execute the syscall and return the resulting value of register a0. `arg1` and `arg2` are the
     /// values in registers X10 and X11, respectively. While not a hard requirement, the convention
@@ -272,6 +390,18 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
     syscall_map.insert(SyscallCode::HALT, Arc::new(SyscallHalt {}));
     syscall_map.insert(SyscallCode::SHA_EXTEND, Arc::new(ShaExtendChip::new()));
     syscall_map.insert(SyscallCode::SHA_COMPRESS, Arc::new(ShaCompressChip::new()));
+    syscall_map.insert(
+        SyscallCode::SHA512_EXTEND,
+        Arc::new(Sha512ExtendChip::new()),
+    );
+    syscall_map.insert(
+        SyscallCode::SHA512_COMPRESS,
+        Arc::new(Sha512CompressChip::new()),
+    );
+    syscall_map.insert(
+        SyscallCode::BLAKE2B_COMPRESS,
+        Arc::new(Blake2bCompressChip::new()),
+    );
     syscall_map.insert(
         SyscallCode::ED_ADD,
         Arc::new(EdAddAssignChip::<Ed25519>::new()),
@@ -296,6 +426,18 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         SyscallCode::SECP256K1_DECOMPRESS,
         Arc::new(WeierstrassDecompressChip::<Secp256k1>::with_lsb_rule()),
     );
+    syscall_map.insert(
+        SyscallCode::SECP256R1_ADD,
+        Arc::new(WeierstrassAddAssignChip::<Secp256r1>::new()),
+    );
+    syscall_map.insert(
+        SyscallCode::SECP256R1_DOUBLE,
+        Arc::new(WeierstrassDoubleAssignChip::<Secp256r1>::new()),
+    );
+    syscall_map.insert(
+        SyscallCode::SECP256R1_DECOMPRESS,
+        Arc::new(WeierstrassDecompressChip::<Secp256r1>::with_lsb_rule()),
+    );
     syscall_map.insert(
         SyscallCode::BN254_ADD,
         Arc::new(WeierstrassAddAssignChip::<Bn254>::new()),
@@ -313,6 +455,58 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(WeierstrassDoubleAssignChip::<Bls12381>::new()),
     );
     syscall_map.insert(SyscallCode::UINT256_MUL, Arc::new(Uint256MulChip::new()));
+    syscall_map.insert(
+        SyscallCode::BN254_FP_ADD,
+        Arc::new(FpOpSyscall::<Bn254BaseField>::new(FieldOperation::Add)),
+    );
+    syscall_map.insert(
+        SyscallCode::BN254_FP_SUB,
+        Arc::new(FpOpSyscall::<Bn254BaseField>::new(FieldOperation::Sub)),
+    );
+    syscall_map.insert(
+        SyscallCode::BN254_FP_MUL,
+        Arc::new(FpOpSyscall::<Bn254BaseField>::new(FieldOperation::Mul)),
+    );
+    syscall_map.insert(
+        SyscallCode::BN254_FP2_ADD,
+        Arc::new(Fp2AddSubSyscall::<Bn254BaseField>::new(FieldOperation::Add)),
+    );
+    syscall_map.insert(
+        SyscallCode::BN254_FP2_SUB,
+        Arc::new(Fp2AddSubSyscall::<Bn254BaseField>::new(FieldOperation::Sub)),
+    );
+    syscall_map.insert(
+        SyscallCode::BN254_FP2_MUL,
+        Arc::new(Fp2MulAssignChip::<Bn254BaseField>::new()),
+    );
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP_ADD,
+        Arc::new(FpOpSyscall::<Bls12381BaseField>::new(FieldOperation::Add)),
+    );
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP_SUB,
+        Arc::new(FpOpSyscall::<Bls12381BaseField>::new(FieldOperation::Sub)),
+    );
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP_MUL,
+        Arc::new(FpOpSyscall::<Bls12381BaseField>::new(FieldOperation::Mul)),
+    );
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP2_ADD,
+        Arc::new(Fp2AddSubSyscall::<Bls12381BaseField>::new(
+            FieldOperation::Add,
+        )),
+    );
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP2_SUB,
+        Arc::new(Fp2AddSubSyscall::<Bls12381BaseField>::new(
+            FieldOperation::Sub,
+        )),
+    );
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP2_MUL,
+        Arc::new(Fp2MulAssignChip::<Bls12381BaseField>::new()),
+    );
     syscall_map.insert(
         SyscallCode::ENTER_UNCONSTRAINED,
         Arc::new(SyscallEnterUnconstrained::new()),
@@ -368,6 +562,7 @@ mod tests {
         for (syscall_code, _) in default_syscall_map().iter() {
             assert_eq!(SyscallCode::from_u32(*syscall_code as u32), *syscall_code);
         }
+        assert_eq!(SyscallCode::try_from_u32(0x00_00_00_FF), None);
     }
 
     #[test]
@@ -387,6 +582,15 @@ mod tests {
                 SyscallCode::SHA_COMPRESS => {
                     assert_eq!(code as u32, sp1_zkvm::syscalls::SHA_COMPRESS)
                 }
+                SyscallCode::SHA512_EXTEND => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::SHA512_EXTEND)
+                }
+                SyscallCode::SHA512_COMPRESS => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::SHA512_COMPRESS)
+                }
+                SyscallCode::BLAKE2B_COMPRESS => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BLAKE2B_COMPRESS)
+                }
                 SyscallCode::ED_ADD => assert_eq!(code as u32, sp1_zkvm::syscalls::ED_ADD),
                 SyscallCode::ED_DECOMPRESS => {
                     assert_eq!(code as u32, sp1_zkvm::syscalls::ED_DECOMPRESS)
@@ -409,6 +613,51 @@ mod tests {
                 SyscallCode::SECP256K1_DECOMPRESS => {
                     assert_eq!(code as u32, sp1_zkvm::syscalls::SECP256K1_DECOMPRESS)
                 }
+                SyscallCode::SECP256R1_ADD => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::SECP256R1_ADD)
+                }
+                SyscallCode::SECP256R1_DOUBLE => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::SECP256R1_DOUBLE)
+                }
+                SyscallCode::SECP256R1_DECOMPRESS => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::SECP256R1_DECOMPRESS)
+                }
+                SyscallCode::BN254_FP_ADD => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BN254_FP_ADD)
+                }
+                SyscallCode::BN254_FP_SUB => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BN254_FP_SUB)
+                }
+                SyscallCode::BN254_FP_MUL => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BN254_FP_MUL)
+                }
+                SyscallCode::BN254_FP2_ADD => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BN254_FP2_ADD)
+                }
+                SyscallCode::BN254_FP2_SUB => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BN254_FP2_SUB)
+                }
+                SyscallCode::BN254_FP2_MUL => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BN254_FP2_MUL)
+                }
+                SyscallCode::BLS12381_FP_ADD => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BLS12381_FP_ADD)
+                }
+                SyscallCode::BLS12381_FP_SUB => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BLS12381_FP_SUB)
+                }
+                SyscallCode::BLS12381_FP_MUL => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BLS12381_FP_MUL)
+                }
+                SyscallCode::BLS12381_FP2_ADD => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BLS12381_FP2_ADD)
+                }
+                SyscallCode::BLS12381_FP2_SUB => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BLS12381_FP2_SUB)
+                }
+                SyscallCode::BLS12381_FP2_MUL => {
+                    assert_eq!(code as u32, sp1_zkvm::syscalls::BLS12381_FP2_MUL)
+                }
                 SyscallCode::BN254_ADD => assert_eq!(code as u32, sp1_zkvm::syscalls::BN254_ADD),
                 SyscallCode::BN254_DOUBLE => {
                     assert_eq!(code as u32, sp1_zkvm::syscalls::BN254_DOUBLE)
@@ -21,20 +21,29 @@ pub(crate) mod riscv_chips {
     pub use crate::cpu::CpuChip;
     pub use crate::memory::MemoryChip;
     pub use crate::program::ProgramChip;
+    pub use crate::syscall::precompiles::blake2b::Blake2bCompressChip;
     pub use crate::syscall::precompiles::edwards::EdAddAssignChip;
     pub use crate::syscall::precompiles::edwards::EdDecompressChip;
+    pub use crate::syscall::precompiles::fptower::Fp2AddSubChip;
+    pub use crate::syscall::precompiles::fptower::Fp2MulAssignChip;
+    pub use crate::syscall::precompiles::fptower::FpOpChip;
     pub use crate::syscall::precompiles::keccak256::KeccakPermuteChip;
     pub use crate::syscall::precompiles::sha256::ShaCompressChip;
     pub use crate::syscall::precompiles::sha256::ShaExtendChip;
+    pub use crate::syscall::precompiles::sha512::Sha512CompressChip;
+    pub use crate::syscall::precompiles::sha512::Sha512ExtendChip;
     pub use crate::syscall::precompiles::uint256::Uint256MulChip;
     pub use crate::syscall::precompiles::weierstrass::WeierstrassAddAssignChip;
     pub use crate::syscall::precompiles::weierstrass::WeierstrassDecompressChip;
     pub use crate::syscall::precompiles::weierstrass::WeierstrassDoubleAssignChip;
     pub use crate::utils::ec::edwards::ed25519::Ed25519Parameters;
     pub use crate::utils::ec::edwards::EdwardsCurve;
+    pub use crate::utils::ec::weierstrass::bls12_381::Bls12381BaseField;
     pub use crate::utils::ec::weierstrass::bls12_381::Bls12381Parameters;
+    pub use crate::utils::ec::weierstrass::bn254::Bn254BaseField;
     pub use crate::utils::ec::weierstrass::bn254::Bn254Parameters;
     pub use crate::utils::ec::weierstrass::secp256k1::Secp256k1Parameters;
+    pub use crate::utils::ec::weierstrass::secp256r1::Secp256r1Parameters;
     pub use crate::utils::ec::weierstrass::SwCurve;
 }
 
@@ -75,6 +84,12 @@ pub enum RiscvAir<F: PrimeField32> {
     Sha256Extend(ShaExtendChip),
     /// A precompile for sha256 compress.
     Sha256Compress(ShaCompressChip),
+    /// A precompile for sha512 extend.
+    Sha512Extend(Sha512ExtendChip),
+    /// A precompile for sha512 compress.
+    Sha512Compress(Sha512CompressChip),
+    /// A precompile for the blake2b compression function.
+    Blake2bCompress(Blake2bCompressChip),
     /// A precompile for addition on the Elliptic curve ed25519.
     Ed25519Add(EdAddAssignChip<EdwardsCurve<Ed25519Parameters>>),
     /// A precompile for decompressing a point on the Edwards curve ed25519.
@@ -99,6 +114,24 @@ pub enum RiscvAir<F: PrimeField32> {
     Uint256Mul(Uint256MulChip),
     /// A precompile for decompressing a point on the BLS12-381 curve.
     Bls12381Decompress(WeierstrassDecompressChip<SwCurve<Bls12381Parameters>>),
+    /// A precompile for addition on the Elliptic curve secp256r1.
+    Secp256r1Add(WeierstrassAddAssignChip<SwCurve<Secp256r1Parameters>>),
+    /// A precompile for doubling a point on the Elliptic curve secp256r1.
+    Secp256r1Double(WeierstrassDoubleAssignChip<SwCurve<Secp256r1Parameters>>),
+    /// A precompile for decompressing a point on the secp256r1 curve.
+    Secp256r1Decompress(WeierstrassDecompressChip<SwCurve<Secp256r1Parameters>>),
+    /// A precompile for BN254 base field addition, subtraction and multiplication.
+    Bn254Fp(FpOpChip<Bn254BaseField>),
+    /// A precompile for BN254 quadratic extension field addition and subtraction.
+    Bn254Fp2AddSub(Fp2AddSubChip<Bn254BaseField>),
+    /// A precompile for BN254 quadratic extension field multiplication.
+    Bn254Fp2Mul(Fp2MulAssignChip<Bn254BaseField>),
+    /// A precompile for BLS12-381 base field addition, subtraction and multiplication.
+    Bls12381Fp(FpOpChip<Bls12381BaseField>),
+    /// A precompile for BLS12-381 quadratic extension field addition and subtraction.
+    Bls12381Fp2AddSub(Fp2AddSubChip<Bls12381BaseField>),
+    /// A precompile for BLS12-381 quadratic extension field multiplication.
+    Bls12381Fp2Mul(Fp2MulAssignChip<Bls12381BaseField>),
 }
 
 impl<F: PrimeField32> RiscvAir<F> {
@@ -124,6 +157,12 @@ impl<F: PrimeField32> RiscvAir<F> {
         chips.push(RiscvAir::Sha256Extend(sha_extend));
         let sha_compress = ShaCompressChip::default();
         chips.push(RiscvAir::Sha256Compress(sha_compress));
+        let sha512_extend = Sha512ExtendChip::default();
+        chips.push(RiscvAir::Sha512Extend(sha512_extend));
+        let sha512_compress = Sha512CompressChip::default();
+        chips.push(RiscvAir::Sha512Compress(sha512_compress));
+        let blake2b_compress = Blake2bCompressChip::default();
+        chips.push(RiscvAir::Blake2bCompress(blake2b_compress));
         let ed_add_assign = EdAddAssignChip::<EdwardsCurve<Ed25519Parameters>>::new();
         chips.push(RiscvAir::Ed25519Add(ed_add_assign));
         let ed_decompress = EdDecompressChip::<Ed25519Parameters>::default();
@@ -151,6 +190,26 @@ impl<F: PrimeField32> RiscvAir<F> {
         let bls12381_decompress =
             WeierstrassDecompressChip::<SwCurve<Bls12381Parameters>>::with_lexicographic_rule();
         chips.push(RiscvAir::Bls12381Decompress(bls12381_decompress));
+        let secp256r1_add_assign = WeierstrassAddAssignChip::<SwCurve<Secp256r1Parameters>>::new();
+        chips.push(RiscvAir::Secp256r1Add(secp256r1_add_assign));
+        let secp256r1_double_assign =
+            WeierstrassDoubleAssignChip::<SwCurve<Secp256r1Parameters>>::new();
+        chips.push(RiscvAir::Secp256r1Double(secp256r1_double_assign));
+        let secp256r1_decompress =
+            WeierstrassDecompressChip::<SwCurve<Secp256r1Parameters>>::with_lsb_rule();
+        chips.push(RiscvAir::Secp256r1Decompress(secp256r1_decompress));
+        let bn254_fp = FpOpChip::<Bn254BaseField>::new();
+        chips.push(RiscvAir::Bn254Fp(bn254_fp));
+        let bn254_fp2_addsub = Fp2AddSubChip::<Bn254BaseField>::new();
+        chips.push(RiscvAir::Bn254Fp2AddSub(bn254_fp2_addsub));
+        let bn254_fp2_mul = Fp2MulAssignChip::<Bn254BaseField>::new();
+        chips.push(RiscvAir::Bn254Fp2Mul(bn254_fp2_mul));
+        let bls12381_fp = FpOpChip::<Bls12381BaseField>::new();
+        chips.push(RiscvAir::Bls12381Fp(bls12381_fp));
+        let bls12381_fp2_addsub = Fp2AddSubChip::<Bls12381BaseField>::new();
+        chips.push(RiscvAir::Bls12381Fp2AddSub(bls12381_fp2_addsub));
+        let bls12381_fp2_mul = Fp2MulAssignChip::<Bls12381BaseField>::new();
+        chips.push(RiscvAir::Bls12381Fp2Mul(bls12381_fp2_mul));
         let div_rem = DivRemChip::default();
         chips.push(RiscvAir::DivRem(div_rem));
         let add = AddSubChip::default();
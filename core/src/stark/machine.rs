@@ -356,27 +356,26 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
                 .collect::<Vec<_>>();
 
             // Generate the permutation traces.
-            let mut permutation_traces = Vec::with_capacity(chips.len());
-            let mut cumulative_sums = Vec::with_capacity(chips.len());
-            tracing::debug_span!("generate permutation traces").in_scope(|| {
-                chips
-                    .par_iter()
-                    .zip(traces.par_iter_mut())
-                    .map(|(chip, (main_trace, pre_trace))| {
-                        let perm_trace = chip.generate_permutation_trace(
-                            *pre_trace,
-                            main_trace,
-                            &permutation_challenges,
-                        );
-                        let cumulative_sum = perm_trace
-                            .row_slice(main_trace.height() - 1)
-                            .last()
-                            .copied()
-                            .unwrap();
-                        (perm_trace, cumulative_sum)
-                    })
-                    .unzip_into_vecs(&mut permutation_traces, &mut cumulative_sums);
-            });
+            let (permutation_traces, cumulative_sums): (Vec<_>, Vec<_>) =
+                tracing::debug_span!("generate permutation traces").in_scope(|| {
+                    chips
+                        .par_iter()
+                        .zip(traces.par_iter_mut())
+                        .map(|(chip, (main_trace, pre_trace))| {
+                            let perm_trace = chip.generate_permutation_trace(
+                                *pre_trace,
+                                main_trace,
+                                &permutation_challenges,
+                            );
+                            let cumulative_sum = perm_trace
+                                .row_slice(main_trace.height() - 1)
+                                .last()
+                                .copied()
+                                .unwrap();
+                            (perm_trace, cumulative_sum)
+                        })
+                        .unzip()
+                });
 
             cumulative_sum += cumulative_sums.iter().copied().sum::<SC::Challenge>();
 
@@ -5,6 +5,7 @@ use p3_air::{ExtensionBuilder, PairBuilder};
 use p3_field::{AbstractExtensionField, AbstractField, ExtensionField, Field, Powers, PrimeField};
 use p3_matrix::{dense::RowMajorMatrix, Matrix};
 use p3_maybe_rayon::prelude::*;
+#[cfg(feature = "std")]
 use rayon_scan::ScanParallelIterator;
 
 use crate::{air::MultiTableAirBuilder, lookup::Interaction};
@@ -141,10 +142,19 @@ pub fn generate_permutation_trace<F: PrimeField, EF: ExtensionField<F>>(
         })
         .collect::<Vec<_>>();
 
+    #[cfg(feature = "std")]
     let cumulative_sums = cumulative_sums
         .into_par_iter()
         .scan(|a, b| *a + *b, zero)
         .collect::<Vec<_>>();
+    #[cfg(not(feature = "std"))]
+    let cumulative_sums = cumulative_sums
+        .into_iter()
+        .scan(zero, |acc, x| {
+            *acc += x;
+            Some(*acc)
+        })
+        .collect::<Vec<_>>();
 
     permutation_trace
         .par_rows_mut()
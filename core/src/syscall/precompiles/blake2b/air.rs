@@ -0,0 +1,391 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+
+use super::columns::{Blake2bCompressCols, Blake2bMixCols, NUM_BLAKE2B_COMPRESS_COLS};
+use super::{
+    Blake2bCompressChip, BLAKE2B_IV, BLAKE2B_MIX_INDICES, BLAKE2B_NUM_ROUNDS, BLAKE2B_SIGMA,
+};
+use crate::air::{u64_limbs, BaseAirBuilder, SP1AirBuilder, WordAirBuilder, U64_SIZE};
+use crate::memory::MemoryCols;
+use crate::operations::{AddU64Operation, FixedRotateRightU64Operation, XorU64Operation};
+use crate::runtime::SyscallCode;
+
+impl<F> BaseAir<F> for Blake2bCompressChip {
+    fn width(&self) -> usize {
+        NUM_BLAKE2B_COMPRESS_COLS
+    }
+}
+
+impl<AB> Air<AB> for Blake2bCompressChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Blake2bCompressCols<AB::Var> = (*local).borrow();
+        let next: &Blake2bCompressCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder
+            .when_transition()
+            .assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        self.eval_control_flow_flags(builder, local, next);
+
+        self.eval_memory(builder, local);
+
+        self.eval_round(builder, local, next);
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::BLAKE2B_COMPRESS.syscall_id()),
+            local.h_ptr,
+            local.block_ptr,
+            local.round[0],
+        );
+    }
+}
+
+impl Blake2bCompressChip {
+    fn eval_control_flow_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Blake2bCompressCols<AB::Var>,
+        next: &Blake2bCompressCols<AB::Var>,
+    ) {
+        let last_round = local.round[BLAKE2B_NUM_ROUNDS - 1];
+
+        // Verify that the round flags are bool and that exactly one of them is set in real rows.
+        builder.assert_bool(local.is_real);
+        let mut round_sum = AB::Expr::zero();
+        for i in 0..BLAKE2B_NUM_ROUNDS {
+            builder.assert_bool(local.round[i]);
+            round_sum += local.round[i].into();
+        }
+        builder.assert_eq(round_sum, local.is_real);
+
+        // A real row either starts a new syscall or continues the previous row's syscall.
+        builder
+            .when_first_row()
+            .assert_eq(local.round[0], local.is_real);
+        for i in 1..BLAKE2B_NUM_ROUNDS {
+            builder
+                .when_transition()
+                .when(next.is_real)
+                .assert_eq(next.round[i], local.round[i - 1]);
+        }
+
+        // If this row is real and not the last round, then the next row should also be real and
+        // have the same inputs.
+        let mut continues = builder.when_transition();
+        let mut continues = continues.when(local.is_real);
+        let mut continues = continues.when_not(last_round);
+        continues.assert_one(next.is_real);
+        continues.assert_eq(local.shard, next.shard);
+        continues.assert_eq(local.channel, next.channel);
+        continues.assert_eq(local.clk, next.clk);
+        continues.assert_eq(local.h_ptr, next.h_ptr);
+        continues.assert_eq(local.block_ptr, next.block_ptr);
+        for i in 0..local.m_mem.len() {
+            continues.assert_word_eq(*local.m_mem[i].value(), *next.m_mem[i].value());
+        }
+
+        // Assert that the table ends in nonreal columns. Since each compress ecall is 12 rows and
+        // the table is padded to a power of 2, the last row of the table should always be padding.
+        builder.when_last_row().assert_zero(local.is_real);
+    }
+
+    /// Constrains that `h` is read in the first round and written in the last round, and that the
+    /// block is read in the first round.
+    fn eval_memory<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Blake2bCompressCols<AB::Var>,
+    ) {
+        let first_round = local.round[0];
+        let last_round = local.round[BLAKE2B_NUM_ROUNDS - 1];
+
+        for (i, h_mem) in local.h_mem.iter().enumerate() {
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk + last_round,
+                local.h_ptr + AB::F::from_canonical_usize(i * 4),
+                h_mem,
+                first_round + last_round,
+            );
+
+            // In the first round, h is read only and does not change.
+            builder
+                .when(first_round)
+                .assert_word_eq(*h_mem.value(), *h_mem.prev_value());
+        }
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.block_ptr,
+            &local.m_mem,
+            first_round,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.block_ptr + AB::F::from_canonical_usize(local.m_mem.len() * 4),
+            &local.tf_mem,
+            first_round,
+        );
+    }
+
+    fn eval_round<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Blake2bCompressCols<AB::Var>,
+        next: &Blake2bCompressCols<AB::Var>,
+    ) {
+        let first_round = local.round[0];
+        let last_round = local.round[BLAKE2B_NUM_ROUNDS - 1];
+        let m_word =
+            |j: usize| u64_limbs(*local.m_mem[2 * j].value(), *local.m_mem[2 * j + 1].value());
+        let tf_word = |j: usize| {
+            u64_limbs(
+                *local.tf_mem[2 * j].value(),
+                *local.tf_mem[2 * j + 1].value(),
+            )
+        };
+        let h_word =
+            |j: usize| u64_limbs(*local.h_mem[2 * j].value(), *local.h_mem[2 * j + 1].value());
+        let h_prev_word = |j: usize| {
+            u64_limbs(
+                *local.h_mem[2 * j].prev_value(),
+                *local.h_mem[2 * j + 1].prev_value(),
+            )
+        };
+
+        // In the first round, v := h || IV, with the offset counter and the finalization flags
+        // xored into the last four words.
+        for i in 0..8 {
+            builder
+                .when(first_round)
+                .assert_all_eq(local.v[i], h_word(i));
+        }
+        for i in 0..4 {
+            builder.when(first_round).assert_all_eq(
+                local.v[8 + i],
+                BLAKE2B_IV[i].to_le_bytes().map(AB::F::from_canonical_u8),
+            );
+            XorU64Operation::<AB::F>::eval(
+                builder,
+                BLAKE2B_IV[4 + i]
+                    .to_le_bytes()
+                    .map(AB::Expr::from_canonical_u8),
+                tf_word(i),
+                local.v_init[i],
+                local.shard,
+                local.channel,
+                first_round,
+            );
+            builder
+                .when(first_round)
+                .assert_all_eq(local.v[12 + i], local.v_init[i].value);
+        }
+
+        // The message words are permuted by the schedule of the current round.
+        for (round, sigma) in BLAKE2B_SIGMA.iter().enumerate() {
+            for (k, j) in sigma.iter().enumerate() {
+                builder
+                    .when(local.round[round])
+                    .assert_all_eq(local.m[k], m_word(*j));
+            }
+        }
+
+        // Apply the eight mixes of the round.
+        let mut v = local.v;
+        for (i, indices) in BLAKE2B_MIX_INDICES.iter().enumerate() {
+            self.eval_mix(
+                builder,
+                &mut v,
+                *indices,
+                local.m[2 * i],
+                local.m[2 * i + 1],
+                &local.mix[i],
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // The result of the round is the working vector of the next round.
+        for i in 0..16 {
+            builder
+                .when_transition()
+                .when(local.is_real)
+                .when_not(last_round)
+                .assert_all_eq(next.v[i], v[i]);
+        }
+
+        // In the last round, h := h xor v[0..8] xor v[8..16] is written back to memory.
+        for i in 0..8 {
+            XorU64Operation::<AB::F>::eval(
+                builder,
+                v[i],
+                v[i + 8],
+                local.v_xor[i],
+                local.shard,
+                local.channel,
+                last_round,
+            );
+            XorU64Operation::<AB::F>::eval(
+                builder,
+                h_prev_word(i),
+                local.v_xor[i].value,
+                local.finalize[i],
+                local.shard,
+                local.channel,
+                last_round,
+            );
+            builder
+                .when(last_round)
+                .assert_all_eq(h_word(i), local.finalize[i].value);
+        }
+    }
+
+    /// Constrains one application of the mixing function `G` and updates `v` with its result.
+    #[allow(clippy::too_many_arguments)]
+    fn eval_mix<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        v: &mut [[AB::Var; U64_SIZE]; 16],
+        indices: [usize; 4],
+        x: [AB::Var; U64_SIZE],
+        y: [AB::Var; U64_SIZE],
+        cols: &Blake2bMixCols<AB::Var>,
+        shard: AB::Var,
+        channel: AB::Var,
+        is_real: AB::Var,
+    ) {
+        let [a, b, c, d] = indices;
+
+        // a := a + b + x.
+        AddU64Operation::<AB::F>::eval(
+            builder,
+            v[a],
+            v[b],
+            cols.a_add_b,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        AddU64Operation::<AB::F>::eval(
+            builder,
+            cols.a_add_b.value,
+            x,
+            cols.a_add_x,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        v[a] = cols.a_add_x.value;
+
+        // d := (d xor a) rightrotate 32.
+        XorU64Operation::<AB::F>::eval(builder, v[d], v[a], cols.d_xor_a, shard, channel, is_real);
+        v[d] = rotate_bytes_right(cols.d_xor_a.value, 4);
+
+        // c := c + d.
+        AddU64Operation::<AB::F>::eval(
+            builder,
+            v[c],
+            v[d],
+            cols.c_add_d,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        v[c] = cols.c_add_d.value;
+
+        // b := (b xor c) rightrotate 24.
+        XorU64Operation::<AB::F>::eval(builder, v[b], v[c], cols.b_xor_c, shard, channel, is_real);
+        v[b] = rotate_bytes_right(cols.b_xor_c.value, 3);
+
+        // a := a + b + y.
+        AddU64Operation::<AB::F>::eval(
+            builder,
+            v[a],
+            v[b],
+            cols.a_add_b_2,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        AddU64Operation::<AB::F>::eval(
+            builder,
+            cols.a_add_b_2.value,
+            y,
+            cols.a_add_y,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        v[a] = cols.a_add_y.value;
+
+        // d := (d xor a) rightrotate 16.
+        XorU64Operation::<AB::F>::eval(
+            builder,
+            v[d],
+            v[a],
+            cols.d_xor_a_2,
+            shard,
+            channel,
+            is_real,
+        );
+        v[d] = rotate_bytes_right(cols.d_xor_a_2.value, 2);
+
+        // c := c + d.
+        AddU64Operation::<AB::F>::eval(
+            builder,
+            v[c],
+            v[d],
+            cols.c_add_d_2,
+            shard,
+            channel,
+            is_real.into(),
+        );
+        v[c] = cols.c_add_d_2.value;
+
+        // b := (b xor c) rightrotate 63.
+        XorU64Operation::<AB::F>::eval(
+            builder,
+            v[b],
+            v[c],
+            cols.b_xor_c_2,
+            shard,
+            channel,
+            is_real,
+        );
+        FixedRotateRightU64Operation::<AB::F>::eval(
+            builder,
+            cols.b_xor_c_2.value,
+            63,
+            cols.b_rr_63,
+            shard,
+            channel,
+            is_real,
+        );
+        v[b] = cols.b_rr_63.value;
+    }
+}
+
+/// Rotates the little-endian limbs of a u64 right by a whole number of bytes.
+fn rotate_bytes_right<T: Copy>(limbs: [T; U64_SIZE], nb_bytes: usize) -> [T; U64_SIZE] {
+    core::array::from_fn(|i| limbs[(i + nb_bytes) % U64_SIZE])
+}
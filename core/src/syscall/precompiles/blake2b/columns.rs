@@ -0,0 +1,88 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+
+use super::BLAKE2B_NUM_ROUNDS;
+use crate::air::U64_SIZE;
+use crate::memory::{MemoryReadCols, MemoryReadWriteCols};
+use crate::operations::AddU64Operation;
+use crate::operations::FixedRotateRightU64Operation;
+use crate::operations::XorU64Operation;
+
+pub const NUM_BLAKE2B_COMPRESS_COLS: usize = size_of::<Blake2bCompressCols<u8>>();
+
+/// A set of columns needed to compute one application of the mixing function `G` on the words
+/// `a, b, c, d` of the working vector with the message words `x, y`.
+///
+/// Rotations by 32, 24 and 16 bits move whole bytes, so they are free and only the final rotation
+/// by 63 bits needs its own columns.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Blake2bMixCols<T> {
+    pub a_add_b: AddU64Operation<T>,
+    /// `a := a + b + x`.
+    pub a_add_x: AddU64Operation<T>,
+    /// `d := (d xor a) rightrotate 32`.
+    pub d_xor_a: XorU64Operation<T>,
+    /// `c := c + d`.
+    pub c_add_d: AddU64Operation<T>,
+    /// `b := (b xor c) rightrotate 24`.
+    pub b_xor_c: XorU64Operation<T>,
+
+    pub a_add_b_2: AddU64Operation<T>,
+    /// `a := a + b + y`.
+    pub a_add_y: AddU64Operation<T>,
+    /// `d := (d xor a) rightrotate 16`.
+    pub d_xor_a_2: XorU64Operation<T>,
+    /// `c := c + d`.
+    pub c_add_d_2: AddU64Operation<T>,
+    pub b_xor_c_2: XorU64Operation<T>,
+    /// `b := (b xor c) rightrotate 63`.
+    pub b_rr_63: FixedRotateRightU64Operation<T>,
+}
+
+/// A set of columns needed to compute the BLAKE2b compression function.
+///
+/// Each blake2b compress syscall is processed over 12 rows, one for each round. The first row reads
+/// `h` and the block from memory and initializes the working vector. Every row applies the eight
+/// mixes of its round to `v`, and the result becomes `v` of the next row. The last row combines the
+/// working vector with `h` and writes the result back to memory.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Blake2bCompressCols<T> {
+    /// Inputs.
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub h_ptr: T,
+    pub block_ptr: T,
+
+    /// Which round is processed in this row. All flags are zero in padding rows.
+    pub round: [T; BLAKE2B_NUM_ROUNDS],
+
+    /// Memory access of `h`. This is read in the first round and written in the last round.
+    pub h_mem: [MemoryReadWriteCols<T>; 16],
+    /// Memory access of the message words of the block. This is only accessed in the first round,
+    /// but its value is kept in every row of the syscall.
+    pub m_mem: [MemoryReadCols<T>; 32],
+    /// Memory access of the offset counter and the finalization flags, read in the first round.
+    pub tf_mem: [MemoryReadCols<T>; 8],
+
+    /// `v[12..16] := IV[4..8] xor (t[0], t[1], f[0], f[1])`, computed in the first round.
+    pub v_init: [XorU64Operation<T>; 4],
+
+    /// The working vector at the start of the round.
+    pub v: [[T; U64_SIZE]; 16],
+
+    /// The message words permuted by the schedule of the round.
+    pub m: [[T; U64_SIZE]; 16],
+
+    pub mix: [Blake2bMixCols<T>; 8],
+
+    pub v_xor: [XorU64Operation<T>; 8],
+    /// `h[i] := h[i] xor v[i] xor v[i + 8]`, computed in the last round.
+    pub finalize: [XorU64Operation<T>; 8],
+
+    pub is_real: T,
+}
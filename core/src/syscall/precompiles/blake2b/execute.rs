@@ -0,0 +1,63 @@
+use super::{blake2b_compress, Blake2bCompressChip, Blake2bCompressEvent, BLAKE2B_BLOCK_NUM_U64S};
+use crate::{runtime::Syscall, syscall::precompiles::SyscallContext};
+
+impl Syscall for Blake2bCompressChip {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let h_ptr = arg1;
+        let block_ptr = arg2;
+        assert_ne!(h_ptr, block_ptr);
+
+        let start_clk = rt.clk;
+
+        // Read in the chaining value and the block.
+        let (h_read_records, h_values) = rt.mr_slice(h_ptr, 16);
+        let (block_read_records, block_values) = rt.mr_slice(block_ptr, 2 * BLAKE2B_BLOCK_NUM_U64S);
+
+        let mut h = [0u64; 8];
+        for (i, values) in h_values.chunks_exact(2).enumerate() {
+            h[i] = values[0] as u64 | ((values[1] as u64) << 32);
+        }
+        let mut block = [0u64; BLAKE2B_BLOCK_NUM_U64S];
+        for (i, values) in block_values.chunks_exact(2).enumerate() {
+            block[i] = values[0] as u64 | ((values[1] as u64) << 32);
+        }
+
+        let mut result = h;
+        blake2b_compress(&mut result, &block);
+
+        // Increment the clk by 1 before writing to h, since we've already read h at the start_clk.
+        rt.clk += 1;
+
+        let values_to_write = result
+            .iter()
+            .flat_map(|x| [*x as u32, (x >> 32) as u32])
+            .collect::<Vec<_>>();
+        let h_write_records = rt.mw_slice(h_ptr, &values_to_write);
+
+        // Push the BLAKE2b compress event.
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut()
+            .blake2b_compress_events
+            .push(Blake2bCompressEvent {
+                lookup_id,
+                shard,
+                channel,
+                clk: start_clk,
+                h_ptr,
+                block_ptr,
+                h,
+                block,
+                h_read_records,
+                block_read_records,
+                h_write_records,
+            });
+
+        None
+    }
+}
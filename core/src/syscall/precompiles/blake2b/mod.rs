@@ -0,0 +1,206 @@
+mod air;
+mod columns;
+mod execute;
+mod trace;
+
+pub use columns::*;
+
+use serde::{Deserialize, Serialize};
+
+use crate::runtime::{MemoryReadRecord, MemoryWriteRecord};
+
+/// The number of rounds of the BLAKE2b compression function.
+pub const BLAKE2B_NUM_ROUNDS: usize = 12;
+
+/// The number of u64s in a compression block: the 16 message words, the two halves of the offset
+/// counter `t` and the two finalization flags `f`.
+pub const BLAKE2B_BLOCK_NUM_U64S: usize = 20;
+
+pub const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// The message schedule of each round. Rounds 10 and 11 reuse the schedules of rounds 0 and 1.
+pub const BLAKE2B_SIGMA: [[usize; 16]; BLAKE2B_NUM_ROUNDS] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+/// The indices `[a, b, c, d]` of the working vector touched by each of the eight mixes of a round.
+/// The first four mix the columns and the last four mix the diagonals.
+pub const BLAKE2B_MIX_INDICES: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Blake2bCompressEvent {
+    pub lookup_id: u128,
+    pub shard: u32,
+    pub channel: u32,
+    pub clk: u32,
+    pub h_ptr: u32,
+    pub block_ptr: u32,
+    pub h: [u64; 8],
+    pub block: [u64; BLAKE2B_BLOCK_NUM_U64S],
+    pub h_read_records: Vec<MemoryReadRecord>,
+    pub block_read_records: Vec<MemoryReadRecord>,
+    pub h_write_records: Vec<MemoryWriteRecord>,
+}
+
+/// Implements the BLAKE2b compression function `F`. The inputs to the syscall are a pointer to the
+/// 8 u64 chaining value `h`, which is overwritten with the result, and a pointer to the 20 u64
+/// block laid out as `m[0..16] || t[0..2] || f[0..2]`. Each u64 is stored as two little-endian
+/// words.
+///
+/// In the AIR, each BLAKE2b compress syscall takes up 12 rows, one per round. The first row also
+/// reads the inputs and initializes the working vector, and the last row writes back `h`.
+#[derive(Default)]
+pub struct Blake2bCompressChip;
+
+impl Blake2bCompressChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Applies the BLAKE2b mixing function `G` to the words of `v` at `indices`.
+pub fn blake2b_mix(v: &mut [u64; 16], indices: [usize; 4], x: u64, y: u64) {
+    let [a, b, c, d] = indices;
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+pub fn blake2b_compress(h: &mut [u64; 8], block: &[u64; BLAKE2B_BLOCK_NUM_U64S]) {
+    let (m, tf) = block.split_at(16);
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&BLAKE2B_IV);
+    for i in 0..4 {
+        v[12 + i] ^= tf[i];
+    }
+    for sigma in BLAKE2B_SIGMA.iter() {
+        for (i, indices) in BLAKE2B_MIX_INDICES.iter().enumerate() {
+            blake2b_mix(&mut v, *indices, m[sigma[2 * i]], m[sigma[2 * i + 1]]);
+        }
+    }
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+#[cfg(test)]
+pub mod compress_tests {
+
+    use crate::{
+        runtime::{Instruction, Opcode, Program, Runtime, SyscallCode},
+        stark::DefaultProver,
+        utils::{run_test, setup_logger, SP1CoreOpts},
+    };
+
+    use super::{blake2b_compress, BLAKE2B_BLOCK_NUM_U64S, BLAKE2B_IV};
+
+    pub fn blake2b_compress_program() -> Program {
+        let h_ptr = 100;
+        let block_ptr = 1000;
+        let mut instructions = vec![Instruction::new(Opcode::ADD, 29, 0, 5, false, true)];
+        for i in 0..16 {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 30, 0, h_ptr + i * 4, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            ]);
+        }
+        for i in 0..40 {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 30, 0, block_ptr + i * 4, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            ]);
+        }
+        instructions.extend(vec![
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::BLAKE2B_COMPRESS as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, h_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, block_ptr, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_blake2b_compress_execute() {
+        let program = blake2b_compress_program();
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let mut expected = [5 | (5 << 32); 8];
+        blake2b_compress(&mut expected, &[5 | (5 << 32); BLAKE2B_BLOCK_NUM_U64S]);
+        for (i, h) in expected.iter().enumerate() {
+            let lo = runtime.word(100 + i as u32 * 8) as u64;
+            let hi = runtime.word(100 + i as u32 * 8 + 4) as u64;
+            assert_eq!(lo | (hi << 32), *h);
+        }
+    }
+
+    #[test]
+    fn test_blake2b_compress_abc() {
+        // BLAKE2b-512 with no key, so the parameter block only sets the digest length.
+        let mut h = BLAKE2B_IV;
+        h[0] ^= 0x01010040;
+
+        // The single final block of the message "abc".
+        let mut block = [0u64; BLAKE2B_BLOCK_NUM_U64S];
+        block[0] = 0x636261;
+        block[16] = 3;
+        block[18] = u64::MAX;
+        blake2b_compress(&mut h, &block);
+
+        let digest = h.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>();
+        assert_eq!(
+            hex::encode(digest),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+    }
+
+    #[test]
+    fn prove_babybear() {
+        setup_logger();
+        let program = blake2b_compress_program();
+        run_test::<DefaultProver<_, _>>(program).unwrap();
+    }
+}
@@ -0,0 +1,208 @@
+use std::borrow::BorrowMut;
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+
+use super::{
+    columns::{Blake2bCompressCols, Blake2bMixCols, NUM_BLAKE2B_COMPRESS_COLS},
+    Blake2bCompressChip, Blake2bCompressEvent, BLAKE2B_IV, BLAKE2B_MIX_INDICES, BLAKE2B_NUM_ROUNDS,
+    BLAKE2B_SIGMA,
+};
+use crate::{
+    air::{MachineAir, U64_SIZE},
+    bytes::{event::ByteRecord, ByteLookupEvent},
+    runtime::{ExecutionRecord, Program},
+    utils::pad_rows,
+};
+
+impl<F: PrimeField32> MachineAir<F> for Blake2bCompressChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Blake2bCompress".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut wrapped_rows = Some(Vec::new());
+        for event in input.blake2b_compress_events.iter() {
+            self.event_to_rows(event, &mut wrapped_rows, &mut Vec::new());
+        }
+        let mut rows = wrapped_rows.unwrap();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_BLAKE2B_COMPRESS_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_BLAKE2B_COMPRESS_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Blake2bCompressCols<F> = trace.values
+                [i * NUM_BLAKE2B_COMPRESS_COLS..(i + 1) * NUM_BLAKE2B_COMPRESS_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let chunk_size = std::cmp::max(input.blake2b_compress_events.len() / num_cpus::get(), 1);
+
+        let blu_batches = input
+            .blake2b_compress_events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
+                events.iter().for_each(|event| {
+                    self.event_to_rows::<F>(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_sharded_byte_lookup_events(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.blake2b_compress_events.is_empty()
+    }
+}
+
+impl Blake2bCompressChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &Blake2bCompressEvent,
+        rows: &mut Option<Vec<[F; NUM_BLAKE2B_COMPRESS_COLS]>>,
+        blu: &mut impl ByteRecord,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+        let block = event.block;
+
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&event.h);
+        v[8..].copy_from_slice(&BLAKE2B_IV);
+
+        for round in 0..BLAKE2B_NUM_ROUNDS {
+            let mut row = [F::zero(); NUM_BLAKE2B_COMPRESS_COLS];
+            let cols: &mut Blake2bCompressCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u32(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.h_ptr = F::from_canonical_u32(event.h_ptr);
+            cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+            cols.round[round] = F::one();
+            cols.is_real = F::one();
+
+            // The message words are only read in the first round, but their value is kept in every
+            // row.
+            for (i, record) in event.block_read_records[..32].iter().enumerate() {
+                if round == 0 {
+                    cols.m_mem[i].populate(channel, *record, blu);
+                } else {
+                    cols.m_mem[i].access.value = record.value.into();
+                }
+            }
+
+            // Read h, t and f and initialize the working vector.
+            if round == 0 {
+                for (i, record) in event.block_read_records[32..].iter().enumerate() {
+                    cols.tf_mem[i].populate(channel, *record, blu);
+                }
+                for (i, record) in event.h_read_records.iter().enumerate() {
+                    cols.h_mem[i].populate_read(channel, *record, blu);
+                }
+                for i in 0..4 {
+                    v[12 + i] = cols.v_init[i].populate(
+                        blu,
+                        shard,
+                        channel,
+                        BLAKE2B_IV[4 + i],
+                        block[16 + i],
+                    );
+                }
+            }
+
+            cols.v = v.map(u64_to_limbs);
+            cols.m = BLAKE2B_SIGMA[round].map(|j| u64_to_limbs(block[j]));
+
+            for (i, indices) in BLAKE2B_MIX_INDICES.iter().enumerate() {
+                let x = block[BLAKE2B_SIGMA[round][2 * i]];
+                let y = block[BLAKE2B_SIGMA[round][2 * i + 1]];
+                cols.mix[i].populate(blu, shard, channel, &mut v, *indices, x, y);
+            }
+
+            // Combine the working vector with h and write the result back.
+            if round == BLAKE2B_NUM_ROUNDS - 1 {
+                for i in 0..8 {
+                    let v_xor = cols.v_xor[i].populate(blu, shard, channel, v[i], v[i + 8]);
+                    cols.finalize[i].populate(blu, shard, channel, event.h[i], v_xor);
+                }
+                for (i, record) in event.h_write_records.iter().enumerate() {
+                    cols.h_mem[i].populate_write(channel, *record, blu);
+                }
+            }
+
+            if rows.as_ref().is_some() {
+                rows.as_mut().unwrap().push(row);
+            }
+        }
+    }
+}
+
+impl<F: PrimeField32> Blake2bMixCols<F> {
+    #[allow(clippy::too_many_arguments)]
+    fn populate(
+        &mut self,
+        blu: &mut impl ByteRecord,
+        shard: u32,
+        channel: u32,
+        v: &mut [u64; 16],
+        indices: [usize; 4],
+        x: u64,
+        y: u64,
+    ) {
+        let [a, b, c, d] = indices;
+
+        let a_add_b = self.a_add_b.populate(blu, shard, channel, v[a], v[b]);
+        v[a] = self.a_add_x.populate(blu, shard, channel, a_add_b, x);
+        v[d] = self
+            .d_xor_a
+            .populate(blu, shard, channel, v[d], v[a])
+            .rotate_right(32);
+        v[c] = self.c_add_d.populate(blu, shard, channel, v[c], v[d]);
+        v[b] = self
+            .b_xor_c
+            .populate(blu, shard, channel, v[b], v[c])
+            .rotate_right(24);
+
+        let a_add_b = self.a_add_b_2.populate(blu, shard, channel, v[a], v[b]);
+        v[a] = self.a_add_y.populate(blu, shard, channel, a_add_b, y);
+        v[d] = self
+            .d_xor_a_2
+            .populate(blu, shard, channel, v[d], v[a])
+            .rotate_right(16);
+        v[c] = self.c_add_d_2.populate(blu, shard, channel, v[c], v[d]);
+        let b_xor_c = self.b_xor_c_2.populate(blu, shard, channel, v[b], v[c]);
+        v[b] = self.b_rr_63.populate(blu, shard, channel, b_xor_c, 63);
+    }
+}
+
+/// Splits a u64 into its little-endian byte limbs.
+fn u64_to_limbs<F: PrimeField32>(value: u64) -> [F; U64_SIZE] {
+    value.to_le_bytes().map(F::from_canonical_u8)
+}
@@ -0,0 +1,401 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::mem::size_of;
+use std::marker::PhantomData;
+
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::AirBuilder;
+use p3_air::{Air, BaseAir};
+use p3_field::AbstractField;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use serde::{Deserialize, Serialize};
+use sp1_derive::AlignedBorrow;
+use typenum::Unsigned;
+
+use crate::air::{BaseAirBuilder, MachineAir, Polynomial, SP1AirBuilder};
+use crate::bytes::event::ByteRecord;
+use crate::bytes::ByteLookupEvent;
+use crate::memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols};
+use crate::operations::field::field_op::{FieldOpCols, FieldOperation};
+use crate::operations::field::params::{FieldType, FpOpField, Limbs, NumLimbs, NumWords};
+use crate::operations::field::range::FieldLtCols;
+use crate::runtime::{ExecutionRecord, Program, Syscall, SyscallCode};
+use crate::runtime::{MemoryReadRecord, MemoryWriteRecord};
+use crate::syscall::precompiles::SyscallContext;
+use crate::utils::{limbs_from_access, limbs_from_prev_access, pad_rows};
+
+/// Base field operation event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FpOpEvent {
+    pub lookup_id: u128,
+    pub shard: u32,
+    pub channel: u32,
+    pub clk: u32,
+    pub x_ptr: u32,
+    pub x: Vec<u32>,
+    pub y_ptr: u32,
+    pub y: Vec<u32>,
+    pub op: FieldOperation,
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    pub y_memory_records: Vec<MemoryReadRecord>,
+}
+
+pub const fn num_fp_cols<P: FpOpField>() -> usize {
+    size_of::<FpOpCols<u8, P>>()
+}
+
+/// A set of columns for the `FP_ADD`, `FP_SUB` and `FP_MUL` operations of a field.
+///
+/// The operation of a row is selected by the `is_add`, `is_sub` and `is_mul` flags, and the
+/// result `x op y` is written over `x`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FpOpCols<T, P: FpOpField> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub is_add: T,
+    pub is_sub: T,
+    pub is_mul: T,
+    pub x_ptr: T,
+    pub y_ptr: T,
+    pub x_access: GenericArray<MemoryWriteCols<T>, P::WordsFieldElement>,
+    pub y_access: GenericArray<MemoryReadCols<T>, P::WordsFieldElement>,
+    pub(crate) output: FieldOpCols<T, P>,
+    pub(crate) output_range_check: FieldLtCols<T, P>,
+}
+
+/// A chip proving the addition, subtraction and multiplication syscalls of a field.
+#[derive(Default)]
+pub struct FpOpChip<P> {
+    _marker: PhantomData<P>,
+}
+
+/// Executes one of the `FP_ADD`, `FP_SUB` and `FP_MUL` syscalls of a field, which are proven by
+/// [FpOpChip].
+pub struct FpOpSyscall<P> {
+    op: FieldOperation,
+    _marker: PhantomData<P>,
+}
+
+impl<P> FpOpSyscall<P> {
+    pub const fn new(op: FieldOperation) -> Self {
+        Self {
+            op,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P: FpOpField> Syscall for FpOpSyscall<P> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if y_ptr % 4 != 0 {
+            panic!();
+        }
+
+        let num_words = <P as NumWords>::WordsFieldElement::USIZE;
+
+        // Read x with slice_unsafe, since the result is written to x afterwards.
+        let x = rt.slice_unsafe(x_ptr, num_words);
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, num_words);
+
+        let modulus = P::modulus();
+        let a = BigUint::from_slice(&x);
+        let b = BigUint::from_slice(&y);
+        assert!(a < modulus, "x is not a field element");
+        assert!(b < modulus, "y is not a field element");
+        let result = match self.op {
+            FieldOperation::Add => (a + b) % &modulus,
+            FieldOperation::Sub => (a + &modulus - b) % &modulus,
+            FieldOperation::Mul => (a * b) % &modulus,
+            FieldOperation::Div => unreachable!(),
+        };
+        let mut result = result.to_u32_digits();
+        result.resize(num_words, 0);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let event = FpOpEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            op: self.op,
+            x_memory_records,
+            y_memory_records,
+        };
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => rt.record_mut().bn254_fp_events.push(event),
+            FieldType::Bls12381 => rt.record_mut().bls12381_fp_events.push(event),
+        }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+impl<P: FpOpField> FpOpChip<P> {
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u32,
+        cols: &mut FpOpCols<F, P>,
+        x: BigUint,
+        y: BigUint,
+        op: FieldOperation,
+    ) {
+        let modulus = P::modulus();
+        let result = cols
+            .output
+            .populate_with_modulus(blu_events, shard, channel, &x, &y, &modulus, op);
+        cols.output_range_check
+            .populate(blu_events, shard, channel, &result, &modulus);
+    }
+}
+
+impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254FpOp".to_string(),
+            FieldType::Bls12381 => "Bls12381FpOp".to_string(),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = match P::FIELD_TYPE {
+            FieldType::Bn254 => &input.bn254_fp_events,
+            FieldType::Bls12381 => &input.bls12381_fp_events,
+        };
+
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for event in events {
+            let mut row = vec![F::zero(); num_fp_cols::<P>()];
+            let cols: &mut FpOpCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            let x = BigUint::from_slice(&event.x);
+            let y = BigUint::from_slice(&event.y);
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u32(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.is_add = F::from_bool(event.op == FieldOperation::Add);
+            cols.is_sub = F::from_bool(event.op == FieldOperation::Sub);
+            cols.is_mul = F::from_bool(event.op == FieldOperation::Mul);
+            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+            Self::populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                event.channel,
+                cols,
+                x,
+                y,
+                event.op,
+            );
+
+            for i in 0..cols.y_access.len() {
+                cols.y_access[i].populate(
+                    event.channel,
+                    event.y_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+            for i in 0..cols.x_access.len() {
+                cols.x_access[i].populate(
+                    event.channel,
+                    event.x_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || {
+            let mut row = vec![F::zero(); num_fp_cols::<P>()];
+            let cols: &mut FpOpCols<F, P> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            Self::populate_field_ops(
+                &mut vec![],
+                0,
+                0,
+                cols,
+                zero.clone(),
+                zero,
+                FieldOperation::Mul,
+            );
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_fp_cols::<P>(),
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut FpOpCols<F, P> =
+                trace.values[i * num_fp_cols::<P>()..(i + 1) * num_fp_cols::<P>()].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => !shard.bn254_fp_events.is_empty(),
+            FieldType::Bls12381 => !shard.bls12381_fp_events.is_empty(),
+        }
+    }
+}
+
+impl<F, P: FpOpField> BaseAir<F> for FpOpChip<P> {
+    fn width(&self) -> usize {
+        num_fp_cols::<P>()
+    }
+}
+
+impl<AB, P: FpOpField> Air<AB> for FpOpChip<P>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &FpOpCols<AB::Var, P> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &FpOpCols<AB::Var, P> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder
+            .when_transition()
+            .assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Exactly one operation is selected in real rows, and none in padding rows.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_add);
+        builder.assert_bool(local.is_sub);
+        builder.assert_bool(local.is_mul);
+        builder.assert_eq(local.is_add + local.is_sub + local.is_mul, local.is_real);
+
+        let x: Limbs<AB::Var, <P as NumLimbs>::Limbs> = limbs_from_prev_access(&local.x_access);
+        let y: Limbs<AB::Var, <P as NumLimbs>::Limbs> = limbs_from_access(&local.y_access);
+        let modulus = P::to_limbs_field::<AB::Expr, AB::F>(&P::modulus());
+        let p_modulus: Polynomial<AB::Expr> = modulus.clone().into();
+
+        local.output.eval_variable(
+            builder,
+            &x,
+            &y,
+            &p_modulus,
+            local.is_add,
+            local.is_sub,
+            local.is_mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Check that the result is reduced.
+        local.output_range_check.eval(
+            builder,
+            &local.output.result,
+            &modulus,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Assert that the correct result is being written to x.
+        builder
+            .when(local.is_real)
+            .assert_all_eq(local.output.result, value_as_limbs(&local.x_access));
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.y_ptr,
+            &local.y_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(1), // We write x at +1 since x, y could be the same.
+            local.x_ptr,
+            &local.x_access,
+            local.is_real,
+        );
+
+        // The syscall id is selected by the operation flags.
+        let (add_code, sub_code, mul_code) = match P::FIELD_TYPE {
+            FieldType::Bn254 => (
+                SyscallCode::BN254_FP_ADD,
+                SyscallCode::BN254_FP_SUB,
+                SyscallCode::BN254_FP_MUL,
+            ),
+            FieldType::Bls12381 => (
+                SyscallCode::BLS12381_FP_ADD,
+                SyscallCode::BLS12381_FP_SUB,
+                SyscallCode::BLS12381_FP_MUL,
+            ),
+        };
+        let syscall_id_felt = local.is_add * AB::F::from_canonical_u32(add_code.syscall_id())
+            + local.is_sub * AB::F::from_canonical_u32(sub_code.syscall_id())
+            + local.is_mul * AB::F::from_canonical_u32(mul_code.syscall_id());
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+        );
+    }
+}
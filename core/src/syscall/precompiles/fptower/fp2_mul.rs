@@ -0,0 +1,493 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::mem::size_of;
+use std::marker::PhantomData;
+
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::AirBuilder;
+use p3_air::{Air, BaseAir};
+use p3_field::AbstractField;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use serde::{Deserialize, Serialize};
+use sp1_derive::AlignedBorrow;
+use typenum::Unsigned;
+
+use crate::air::{MachineAir, SP1AirBuilder};
+use crate::bytes::event::ByteRecord;
+use crate::bytes::ByteLookupEvent;
+use crate::memory::{MemoryCols, MemoryReadCols, MemoryWriteCols};
+use crate::operations::field::field_op::{FieldOpCols, FieldOperation};
+use crate::operations::field::params::{FieldType, FpOpField, Limbs, NumLimbs, NumWords};
+use crate::operations::field::range::FieldLtCols;
+use crate::runtime::{ExecutionRecord, Program, Syscall, SyscallCode};
+use crate::runtime::{MemoryReadRecord, MemoryWriteRecord};
+use crate::syscall::precompiles::SyscallContext;
+use crate::utils::{limbs_from_access, limbs_from_prev_access, pad_rows};
+
+/// Quadratic extension field multiplication event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fp2MulEvent {
+    pub lookup_id: u128,
+    pub shard: u32,
+    pub channel: u32,
+    pub clk: u32,
+    pub x_ptr: u32,
+    pub x: Vec<u32>,
+    pub y_ptr: u32,
+    pub y: Vec<u32>,
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    pub y_memory_records: Vec<MemoryReadRecord>,
+}
+
+pub const fn num_fp2_mul_cols<P: FpOpField>() -> usize {
+    size_of::<Fp2MulAssignCols<u8, P>>()
+}
+
+/// A set of columns for the `FP2_MUL` operation of a field.
+///
+/// The quadratic extension is `Fp[u] / (u^2 + 1)`, so that the product of `a0 + a1 * u` and
+/// `b0 + b1 * u` is `(a0 * b0 - a1 * b1) + (a0 * b1 + a1 * b0) * u`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2MulAssignCols<T, P: FpOpField> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub x_ptr: T,
+    pub y_ptr: T,
+    pub x_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+    pub y_access: GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>,
+    pub(crate) a0_mul_b0: FieldOpCols<T, P>,
+    pub(crate) a1_mul_b1: FieldOpCols<T, P>,
+    pub(crate) a0_mul_b1: FieldOpCols<T, P>,
+    pub(crate) a1_mul_b0: FieldOpCols<T, P>,
+    pub(crate) c0: FieldOpCols<T, P>,
+    pub(crate) c1: FieldOpCols<T, P>,
+    pub(crate) c0_range_check: FieldLtCols<T, P>,
+    pub(crate) c1_range_check: FieldLtCols<T, P>,
+}
+
+/// A chip proving the quadratic extension multiplication syscall of a field.
+#[derive(Default)]
+pub struct Fp2MulAssignChip<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P: FpOpField> Syscall for Fp2MulAssignChip<P> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if y_ptr % 4 != 0 {
+            panic!();
+        }
+
+        let num_words = <P as NumWords>::WordsCurvePoint::USIZE;
+
+        // Read x with slice_unsafe, since the result is written to x afterwards.
+        let x = rt.slice_unsafe(x_ptr, num_words);
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, num_words);
+
+        let modulus = P::modulus();
+        let (x0, x1) = x.split_at(num_words / 2);
+        let (y0, y1) = y.split_at(num_words / 2);
+        let (a0, a1) = (BigUint::from_slice(x0), BigUint::from_slice(x1));
+        let (b0, b1) = (BigUint::from_slice(y0), BigUint::from_slice(y1));
+        for c in [&a0, &a1, &b0, &b1] {
+            assert!(c < &modulus, "input is not a field element");
+        }
+
+        let a1_mul_b1 = (&a1 * &b1) % &modulus;
+        let c0 = ((&a0 * &b0) % &modulus + &modulus - a1_mul_b1) % &modulus;
+        let c1 = (&a0 * &b1 + &a1 * &b0) % &modulus;
+
+        let mut result = Vec::with_capacity(num_words);
+        for c in [c0, c1] {
+            let mut c = c.to_u32_digits();
+            c.resize(num_words / 2, 0);
+            result.extend(c);
+        }
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let event = Fp2MulEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            x_memory_records,
+            y_memory_records,
+        };
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => rt.record_mut().bn254_fp2_mul_events.push(event),
+            FieldType::Bls12381 => rt.record_mut().bls12381_fp2_mul_events.push(event),
+        }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+impl<P: FpOpField> Fp2MulAssignChip<P> {
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u32,
+        cols: &mut Fp2MulAssignCols<F, P>,
+        a: (BigUint, BigUint),
+        b: (BigUint, BigUint),
+    ) {
+        let modulus = P::modulus();
+
+        // c0 = a0 * b0 - a1 * b1.
+        let c0 = {
+            let a0_mul_b0 = cols.a0_mul_b0.populate(
+                blu_events,
+                shard,
+                channel,
+                &a.0,
+                &b.0,
+                FieldOperation::Mul,
+            );
+            let a1_mul_b1 = cols.a1_mul_b1.populate(
+                blu_events,
+                shard,
+                channel,
+                &a.1,
+                &b.1,
+                FieldOperation::Mul,
+            );
+            cols.c0.populate(
+                blu_events,
+                shard,
+                channel,
+                &a0_mul_b0,
+                &a1_mul_b1,
+                FieldOperation::Sub,
+            )
+        };
+
+        // c1 = a0 * b1 + a1 * b0.
+        let c1 = {
+            let a0_mul_b1 = cols.a0_mul_b1.populate(
+                blu_events,
+                shard,
+                channel,
+                &a.0,
+                &b.1,
+                FieldOperation::Mul,
+            );
+            let a1_mul_b0 = cols.a1_mul_b0.populate(
+                blu_events,
+                shard,
+                channel,
+                &a.1,
+                &b.0,
+                FieldOperation::Mul,
+            );
+            cols.c1.populate(
+                blu_events,
+                shard,
+                channel,
+                &a0_mul_b1,
+                &a1_mul_b0,
+                FieldOperation::Add,
+            )
+        };
+
+        cols.c0_range_check
+            .populate(blu_events, shard, channel, &c0, &modulus);
+        cols.c1_range_check
+            .populate(blu_events, shard, channel, &c1, &modulus);
+    }
+}
+
+impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2MulAssignChip<P> {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254Fp2MulAssign".to_string(),
+            FieldType::Bls12381 => "Bls12381Fp2MulAssign".to_string(),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = match P::FIELD_TYPE {
+            FieldType::Bn254 => &input.bn254_fp2_mul_events,
+            FieldType::Bls12381 => &input.bls12381_fp2_mul_events,
+        };
+
+        let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for event in events {
+            let mut row = vec![F::zero(); num_fp2_mul_cols::<P>()];
+            let cols: &mut Fp2MulAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            let (x0, x1) = event.x.split_at(num_words_field_element);
+            let (y0, y1) = event.y.split_at(num_words_field_element);
+            let a = (BigUint::from_slice(x0), BigUint::from_slice(x1));
+            let b = (BigUint::from_slice(y0), BigUint::from_slice(y1));
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u32(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+            Self::populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                event.channel,
+                cols,
+                a,
+                b,
+            );
+
+            for i in 0..cols.y_access.len() {
+                cols.y_access[i].populate(
+                    event.channel,
+                    event.y_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+            for i in 0..cols.x_access.len() {
+                cols.x_access[i].populate(
+                    event.channel,
+                    event.x_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || {
+            let mut row = vec![F::zero(); num_fp2_mul_cols::<P>()];
+            let cols: &mut Fp2MulAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+            let zero = (BigUint::zero(), BigUint::zero());
+            Self::populate_field_ops(&mut vec![], 0, 0, cols, zero.clone(), zero);
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_fp2_mul_cols::<P>(),
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Fp2MulAssignCols<F, P> = trace.values
+                [i * num_fp2_mul_cols::<P>()..(i + 1) * num_fp2_mul_cols::<P>()]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => !shard.bn254_fp2_mul_events.is_empty(),
+            FieldType::Bls12381 => !shard.bls12381_fp2_mul_events.is_empty(),
+        }
+    }
+}
+
+impl<F, P: FpOpField> BaseAir<F> for Fp2MulAssignChip<P> {
+    fn width(&self) -> usize {
+        num_fp2_mul_cols::<P>()
+    }
+}
+
+impl<AB, P: FpOpField> Air<AB> for Fp2MulAssignChip<P>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Fp2MulAssignCols<AB::Var, P> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Fp2MulAssignCols<AB::Var, P> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder
+            .when_transition()
+            .assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        builder.assert_bool(local.is_real);
+
+        let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+
+        let a0: Limbs<AB::Var, <P as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.x_access[0..num_words_field_element]);
+        let a1: Limbs<AB::Var, <P as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.x_access[num_words_field_element..]);
+        let b0: Limbs<AB::Var, <P as NumLimbs>::Limbs> =
+            limbs_from_access(&local.y_access[0..num_words_field_element]);
+        let b1: Limbs<AB::Var, <P as NumLimbs>::Limbs> =
+            limbs_from_access(&local.y_access[num_words_field_element..]);
+
+        // c0 = a0 * b0 - a1 * b1.
+        {
+            local.a0_mul_b0.eval(
+                builder,
+                &a0,
+                &b0,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.a1_mul_b1.eval(
+                builder,
+                &a1,
+                &b1,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.c0.eval(
+                builder,
+                &local.a0_mul_b0.result,
+                &local.a1_mul_b1.result,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // c1 = a0 * b1 + a1 * b0.
+        {
+            local.a0_mul_b1.eval(
+                builder,
+                &a0,
+                &b1,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.a1_mul_b0.eval(
+                builder,
+                &a1,
+                &b0,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.c1.eval(
+                builder,
+                &local.a0_mul_b1.result,
+                &local.a1_mul_b0.result,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Check that the result is reduced.
+        let modulus = P::to_limbs_field::<AB::Expr, AB::F>(&P::modulus());
+        local.c0_range_check.eval(
+            builder,
+            &local.c0.result,
+            &modulus,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.c1_range_check.eval(
+            builder,
+            &local.c1.result,
+            &modulus,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Constrain x_access.value = [c0, c1], so that x is updated with the result.
+        for i in 0..P::NB_LIMBS {
+            builder
+                .when(local.is_real)
+                .assert_eq(local.c0.result[i], local.x_access[i / 4].value()[i % 4]);
+            builder.when(local.is_real).assert_eq(
+                local.c1.result[i],
+                local.x_access[num_words_field_element + i / 4].value()[i % 4],
+            );
+        }
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.y_ptr,
+            &local.y_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(1), // We write x at +1 since x, y could be the same.
+            local.x_ptr,
+            &local.x_access,
+            local.is_real,
+        );
+
+        let syscall_id_felt = match P::FIELD_TYPE {
+            FieldType::Bn254 => AB::F::from_canonical_u32(SyscallCode::BN254_FP2_MUL.syscall_id()),
+            FieldType::Bls12381 => {
+                AB::F::from_canonical_u32(SyscallCode::BLS12381_FP2_MUL.syscall_id())
+            }
+        };
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+        );
+    }
+}
@@ -0,0 +1,199 @@
+mod fp;
+mod fp2_addsub;
+mod fp2_mul;
+
+pub use fp::*;
+pub use fp2_addsub::*;
+pub use fp2_mul::*;
+
+#[cfg(test)]
+mod tests {
+    use num::bigint::RandBigInt;
+    use num::BigUint;
+    use rand::thread_rng;
+
+    use crate::io::SP1Stdin;
+    use crate::operations::field::params::FieldParameters;
+    use crate::runtime::{Instruction, Opcode, Program, Runtime, SyscallCode};
+    use crate::stark::DefaultProver;
+    use crate::utils::ec::weierstrass::bls12_381::Bls12381BaseField;
+    use crate::utils::ec::weierstrass::bn254::Bn254BaseField;
+    use crate::utils::{self, run_test_core, SP1CoreOpts};
+
+    const X_PTR: u32 = 100;
+    const Y_PTR: u32 = 1000;
+
+    /// Converts field elements to their little-endian word representation.
+    fn to_words(elements: &[BigUint], words_per_element: usize) -> Vec<u32> {
+        elements
+            .iter()
+            .flat_map(|element| {
+                let mut words = element.to_u32_digits();
+                words.resize(words_per_element, 0);
+                words
+            })
+            .collect()
+    }
+
+    /// Builds a program that writes `x` and `y` to memory and executes the syscall `code` on them.
+    fn syscall_program(code: SyscallCode, x: &[u32], y: &[u32]) -> Program {
+        let mut instructions = vec![];
+        for (ptr, words) in [(X_PTR, x), (Y_PTR, y)] {
+            for (i, word) in words.iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, *word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, ptr + i as u32 * 4, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                ]);
+            }
+        }
+        instructions.extend(vec![
+            Instruction::new(Opcode::ADD, 5, 0, code as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, X_PTR, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, Y_PTR, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        Program::new(instructions, 0, 0)
+    }
+
+    /// Executes the syscall `code` on `x` and `y`, checks that `x` is overwritten with `expected`
+    /// and proves the execution.
+    fn run_syscall(code: SyscallCode, x: &[u32], y: &[u32], expected: &[u32]) {
+        let program = syscall_program(code, x, y);
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        for (i, word) in expected.iter().enumerate() {
+            assert_eq!(runtime.word(X_PTR + i as u32 * 4), *word);
+        }
+        run_test_core::<DefaultProver<_, _>>(runtime, SP1Stdin::new()).unwrap();
+    }
+
+    #[test]
+    fn test_bn254_fp_ops() {
+        utils::setup_logger();
+        let modulus = Bn254BaseField::modulus();
+        let mut rng = thread_rng();
+        let a = rng.gen_biguint_below(&modulus);
+        let b = rng.gen_biguint_below(&modulus);
+
+        let cases = [
+            (SyscallCode::BN254_FP_ADD, (&a + &b) % &modulus),
+            (SyscallCode::BN254_FP_SUB, (&a + &modulus - &b) % &modulus),
+            (SyscallCode::BN254_FP_MUL, (&a * &b) % &modulus),
+        ];
+        for (code, expected) in cases {
+            run_syscall(
+                code,
+                &to_words(&[a.clone()], 8),
+                &to_words(&[b.clone()], 8),
+                &to_words(&[expected], 8),
+            );
+        }
+    }
+
+    #[test]
+    fn test_bn254_fp2_ops() {
+        utils::setup_logger();
+        let modulus = Bn254BaseField::modulus();
+        let mut rng = thread_rng();
+        let a = [
+            rng.gen_biguint_below(&modulus),
+            rng.gen_biguint_below(&modulus),
+        ];
+        let b = [
+            rng.gen_biguint_below(&modulus),
+            rng.gen_biguint_below(&modulus),
+        ];
+
+        // The quadratic extension is defined by u^2 = -1.
+        let add = [(&a[0] + &b[0]) % &modulus, (&a[1] + &b[1]) % &modulus];
+        let sub = [
+            (&a[0] + &modulus - &b[0]) % &modulus,
+            (&a[1] + &modulus - &b[1]) % &modulus,
+        ];
+        let mul = [
+            (&a[0] * &b[0] + &modulus * &modulus - &a[1] * &b[1]) % &modulus,
+            (&a[0] * &b[1] + &a[1] * &b[0]) % &modulus,
+        ];
+
+        let cases = [
+            (SyscallCode::BN254_FP2_ADD, add),
+            (SyscallCode::BN254_FP2_SUB, sub),
+            (SyscallCode::BN254_FP2_MUL, mul),
+        ];
+        for (code, expected) in cases {
+            run_syscall(
+                code,
+                &to_words(&a, 8),
+                &to_words(&b, 8),
+                &to_words(&expected, 8),
+            );
+        }
+    }
+
+    #[test]
+    fn test_bls12381_fp_ops() {
+        utils::setup_logger();
+        let modulus = Bls12381BaseField::modulus();
+        let mut rng = thread_rng();
+        let a = rng.gen_biguint_below(&modulus);
+        let b = rng.gen_biguint_below(&modulus);
+
+        let cases = [
+            (SyscallCode::BLS12381_FP_ADD, (&a + &b) % &modulus),
+            (
+                SyscallCode::BLS12381_FP_SUB,
+                (&a + &modulus - &b) % &modulus,
+            ),
+            (SyscallCode::BLS12381_FP_MUL, (&a * &b) % &modulus),
+        ];
+        for (code, expected) in cases {
+            run_syscall(
+                code,
+                &to_words(&[a.clone()], 12),
+                &to_words(&[b.clone()], 12),
+                &to_words(&[expected], 12),
+            );
+        }
+    }
+
+    #[test]
+    fn test_bls12381_fp2_ops() {
+        utils::setup_logger();
+        let modulus = Bls12381BaseField::modulus();
+        let mut rng = thread_rng();
+        let a = [
+            rng.gen_biguint_below(&modulus),
+            rng.gen_biguint_below(&modulus),
+        ];
+        let b = [
+            rng.gen_biguint_below(&modulus),
+            rng.gen_biguint_below(&modulus),
+        ];
+
+        // The quadratic extension is defined by u^2 = -1.
+        let add = [(&a[0] + &b[0]) % &modulus, (&a[1] + &b[1]) % &modulus];
+        let sub = [
+            (&a[0] + &modulus - &b[0]) % &modulus,
+            (&a[1] + &modulus - &b[1]) % &modulus,
+        ];
+        let mul = [
+            (&a[0] * &b[0] + &modulus * &modulus - &a[1] * &b[1]) % &modulus,
+            (&a[0] * &b[1] + &a[1] * &b[0]) % &modulus,
+        ];
+
+        let cases = [
+            (SyscallCode::BLS12381_FP2_ADD, add),
+            (SyscallCode::BLS12381_FP2_SUB, sub),
+            (SyscallCode::BLS12381_FP2_MUL, mul),
+        ];
+        for (code, expected) in cases {
+            run_syscall(
+                code,
+                &to_words(&a, 12),
+                &to_words(&b, 12),
+                &to_words(&expected, 12),
+            );
+        }
+    }
+}
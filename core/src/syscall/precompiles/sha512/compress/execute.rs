@@ -0,0 +1,102 @@
+use super::Sha512CompressChip;
+use crate::{
+    runtime::{MemoryReadRecord, MemoryWriteRecord, Syscall},
+    syscall::precompiles::{
+        sha512::{Sha512CompressEvent, SHA512_COMPRESS_K},
+        SyscallContext,
+    },
+};
+
+impl Syscall for Sha512CompressChip {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let w_ptr = arg1;
+        let h_ptr = arg2;
+        assert_ne!(w_ptr, h_ptr);
+
+        let start_clk = rt.clk;
+        let mut h_read_records: Vec<[MemoryReadRecord; 2]> = Vec::new();
+        let mut w_i_read_records: Vec<[MemoryReadRecord; 2]> = Vec::new();
+        let mut h_write_records: Vec<[MemoryWriteRecord; 2]> = Vec::new();
+
+        // Execute the "initialize" phase where we read in the h values.
+        let mut hx = [0u64; 8];
+        for i in 0..8 {
+            let (records, values) = rt.mr_slice(h_ptr + i as u32 * 8, 2);
+            h_read_records.push(records.try_into().unwrap());
+            hx[i] = values[0] as u64 | ((values[1] as u64) << 32);
+        }
+
+        let mut original_w = Vec::new();
+        // Execute the "compress" phase.
+        let mut a = hx[0];
+        let mut b = hx[1];
+        let mut c = hx[2];
+        let mut d = hx[3];
+        let mut e = hx[4];
+        let mut f = hx[5];
+        let mut g = hx[6];
+        let mut h = hx[7];
+        for i in 0..80 {
+            let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+            let ch = (e & f) ^ (!e & g);
+            let (records, values) = rt.mr_slice(w_ptr + i * 8, 2);
+            let w_i = values[0] as u64 | ((values[1] as u64) << 32);
+            original_w.push(w_i);
+            w_i_read_records.push(records.try_into().unwrap());
+            let temp1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(SHA512_COMPRESS_K[i as usize])
+                .wrapping_add(w_i);
+            let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+        // Increment the clk by 1 before writing to h, since we've already read h at the start_clk
+        // during the initialization phase.
+        rt.clk += 1;
+
+        // Execute the "finalize" phase.
+        let v = [a, b, c, d, e, f, g, h];
+        for i in 0..8 {
+            let value = hx[i].wrapping_add(v[i]);
+            let records = rt.mw_slice(h_ptr + i as u32 * 8, &[value as u32, (value >> 32) as u32]);
+            h_write_records.push(records.try_into().unwrap());
+        }
+
+        // Push the SHA-512 compress event.
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut()
+            .sha512_compress_events
+            .push(Sha512CompressEvent {
+                lookup_id,
+                shard,
+                channel,
+                clk: start_clk,
+                w_ptr,
+                h_ptr,
+                w: original_w,
+                h: hx,
+                h_read_records: h_read_records.try_into().unwrap(),
+                w_i_read_records,
+                h_write_records: h_write_records.try_into().unwrap(),
+            });
+
+        None
+    }
+}
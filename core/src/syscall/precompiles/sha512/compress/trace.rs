@@ -0,0 +1,319 @@
+use std::borrow::BorrowMut;
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+
+use super::{
+    columns::{Sha512CompressCols, NUM_SHA512_COMPRESS_COLS},
+    Sha512CompressChip, Sha512CompressEvent, SHA512_COMPRESS_K,
+};
+use crate::{
+    air::{MachineAir, U64_SIZE},
+    bytes::{event::ByteRecord, ByteLookupEvent},
+    runtime::{ExecutionRecord, Program},
+    utils::pad_rows,
+};
+
+impl<F: PrimeField32> MachineAir<F> for Sha512CompressChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Sha512Compress".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let rows = Vec::new();
+
+        let mut wrapped_rows = Some(rows);
+        for i in 0..input.sha512_compress_events.len() {
+            let event = input.sha512_compress_events[i].clone();
+            self.event_to_rows(&event, &mut wrapped_rows, &mut Vec::new());
+        }
+        let mut rows = wrapped_rows.unwrap();
+
+        let num_real_rows = rows.len();
+
+        pad_rows(&mut rows, || [F::zero(); NUM_SHA512_COMPRESS_COLS]);
+
+        // Set the octet_num and octect columns for the padded rows.
+        let mut octet_num = 0;
+        let mut octet = 0;
+        for row in rows[num_real_rows..].iter_mut() {
+            let cols: &mut Sha512CompressCols<F> = row.as_mut_slice().borrow_mut();
+            cols.octet_num[octet_num] = F::one();
+            cols.octet[octet] = F::one();
+
+            // If in the compression phase, set the k value.
+            if octet_num != 0 && octet_num != 11 {
+                let compression_idx = octet_num - 1;
+                let k_idx = compression_idx * 8 + octet;
+                cols.k = u64_to_limbs(SHA512_COMPRESS_K[k_idx]);
+            }
+
+            octet = (octet + 1) % 8;
+            if octet == 0 {
+                octet_num = (octet_num + 1) % 12;
+            }
+
+            cols.is_last_row = cols.octet[7] * cols.octet_num[11];
+        }
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_SHA512_COMPRESS_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Sha512CompressCols<F> = trace.values
+                [i * NUM_SHA512_COMPRESS_COLS..(i + 1) * NUM_SHA512_COMPRESS_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let chunk_size = std::cmp::max(input.sha512_compress_events.len() / num_cpus::get(), 1);
+
+        let blu_batches = input
+            .sha512_compress_events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
+                events.iter().for_each(|event| {
+                    self.event_to_rows::<F>(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_sharded_byte_lookup_events(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.sha512_compress_events.is_empty()
+    }
+}
+
+impl Sha512CompressChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &Sha512CompressEvent,
+        rows: &mut Option<Vec<[F; NUM_SHA512_COMPRESS_COLS]>>,
+        blu: &mut impl ByteRecord,
+    ) {
+        let shard = event.shard;
+        let channel = event.channel;
+
+        let og_h = event.h;
+
+        let mut octet_num_idx = 0;
+
+        // Load a, b, c, d, e, f, g, h.
+        for j in 0..8usize {
+            let mut row = [F::zero(); NUM_SHA512_COMPRESS_COLS];
+            let cols: &mut Sha512CompressCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u32(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.w_ptr = F::from_canonical_u32(event.w_ptr);
+            cols.h_ptr = F::from_canonical_u32(event.h_ptr);
+
+            cols.octet[j] = F::one();
+            cols.octet_num[octet_num_idx] = F::one();
+            cols.is_initialize = F::one();
+
+            for k in 0..2 {
+                cols.mem[k].populate_read(channel, event.h_read_records[j][k], blu);
+            }
+            cols.mem_addr = F::from_canonical_u32(event.h_ptr + (j * 8) as u32);
+
+            cols.a = u64_to_limbs(og_h[0]);
+            cols.b = u64_to_limbs(og_h[1]);
+            cols.c = u64_to_limbs(og_h[2]);
+            cols.d = u64_to_limbs(og_h[3]);
+            cols.e = u64_to_limbs(og_h[4]);
+            cols.f = u64_to_limbs(og_h[5]);
+            cols.g = u64_to_limbs(og_h[6]);
+            cols.h = u64_to_limbs(og_h[7]);
+
+            cols.is_real = F::one();
+            cols.start = cols.is_real * cols.octet_num[0] * cols.octet[0];
+            if rows.as_ref().is_some() {
+                rows.as_mut().unwrap().push(row);
+            }
+        }
+
+        // Performs the compress operation.
+        let mut h_array = event.h;
+        for j in 0..80 {
+            if j % 8 == 0 {
+                octet_num_idx += 1;
+            }
+            let mut row = [F::zero(); NUM_SHA512_COMPRESS_COLS];
+            let cols: &mut Sha512CompressCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.k = u64_to_limbs(SHA512_COMPRESS_K[j]);
+            cols.is_compression = F::one();
+            cols.octet[j % 8] = F::one();
+            cols.octet_num[octet_num_idx] = F::one();
+
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u32(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.w_ptr = F::from_canonical_u32(event.w_ptr);
+            cols.h_ptr = F::from_canonical_u32(event.h_ptr);
+            for k in 0..2 {
+                cols.mem[k].populate_read(channel, event.w_i_read_records[j][k], blu);
+            }
+            cols.mem_addr = F::from_canonical_u32(event.w_ptr + (j * 8) as u32);
+
+            let a = h_array[0];
+            let b = h_array[1];
+            let c = h_array[2];
+            let d = h_array[3];
+            let e = h_array[4];
+            let f = h_array[5];
+            let g = h_array[6];
+            let h = h_array[7];
+            cols.a = u64_to_limbs(a);
+            cols.b = u64_to_limbs(b);
+            cols.c = u64_to_limbs(c);
+            cols.d = u64_to_limbs(d);
+            cols.e = u64_to_limbs(e);
+            cols.f = u64_to_limbs(f);
+            cols.g = u64_to_limbs(g);
+            cols.h = u64_to_limbs(h);
+
+            let e_rr_14 = cols.e_rr_14.populate(blu, shard, channel, e, 14);
+            let e_rr_18 = cols.e_rr_18.populate(blu, shard, channel, e, 18);
+            let e_rr_41 = cols.e_rr_41.populate(blu, shard, channel, e, 41);
+            let s1_intermediate = cols
+                .s1_intermediate
+                .populate(blu, shard, channel, e_rr_14, e_rr_18);
+            let s1 = cols
+                .s1
+                .populate(blu, shard, channel, s1_intermediate, e_rr_41);
+
+            let e_and_f = cols.e_and_f.populate(blu, shard, channel, e, f);
+            let e_not = cols.e_not.populate(blu, shard, channel, e);
+            let e_not_and_g = cols.e_not_and_g.populate(blu, shard, channel, e_not, g);
+            let ch = cols.ch.populate(blu, shard, channel, e_and_f, e_not_and_g);
+
+            let temp1 = cols.temp1.populate(
+                blu,
+                shard,
+                channel,
+                h,
+                s1,
+                ch,
+                SHA512_COMPRESS_K[j],
+                event.w[j],
+            );
+
+            let a_rr_28 = cols.a_rr_28.populate(blu, shard, channel, a, 28);
+            let a_rr_34 = cols.a_rr_34.populate(blu, shard, channel, a, 34);
+            let a_rr_39 = cols.a_rr_39.populate(blu, shard, channel, a, 39);
+            let s0_intermediate = cols
+                .s0_intermediate
+                .populate(blu, shard, channel, a_rr_28, a_rr_34);
+            let s0 = cols
+                .s0
+                .populate(blu, shard, channel, s0_intermediate, a_rr_39);
+
+            let a_and_b = cols.a_and_b.populate(blu, shard, channel, a, b);
+            let a_and_c = cols.a_and_c.populate(blu, shard, channel, a, c);
+            let b_and_c = cols.b_and_c.populate(blu, shard, channel, b, c);
+            let maj_intermediate = cols
+                .maj_intermediate
+                .populate(blu, shard, channel, a_and_b, a_and_c);
+            let maj = cols
+                .maj
+                .populate(blu, shard, channel, maj_intermediate, b_and_c);
+
+            let temp2 = cols.temp2.populate(blu, shard, channel, s0, maj);
+
+            let d_add_temp1 = cols.d_add_temp1.populate(blu, shard, channel, d, temp1);
+            let temp1_add_temp2 = cols
+                .temp1_add_temp2
+                .populate(blu, shard, channel, temp1, temp2);
+
+            h_array[7] = g;
+            h_array[6] = f;
+            h_array[5] = e;
+            h_array[4] = d_add_temp1;
+            h_array[3] = c;
+            h_array[2] = b;
+            h_array[1] = a;
+            h_array[0] = temp1_add_temp2;
+
+            cols.is_real = F::one();
+            cols.start = cols.is_real * cols.octet_num[0] * cols.octet[0];
+
+            if rows.as_ref().is_some() {
+                rows.as_mut().unwrap().push(row);
+            }
+        }
+
+        octet_num_idx += 1;
+        // Store a, b, c, d, e, f, g, h.
+        for j in 0..8usize {
+            let mut row = [F::zero(); NUM_SHA512_COMPRESS_COLS];
+            let cols: &mut Sha512CompressCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u32(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.w_ptr = F::from_canonical_u32(event.w_ptr);
+            cols.h_ptr = F::from_canonical_u32(event.h_ptr);
+
+            cols.octet[j] = F::one();
+            cols.octet_num[octet_num_idx] = F::one();
+            cols.is_finalize = F::one();
+
+            cols.finalize_add
+                .populate(blu, shard, channel, og_h[j], h_array[j]);
+            for k in 0..2 {
+                cols.mem[k].populate_write(channel, event.h_write_records[j][k], blu);
+            }
+            cols.mem_addr = F::from_canonical_u32(event.h_ptr + (j * 8) as u32);
+
+            cols.a = u64_to_limbs(h_array[0]);
+            cols.b = u64_to_limbs(h_array[1]);
+            cols.c = u64_to_limbs(h_array[2]);
+            cols.d = u64_to_limbs(h_array[3]);
+            cols.e = u64_to_limbs(h_array[4]);
+            cols.f = u64_to_limbs(h_array[5]);
+            cols.g = u64_to_limbs(h_array[6]);
+            cols.h = u64_to_limbs(h_array[7]);
+            cols.finalized_operand = u64_to_limbs(h_array[j]);
+
+            cols.is_real = F::one();
+            cols.is_last_row = cols.octet[7] * cols.octet_num[11];
+            cols.start = cols.is_real * cols.octet_num[0] * cols.octet[0];
+
+            if rows.as_ref().is_some() {
+                rows.as_mut().unwrap().push(row);
+            }
+        }
+    }
+}
+
+/// Splits a u64 into its little-endian byte limbs.
+fn u64_to_limbs<F: PrimeField32>(value: u64) -> [F; U64_SIZE] {
+    value.to_le_bytes().map(F::from_canonical_u8)
+}
@@ -0,0 +1,264 @@
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+
+use super::{Sha512ExtendChip, Sha512ExtendCols, NUM_SHA512_EXTEND_COLS};
+use crate::air::{u64_limbs, BaseAirBuilder, SP1AirBuilder};
+use crate::memory::MemoryCols;
+use crate::operations::{
+    Add4U64Operation, FixedRotateRightU64Operation, FixedShiftRightU64Operation, XorU64Operation,
+};
+use crate::runtime::SyscallCode;
+use core::borrow::Borrow;
+
+impl<F> BaseAir<F> for Sha512ExtendChip {
+    fn width(&self) -> usize {
+        NUM_SHA512_EXTEND_COLS
+    }
+}
+
+impl<AB> Air<AB> for Sha512ExtendChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        // Initialize columns.
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Sha512ExtendCols<AB::Var> = (*local).borrow();
+        let next: &Sha512ExtendCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder
+            .when_transition()
+            .assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let i_start = AB::F::from_canonical_u32(16);
+        let nb_bytes_in_u64 = AB::F::from_canonical_u32(8);
+
+        // Evaluate the control flags.
+        self.eval_flags(builder);
+
+        // Copy over the inputs until the result has been computed (every 64 rows).
+        builder
+            .when_transition()
+            .when_not(local.cycle_16_end.result * local.cycle_64[3])
+            .assert_eq(local.shard, next.shard);
+        builder
+            .when_transition()
+            .when_not(local.cycle_16_end.result * local.cycle_64[3])
+            .assert_eq(local.clk, next.clk);
+        builder
+            .when_transition()
+            .when_not(local.cycle_16_end.result * local.cycle_64[3])
+            .assert_eq(local.channel, next.channel);
+        builder
+            .when_transition()
+            .when_not(local.cycle_16_end.result * local.cycle_64[3])
+            .assert_eq(local.w_ptr, next.w_ptr);
+
+        // Read w[i-15].
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + (local.i - i_start),
+            local.w_ptr + (local.i - AB::F::from_canonical_u32(15)) * nb_bytes_in_u64,
+            &local.w_i_minus_15,
+            local.is_real,
+        );
+
+        // Read w[i-2].
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + (local.i - i_start),
+            local.w_ptr + (local.i - AB::F::from_canonical_u32(2)) * nb_bytes_in_u64,
+            &local.w_i_minus_2,
+            local.is_real,
+        );
+
+        // Read w[i-16].
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + (local.i - i_start),
+            local.w_ptr + (local.i - AB::F::from_canonical_u32(16)) * nb_bytes_in_u64,
+            &local.w_i_minus_16,
+            local.is_real,
+        );
+
+        // Read w[i-7].
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + (local.i - i_start),
+            local.w_ptr + (local.i - AB::F::from_canonical_u32(7)) * nb_bytes_in_u64,
+            &local.w_i_minus_7,
+            local.is_real,
+        );
+
+        let w_i_minus_15 = u64_limbs(
+            *local.w_i_minus_15[0].value(),
+            *local.w_i_minus_15[1].value(),
+        );
+        let w_i_minus_2 = u64_limbs(*local.w_i_minus_2[0].value(), *local.w_i_minus_2[1].value());
+        let w_i_minus_16 = u64_limbs(
+            *local.w_i_minus_16[0].value(),
+            *local.w_i_minus_16[1].value(),
+        );
+        let w_i_minus_7 = u64_limbs(*local.w_i_minus_7[0].value(), *local.w_i_minus_7[1].value());
+
+        // Compute `s0`.
+        // w[i-15] rightrotate 1.
+        FixedRotateRightU64Operation::<AB::F>::eval(
+            builder,
+            w_i_minus_15,
+            1,
+            local.w_i_minus_15_rr_1,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        // w[i-15] rightrotate 8.
+        FixedRotateRightU64Operation::<AB::F>::eval(
+            builder,
+            w_i_minus_15,
+            8,
+            local.w_i_minus_15_rr_8,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        // w[i-15] rightshift 7.
+        FixedShiftRightU64Operation::<AB::F>::eval(
+            builder,
+            w_i_minus_15,
+            7,
+            local.w_i_minus_15_rs_7,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        // (w[i-15] rightrotate 1) xor (w[i-15] rightrotate 8)
+        XorU64Operation::<AB::F>::eval(
+            builder,
+            local.w_i_minus_15_rr_1.value,
+            local.w_i_minus_15_rr_8.value,
+            local.s0_intermediate,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        // s0 := (w[i-15] rightrotate 1) xor (w[i-15] rightrotate 8) xor (w[i-15] rightshift 7)
+        XorU64Operation::<AB::F>::eval(
+            builder,
+            local.s0_intermediate.value,
+            local.w_i_minus_15_rs_7.value,
+            local.s0,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Compute `s1`.
+        // w[i-2] rightrotate 19.
+        FixedRotateRightU64Operation::<AB::F>::eval(
+            builder,
+            w_i_minus_2,
+            19,
+            local.w_i_minus_2_rr_19,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        // w[i-2] rightrotate 61.
+        FixedRotateRightU64Operation::<AB::F>::eval(
+            builder,
+            w_i_minus_2,
+            61,
+            local.w_i_minus_2_rr_61,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        // w[i-2] rightshift 6.
+        FixedShiftRightU64Operation::<AB::F>::eval(
+            builder,
+            w_i_minus_2,
+            6,
+            local.w_i_minus_2_rs_6,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        // (w[i-2] rightrotate 19) xor (w[i-2] rightrotate 61)
+        XorU64Operation::<AB::F>::eval(
+            builder,
+            local.w_i_minus_2_rr_19.value,
+            local.w_i_minus_2_rr_61.value,
+            local.s1_intermediate,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        // s1 := (w[i-2] rightrotate 19) xor (w[i-2] rightrotate 61) xor (w[i-2] rightshift 6)
+        XorU64Operation::<AB::F>::eval(
+            builder,
+            local.s1_intermediate.value,
+            local.w_i_minus_2_rs_6.value,
+            local.s1,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // s2 := w[i-16] + s0 + w[i-7] + s1.
+        Add4U64Operation::<AB::F>::eval(
+            builder,
+            &[w_i_minus_16, local.s0.value, w_i_minus_7, local.s1.value],
+            local.shard,
+            local.channel,
+            local.is_real,
+            local.s2,
+        );
+
+        // Write `s2` to `w[i]`.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + (local.i - i_start),
+            local.w_ptr + local.i * nb_bytes_in_u64,
+            &local.w_i,
+            local.is_real,
+        );
+
+        let w_i = u64_limbs(*local.w_i[0].value(), *local.w_i[1].value());
+        builder.assert_all_eq(w_i, local.s2.value);
+
+        // Receive syscall event in first row of 64-cycle.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::SHA512_EXTEND.syscall_id()),
+            local.w_ptr,
+            AB::Expr::zero(),
+            local.cycle_64_start,
+        );
+
+        // Assert that is_real is a bool.
+        builder.assert_bool(local.is_real);
+
+        // Ensure that all rows in a 64 row cycle has the same `is_real` values.
+        builder
+            .when_transition()
+            .when_not(local.cycle_64_end)
+            .assert_eq(local.is_real, next.is_real);
+
+        // Assert that the table ends in nonreal columns. The trace generation always pads the
+        // table with at least one extra row, so the last row of the table is always padding.
+        builder.when_last_row().assert_zero(local.is_real);
+    }
+}
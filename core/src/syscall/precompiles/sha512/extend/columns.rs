@@ -0,0 +1,77 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+
+use crate::memory::MemoryReadCols;
+use crate::memory::MemoryWriteCols;
+use crate::operations::Add4U64Operation;
+use crate::operations::FixedRotateRightU64Operation;
+use crate::operations::FixedShiftRightU64Operation;
+use crate::operations::IsZeroOperation;
+use crate::operations::XorU64Operation;
+
+pub const NUM_SHA512_EXTEND_COLS: usize = size_of::<Sha512ExtendCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Sha512ExtendCols<T> {
+    /// Inputs.
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub w_ptr: T,
+
+    /// Control flags.
+    pub i: T,
+
+    /// g^n where g is generator with order 16 and n is the row number.
+    pub cycle_16: T,
+
+    /// Checks whether current row is start of a 16-row cycle. Bool result is stored in `result`.
+    pub cycle_16_start: IsZeroOperation<T>,
+
+    /// Checks whether current row is end of a 16-row cycle. Bool result is stored in `result`.
+    pub cycle_16_end: IsZeroOperation<T>,
+
+    /// Flags for when in the first, second, third, or fourth 16-row cycle.
+    pub cycle_64: [T; 4],
+
+    /// Whether the current row is the first of a 64-row cycle and is real.
+    pub cycle_64_start: T,
+    /// Whether the current row is the end of a 64-row cycle and is real.
+    pub cycle_64_end: T,
+
+    /// Inputs to `s0`. Each 64-bit value spans two memory words.
+    pub w_i_minus_15: [MemoryReadCols<T>; 2],
+    pub w_i_minus_15_rr_1: FixedRotateRightU64Operation<T>,
+    pub w_i_minus_15_rr_8: FixedRotateRightU64Operation<T>,
+    pub w_i_minus_15_rs_7: FixedShiftRightU64Operation<T>,
+    pub s0_intermediate: XorU64Operation<T>,
+
+    /// `s0 := (w[i-15] rightrotate 1) xor (w[i-15] rightrotate 8) xor (w[i-15] rightshift 7)`.
+    pub s0: XorU64Operation<T>,
+
+    /// Inputs to `s1`.
+    pub w_i_minus_2: [MemoryReadCols<T>; 2],
+    pub w_i_minus_2_rr_19: FixedRotateRightU64Operation<T>,
+    pub w_i_minus_2_rr_61: FixedRotateRightU64Operation<T>,
+    pub w_i_minus_2_rs_6: FixedShiftRightU64Operation<T>,
+    pub s1_intermediate: XorU64Operation<T>,
+
+    /// `s1 := (w[i-2] rightrotate 19) xor (w[i-2] rightrotate 61) xor (w[i-2] rightshift 6)`.
+    pub s1: XorU64Operation<T>,
+
+    /// Inputs to `s2`.
+    pub w_i_minus_16: [MemoryReadCols<T>; 2],
+    pub w_i_minus_7: [MemoryReadCols<T>; 2],
+
+    /// `w[i] := w[i-16] + s0 + w[i-7] + s1`.
+    pub s2: Add4U64Operation<T>,
+
+    /// Result.
+    pub w_i: [MemoryWriteCols<T>; 2],
+
+    /// Selector.
+    pub is_real: T,
+}
@@ -0,0 +1,88 @@
+use crate::{
+    runtime::{MemoryReadRecord, MemoryWriteRecord, Syscall},
+    syscall::precompiles::{sha512::Sha512ExtendEvent, SyscallContext},
+};
+
+use super::Sha512ExtendChip;
+
+impl Syscall for Sha512ExtendChip {
+    fn num_extra_cycles(&self) -> u32 {
+        64
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk_init = rt.clk;
+        let w_ptr = arg1;
+        if arg2 != 0 {
+            panic!("arg2 must be 0")
+        }
+
+        let w_ptr_init = w_ptr;
+        let mut w_i_minus_15_reads: Vec<[MemoryReadRecord; 2]> = Vec::new();
+        let mut w_i_minus_2_reads: Vec<[MemoryReadRecord; 2]> = Vec::new();
+        let mut w_i_minus_16_reads: Vec<[MemoryReadRecord; 2]> = Vec::new();
+        let mut w_i_minus_7_reads: Vec<[MemoryReadRecord; 2]> = Vec::new();
+        let mut w_i_writes: Vec<[MemoryWriteRecord; 2]> = Vec::new();
+        for i in 16..80 {
+            // Read w[i-15].
+            let (records, w_i_minus_15) = rt.mr_slice(w_ptr + (i - 15) * 8, 2);
+            w_i_minus_15_reads.push(records.try_into().unwrap());
+            let w_i_minus_15 = w_i_minus_15[0] as u64 | ((w_i_minus_15[1] as u64) << 32);
+
+            // Compute `s0`.
+            let s0 =
+                w_i_minus_15.rotate_right(1) ^ w_i_minus_15.rotate_right(8) ^ (w_i_minus_15 >> 7);
+
+            // Read w[i-2].
+            let (records, w_i_minus_2) = rt.mr_slice(w_ptr + (i - 2) * 8, 2);
+            w_i_minus_2_reads.push(records.try_into().unwrap());
+            let w_i_minus_2 = w_i_minus_2[0] as u64 | ((w_i_minus_2[1] as u64) << 32);
+
+            // Compute `s1`.
+            let s1 =
+                w_i_minus_2.rotate_right(19) ^ w_i_minus_2.rotate_right(61) ^ (w_i_minus_2 >> 6);
+
+            // Read w[i-16].
+            let (records, w_i_minus_16) = rt.mr_slice(w_ptr + (i - 16) * 8, 2);
+            w_i_minus_16_reads.push(records.try_into().unwrap());
+            let w_i_minus_16 = w_i_minus_16[0] as u64 | ((w_i_minus_16[1] as u64) << 32);
+
+            // Read w[i-7].
+            let (records, w_i_minus_7) = rt.mr_slice(w_ptr + (i - 7) * 8, 2);
+            w_i_minus_7_reads.push(records.try_into().unwrap());
+            let w_i_minus_7 = w_i_minus_7[0] as u64 | ((w_i_minus_7[1] as u64) << 32);
+
+            // Compute `w_i`.
+            let w_i = s1
+                .wrapping_add(w_i_minus_16)
+                .wrapping_add(s0)
+                .wrapping_add(w_i_minus_7);
+
+            // Write w[i].
+            let records = rt.mw_slice(w_ptr + i * 8, &[w_i as u32, (w_i >> 32) as u32]);
+            w_i_writes.push(records.try_into().unwrap());
+            rt.clk += 1;
+        }
+
+        // Push the SHA-512 extend event.
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut()
+            .sha512_extend_events
+            .push(Sha512ExtendEvent {
+                lookup_id,
+                shard,
+                channel,
+                clk: clk_init,
+                w_ptr: w_ptr_init,
+                w_i_minus_15_reads,
+                w_i_minus_2_reads,
+                w_i_minus_16_reads,
+                w_i_minus_7_reads,
+                w_i_writes,
+            });
+
+        None
+    }
+}
@@ -0,0 +1,133 @@
+mod air;
+mod columns;
+mod execute;
+mod flags;
+mod trace;
+
+pub use columns::*;
+
+use crate::runtime::{MemoryReadRecord, MemoryWriteRecord};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sha512ExtendEvent {
+    pub lookup_id: u128,
+    pub shard: u32,
+    pub channel: u32,
+    pub clk: u32,
+    pub w_ptr: u32,
+    pub w_i_minus_15_reads: Vec<[MemoryReadRecord; 2]>,
+    pub w_i_minus_2_reads: Vec<[MemoryReadRecord; 2]>,
+    pub w_i_minus_16_reads: Vec<[MemoryReadRecord; 2]>,
+    pub w_i_minus_7_reads: Vec<[MemoryReadRecord; 2]>,
+    pub w_i_writes: Vec<[MemoryWriteRecord; 2]>,
+}
+
+/// Implements the SHA-512 extension operation which loops over i = [16, 79] and modifies w[i] in
+/// each iteration. The only input to the syscall is the 8byte-aligned pointer to the w array, where
+/// each u64 is stored as two little-endian words.
+///
+/// In the AIR, each SHA-512 extend syscall takes up 64 rows, where each row corresponds to a single
+/// iteration of the loop.
+#[derive(Default)]
+pub struct Sha512ExtendChip;
+
+impl Sha512ExtendChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+pub fn sha512_extend(w: &mut [u64]) {
+    for i in 16..80 {
+        let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+        let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+}
+
+#[cfg(test)]
+pub mod extend_tests {
+
+    use p3_baby_bear::BabyBear;
+
+    use p3_matrix::dense::RowMajorMatrix;
+
+    use crate::{
+        air::MachineAir,
+        alu::AluEvent,
+        runtime::{ExecutionRecord, Instruction, Opcode, Program, Runtime, SyscallCode},
+        stark::DefaultProver,
+        utils::{self, run_test, tests::SHA512_EXTEND_ELF, SP1CoreOpts},
+    };
+
+    use super::{sha512_extend, Sha512ExtendChip, NUM_SHA512_EXTEND_COLS};
+
+    pub fn sha512_extend_program() -> Program {
+        let w_ptr = 100;
+        let mut instructions = vec![Instruction::new(Opcode::ADD, 29, 0, 5, false, true)];
+        for i in 0..160 {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 30, 0, w_ptr + i * 4, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            ]);
+        }
+        instructions.extend(vec![
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::SHA512_EXTEND as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, w_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn generate_trace() {
+        let mut shard = ExecutionRecord::default();
+        shard.add_events = vec![AluEvent::new(0, 0, 0, Opcode::ADD, 14, 8, 6)];
+        let chip = Sha512ExtendChip::new();
+        let trace: RowMajorMatrix<BabyBear> =
+            chip.generate_trace(&shard, &mut ExecutionRecord::default());
+        assert_eq!(trace.width, NUM_SHA512_EXTEND_COLS);
+    }
+
+    #[test]
+    fn test_sha512_extend_execute() {
+        let program = sha512_extend_program();
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let mut expected = [0u64; 80];
+        expected[..16].fill(5 | (5 << 32));
+        sha512_extend(&mut expected);
+        for (i, w) in expected.iter().enumerate() {
+            let lo = runtime.word(100 + i as u32 * 8) as u64;
+            let hi = runtime.word(100 + i as u32 * 8 + 4) as u64;
+            assert_eq!(lo | (hi << 32), *w);
+        }
+    }
+
+    #[test]
+    fn test_sha512_extend_prove() {
+        utils::setup_logger();
+        let program = sha512_extend_program();
+        run_test::<DefaultProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn test_sha512_extend_program() {
+        utils::setup_logger();
+        let program = Program::from(SHA512_EXTEND_ELF);
+        run_test::<DefaultProver<_, _>>(program).unwrap();
+    }
+}
@@ -79,6 +79,7 @@ impl<E: EllipticCurve> Syscall for WeierstrassAddAssignChip<E> {
         let event = create_ec_add_event::<E>(rt, arg1, arg2);
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => rt.record_mut().secp256k1_add_events.push(event),
+            CurveType::Secp256r1 => rt.record_mut().secp256r1_add_events.push(event),
             CurveType::Bn254 => rt.record_mut().bn254_add_events.push(event),
             CurveType::Bls12381 => rt.record_mut().bls12381_add_events.push(event),
             _ => panic!("Unsupported curve"),
@@ -209,6 +210,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
     fn name(&self) -> String {
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => "Secp256k1AddAssign".to_string(),
+            CurveType::Secp256r1 => "Secp256r1AddAssign".to_string(),
             CurveType::Bn254 => "Bn254AddAssign".to_string(),
             CurveType::Bls12381 => "Bls12381AddAssign".to_string(),
             _ => panic!("Unsupported curve"),
@@ -222,6 +224,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
     ) -> RowMajorMatrix<F> {
         let events = match E::CURVE_TYPE {
             CurveType::Secp256k1 => &input.secp256k1_add_events,
+            CurveType::Secp256r1 => &input.secp256r1_add_events,
             CurveType::Bn254 => &input.bn254_add_events,
             CurveType::Bls12381 => &input.bls12381_add_events,
             _ => panic!("Unsupported curve"),
@@ -323,6 +326,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
     fn included(&self, shard: &Self::Record) -> bool {
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => !shard.secp256k1_add_events.is_empty(),
+            CurveType::Secp256r1 => !shard.secp256r1_add_events.is_empty(),
             CurveType::Bn254 => !shard.bn254_add_events.is_empty(),
             CurveType::Bls12381 => !shard.bls12381_add_events.is_empty(),
             _ => panic!("Unsupported curve"),
@@ -499,6 +503,9 @@ where
             CurveType::Secp256k1 => {
                 AB::F::from_canonical_u32(SyscallCode::SECP256K1_ADD.syscall_id())
             }
+            CurveType::Secp256r1 => {
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_ADD.syscall_id())
+            }
             CurveType::Bn254 => AB::F::from_canonical_u32(SyscallCode::BN254_ADD.syscall_id()),
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_ADD.syscall_id())
@@ -523,14 +530,17 @@ where
 mod tests {
 
     use crate::{
-        runtime::Program,
+        io::SP1Stdin,
+        runtime::{Instruction, Opcode, Program, Runtime, SyscallCode},
         stark::DefaultProver,
         utils::{
-            run_test, setup_logger,
+            ec::weierstrass::{secp256r1::Secp256r1Parameters, SwCurve},
+            run_test, run_test_core, setup_logger,
             tests::{
                 BLS12381_ADD_ELF, BLS12381_DOUBLE_ELF, BLS12381_MUL_ELF, BN254_ADD_ELF,
                 BN254_MUL_ELF, SECP256K1_ADD_ELF, SECP256K1_MUL_ELF,
             },
+            SP1CoreOpts,
         },
     };
 
@@ -582,4 +592,47 @@ mod tests {
         let program = Program::from(BLS12381_MUL_ELF);
         run_test::<DefaultProver<_, _>>(program).unwrap();
     }
+
+    #[test]
+    fn test_secp256r1_add_simple() {
+        setup_logger();
+        let p_ptr = 100;
+        let q_ptr = 1000;
+        let generator = SwCurve::<Secp256r1Parameters>::generator();
+        let generator_2 = generator.sw_double();
+
+        let mut instructions = vec![];
+        for (ptr, point) in [(p_ptr, &generator), (q_ptr, &generator_2)] {
+            for (i, word) in point.to_words_le().into_iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, ptr + i as u32 * 4, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                ]);
+            }
+        }
+        instructions.extend(vec![
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::SECP256R1_ADD as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, p_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, q_ptr, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        let program = Program::new(instructions, 0, 0);
+
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let expected = generator.sw_add(&generator_2).to_words_le();
+        for (i, word) in expected.iter().enumerate() {
+            assert_eq!(runtime.word(p_ptr + i as u32 * 4), *word);
+        }
+
+        run_test_core::<DefaultProver<_, _>>(runtime, SP1Stdin::new()).unwrap();
+    }
 }
@@ -31,6 +31,7 @@ use crate::syscall::precompiles::create_ec_decompress_event;
 use crate::syscall::precompiles::SyscallContext;
 use crate::utils::ec::weierstrass::bls12_381::bls12381_sqrt;
 use crate::utils::ec::weierstrass::secp256k1::secp256k1_sqrt;
+use crate::utils::ec::weierstrass::secp256r1::secp256r1_sqrt;
 use crate::utils::ec::weierstrass::WeierstrassParameters;
 use crate::utils::ec::CurveType;
 use crate::utils::ec::EllipticCurve;
@@ -59,6 +60,8 @@ pub struct WeierstrassDecompressCols<T, P: FieldParameters + NumWords> {
     pub(crate) range_x: FieldLtCols<T, P>,
     pub(crate) x_2: FieldOpCols<T, P>,
     pub(crate) x_3: FieldOpCols<T, P>,
+    pub(crate) ax: FieldOpCols<T, P>,
+    pub(crate) x_3_plus_ax: FieldOpCols<T, P>,
     pub(crate) x_3_plus_b: FieldOpCols<T, P>,
     pub(crate) y: FieldSqrtCols<T, P>,
     pub(crate) neg_y: FieldOpCols<T, P>,
@@ -101,6 +104,7 @@ impl<E: EllipticCurve> Syscall for WeierstrassDecompressChip<E> {
         let event = create_ec_decompress_event::<E>(rt, arg1, arg2);
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => rt.record_mut().k256_decompress_events.push(event),
+            CurveType::Secp256r1 => rt.record_mut().secp256r1_decompress_events.push(event),
             CurveType::Bls12381 => rt.record_mut().bls12381_decompress_events.push(event),
             _ => panic!("Unsupported curve"),
         }
@@ -141,7 +145,7 @@ impl<E: EllipticCurve + WeierstrassParameters> WeierstrassDecompressChip<E> {
         cols: &mut WeierstrassDecompressCols<F, E::BaseField>,
         x: BigUint,
     ) {
-        // Y = sqrt(x^3 + b)
+        // Y = sqrt(x^3 + ax + b)
         cols.range_x
             .populate(record, shard, channel, &x, &E::BaseField::modulus());
         let x_2 = cols.x_2.populate(
@@ -155,13 +159,26 @@ impl<E: EllipticCurve + WeierstrassParameters> WeierstrassDecompressChip<E> {
         let x_3 = cols
             .x_3
             .populate(record, shard, channel, &x_2, &x, FieldOperation::Mul);
+        let a = E::a_int();
+        let ax = cols
+            .ax
+            .populate(record, shard, channel, &a, &x, FieldOperation::Mul);
+        let x_3_plus_ax =
+            cols.x_3_plus_ax
+                .populate(record, shard, channel, &x_3, &ax, FieldOperation::Add);
         let b = E::b_int();
-        let x_3_plus_b =
-            cols.x_3_plus_b
-                .populate(record, shard, channel, &x_3, &b, FieldOperation::Add);
+        let x_3_plus_b = cols.x_3_plus_b.populate(
+            record,
+            shard,
+            channel,
+            &x_3_plus_ax,
+            &b,
+            FieldOperation::Add,
+        );
 
         let sqrt_fn = match E::CURVE_TYPE {
             CurveType::Secp256k1 => secp256k1_sqrt,
+            CurveType::Secp256r1 => secp256r1_sqrt,
             CurveType::Bls12381 => bls12381_sqrt,
             _ => panic!("Unsupported curve"),
         };
@@ -184,6 +201,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
     fn name(&self) -> String {
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => "Secp256k1Decompress".to_string(),
+            CurveType::Secp256r1 => "Secp256r1Decompress".to_string(),
             CurveType::Bls12381 => "Bls12381Decompress".to_string(),
             _ => panic!("Unsupported curve"),
         }
@@ -196,6 +214,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
     ) -> RowMajorMatrix<F> {
         let events = match E::CURVE_TYPE {
             CurveType::Secp256k1 => &input.k256_decompress_events,
+            CurveType::Secp256r1 => &input.secp256r1_decompress_events,
             CurveType::Bls12381 => &input.bls12381_decompress_events,
             _ => panic!("Unsupported curve"),
         };
@@ -309,7 +328,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             let cols: &mut WeierstrassDecompressCols<F, E::BaseField> =
                 row.as_mut_slice()[0..weierstrass_width].borrow_mut();
 
-            // take X of the generator as a dummy value to make sure Y^2 = X^3 + b holds
+            // take X of the generator as a dummy value to make sure Y^2 = X^3 + aX + b holds
             let dummy_value = E::generator().0;
             let dummy_bytes = dummy_value.to_bytes_le();
             let words = bytes_to_words_le_vec(&dummy_bytes);
@@ -336,6 +355,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
     fn included(&self, shard: &Self::Record) -> bool {
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => !shard.k256_decompress_events.is_empty(),
+            CurveType::Secp256r1 => !shard.secp256r1_decompress_events.is_empty(),
             CurveType::Bls12381 => !shard.bls12381_decompress_events.is_empty(),
             _ => panic!("Unsupported curve"),
         }
@@ -410,11 +430,31 @@ where
             local.channel,
             local.is_real,
         );
+        let a = E::a_int();
+        let a_const = E::BaseField::to_limbs_field::<AB::F, _>(&a);
+        local.ax.eval(
+            builder,
+            &a_const,
+            &x,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x_3_plus_ax.eval(
+            builder,
+            &local.x_3.result,
+            &local.ax.result,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
         let b = E::b_int();
         let b_const = E::BaseField::to_limbs_field::<AB::F, _>(&b);
         local.x_3_plus_b.eval(
             builder,
-            &local.x_3.result,
+            &local.x_3_plus_ax.result,
             &b_const,
             FieldOperation::Add,
             local.shard,
@@ -589,6 +629,9 @@ where
             CurveType::Secp256k1 => {
                 AB::F::from_canonical_u32(SyscallCode::SECP256K1_DECOMPRESS.syscall_id())
             }
+            CurveType::Secp256r1 => {
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_DECOMPRESS.syscall_id())
+            }
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_DECOMPRESS.syscall_id())
             }
@@ -620,8 +663,11 @@ mod tests {
     use elliptic_curve::sec1::ToEncodedPoint;
     use rand::{thread_rng, Rng};
 
-    use crate::utils::run_test_io;
+    use crate::runtime::{Instruction, Opcode, Runtime, SyscallCode};
+    use crate::utils::ec::weierstrass::{secp256r1::Secp256r1Parameters, SwCurve};
     use crate::utils::tests::SECP256K1_DECOMPRESS_ELF;
+    use crate::utils::{run_test_core, run_test_io, SP1CoreOpts};
+    use num::BigUint;
 
     #[test]
     fn test_weierstrass_bls_decompress() {
@@ -678,4 +724,51 @@ mod tests {
             assert_eq!(result, decompressed);
         }
     }
+
+    #[test]
+    fn test_weierstrass_secp256r1_decompress() {
+        utils::setup_logger();
+        let ptr = 100;
+
+        // Decompress both 2G and 3G so that both parities of y are likely to be covered.
+        let generator = SwCurve::<Secp256r1Parameters>::generator();
+        let generator_2 = generator.sw_double();
+        for point in [generator_2.clone(), generator.sw_add(&generator_2)] {
+            let words = point.to_words_le();
+            let is_odd = (&point.y % 2u32) == BigUint::from(1u32);
+
+            // The x coordinate is stored in the second half of the slice and the y coordinate is
+            // written to the first half.
+            let mut instructions = vec![];
+            for (i, word) in words[..8].iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, *word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, ptr + 32 + i as u32 * 4, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                ]);
+            }
+            instructions.extend(vec![
+                Instruction::new(
+                    Opcode::ADD,
+                    5,
+                    0,
+                    SyscallCode::SECP256R1_DECOMPRESS as u32,
+                    false,
+                    true,
+                ),
+                Instruction::new(Opcode::ADD, 10, 0, ptr, false, true),
+                Instruction::new(Opcode::ADD, 11, 0, is_odd as u32, false, true),
+                Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            ]);
+            let program = Program::new(instructions, 0, 0);
+
+            let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+            runtime.run().unwrap();
+            for (i, word) in words[8..].iter().enumerate() {
+                assert_eq!(runtime.word(ptr + i as u32 * 4), *word);
+            }
+
+            run_test_core::<DefaultProver<_, _>>(runtime, SP1Stdin::new()).unwrap();
+        }
+    }
 }
@@ -5,7 +5,6 @@ use std::marker::PhantomData;
 
 use generic_array::GenericArray;
 use num::BigUint;
-use num::Zero;
 use p3_air::AirBuilder;
 use p3_air::{Air, BaseAir};
 use p3_field::AbstractField;
@@ -80,6 +79,7 @@ impl<E: EllipticCurve + WeierstrassParameters> Syscall for WeierstrassDoubleAssi
         let event = create_ec_double_event::<E>(rt, arg1, arg2);
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => rt.record_mut().secp256k1_double_events.push(event),
+            CurveType::Secp256r1 => rt.record_mut().secp256r1_double_events.push(event),
             CurveType::Bn254 => rt.record_mut().bn254_double_events.push(event),
             CurveType::Bls12381 => rt.record_mut().bls12381_double_events.push(event),
             _ => panic!("Unsupported curve"),
@@ -228,6 +228,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
     fn name(&self) -> String {
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => "Secp256k1DoubleAssign".to_string(),
+            CurveType::Secp256r1 => "Secp256r1DoubleAssign".to_string(),
             CurveType::Bn254 => "Bn254DoubleAssign".to_string(),
             CurveType::Bls12381 => "Bls12381DoubleAssign".to_string(),
             _ => panic!("Unsupported curve"),
@@ -242,6 +243,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
         // collects the events based on the curve type.
         let events = match E::CURVE_TYPE {
             CurveType::Secp256k1 => &input.secp256k1_double_events,
+            CurveType::Secp256r1 => &input.secp256r1_double_events,
             CurveType::Bn254 => &input.bn254_double_events,
             CurveType::Bls12381 => &input.bls12381_double_events,
             _ => panic!("Unsupported curve"),
@@ -312,8 +314,15 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             let mut row = vec![F::zero(); num_weierstrass_double_cols::<E::BaseField>()];
             let cols: &mut WeierstrassDoubleAssignCols<F, E::BaseField> =
                 row.as_mut_slice().borrow_mut();
-            let zero = BigUint::zero();
-            Self::populate_field_ops(&mut vec![], 0, 0, cols, zero.clone(), zero.clone());
+
+            // Take the generator as a dummy point, since doubling the zero point divides a nonzero
+            // slope numerator by zero on curves with `a != 0`.
+            let (dummy_x, dummy_y) = E::generator();
+            let dummy_point = AffinePoint::<E>::new(dummy_x.clone(), dummy_y.clone());
+            for (i, word) in dummy_point.to_words_le().into_iter().enumerate() {
+                cols.p_access[i].prev_value = word.into();
+            }
+            Self::populate_field_ops(&mut vec![], 0, 0, cols, dummy_x, dummy_y);
             row
         });
 
@@ -338,6 +347,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
     fn included(&self, shard: &Self::Record) -> bool {
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => !shard.secp256k1_double_events.is_empty(),
+            CurveType::Secp256r1 => !shard.secp256r1_double_events.is_empty(),
             CurveType::Bn254 => !shard.bn254_double_events.is_empty(),
             CurveType::Bls12381 => !shard.bls12381_double_events.is_empty(),
             _ => panic!("Unsupported curve"),
@@ -524,6 +534,9 @@ where
             CurveType::Secp256k1 => {
                 AB::F::from_canonical_u32(SyscallCode::SECP256K1_DOUBLE.syscall_id())
             }
+            CurveType::Secp256r1 => {
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_DOUBLE.syscall_id())
+            }
             CurveType::Bn254 => AB::F::from_canonical_u32(SyscallCode::BN254_DOUBLE.syscall_id()),
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_DOUBLE.syscall_id())
@@ -548,11 +561,14 @@ where
 pub mod tests {
 
     use crate::{
-        runtime::Program,
+        io::SP1Stdin,
+        runtime::{Instruction, Opcode, Program, Runtime, SyscallCode},
         stark::DefaultProver,
         utils::{
-            run_test, setup_logger,
+            ec::weierstrass::{secp256r1::Secp256r1Parameters, SwCurve},
+            run_test, run_test_core, setup_logger,
             tests::{BLS12381_DOUBLE_ELF, BN254_DOUBLE_ELF, SECP256K1_DOUBLE_ELF},
+            SP1CoreOpts,
         },
     };
 
@@ -576,4 +592,43 @@ pub mod tests {
         let program = Program::from(BLS12381_DOUBLE_ELF);
         run_test::<DefaultProver<_, _>>(program).unwrap();
     }
+
+    #[test]
+    fn test_secp256r1_double_simple() {
+        setup_logger();
+        let p_ptr = 100;
+        let generator = SwCurve::<Secp256r1Parameters>::generator();
+
+        let mut instructions = vec![];
+        for (i, word) in generator.to_words_le().into_iter().enumerate() {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                Instruction::new(Opcode::ADD, 30, 0, p_ptr + i as u32 * 4, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            ]);
+        }
+        instructions.extend(vec![
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::SECP256R1_DOUBLE as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, p_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        let program = Program::new(instructions, 0, 0);
+
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let expected = generator.sw_double().to_words_le();
+        for (i, word) in expected.iter().enumerate() {
+            assert_eq!(runtime.word(p_ptr + i as u32 * 4), *word);
+        }
+
+        run_test_core::<DefaultProver<_, _>>(runtime, SP1Stdin::new()).unwrap();
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    runtime::{Register, Syscall, SyscallContext},
+    runtime::{append_stderr_tail, OutputMode, Register, Runtime, Syscall, SyscallContext},
     utils::num_to_comma_separated,
 };
 
@@ -32,11 +32,7 @@ impl Syscall for SyscallWrite {
                     .unwrap()
                     .trim_end()
                     .trim_start();
-                let depth = rt.cycle_tracker.len() as u32;
-                rt.cycle_tracker
-                    .insert(fn_name.to_string(), (rt.state.global_clk, depth));
-                let padding = (0..depth).map(|_| "│ ").collect::<String>();
-                log::debug!("{}┌╴{}", padding, fn_name);
+                start_cycle_tracker(rt, fn_name);
             } else if s.contains("cycle-tracker-end:") {
                 let fn_name = s
                     .split("cycle-tracker-end:")
@@ -44,29 +40,35 @@ impl Syscall for SyscallWrite {
                     .unwrap()
                     .trim_end()
                     .trim_start();
-                let (start, depth) = rt.cycle_tracker.remove(fn_name).unwrap_or((0, 0));
-                // Leftpad by 2 spaces for each depth.
-                let padding = (0..depth).map(|_| "│ ").collect::<String>();
-                log::info!(
-                    "{}└╴{} cycles",
-                    padding,
-                    num_to_comma_separated(rt.state.global_clk - start as u64)
-                );
+                end_cycle_tracker(rt, fn_name);
             } else {
-                let flush_s = update_io_buf(ctx, fd, s);
-                if !flush_s.is_empty() {
-                    flush_s
-                        .into_iter()
-                        .for_each(|line| println!("stdout: {}", line));
+                match rt.stdout.clone() {
+                    OutputMode::Passthrough => {
+                        let flush_s = update_io_buf(ctx, fd, s);
+                        if !flush_s.is_empty() {
+                            flush_s
+                                .into_iter()
+                                .for_each(|line| println!("stdout: {}", line));
+                        }
+                    }
+                    OutputMode::Capture(buffer) => buffer.write(slice),
+                    OutputMode::Discard => {}
                 }
             }
         } else if fd == 2 {
             let s = core::str::from_utf8(slice).unwrap();
-            let flush_s = update_io_buf(ctx, fd, s);
-            if !flush_s.is_empty() {
-                flush_s
-                    .into_iter()
-                    .for_each(|line| println!("stderr: {}", line));
+            append_stderr_tail(&mut rt.stderr_tail, s);
+            match rt.stderr.clone() {
+                OutputMode::Passthrough => {
+                    let flush_s = update_io_buf(ctx, fd, s);
+                    if !flush_s.is_empty() {
+                        flush_s
+                            .into_iter()
+                            .for_each(|line| println!("stderr: {}", line));
+                    }
+                }
+                OutputMode::Capture(buffer) => buffer.write(slice),
+                OutputMode::Discard => {}
             }
         } else if fd == 3 {
             rt.state.public_values_stream.extend_from_slice(slice);
@@ -84,6 +86,32 @@ impl Syscall for SyscallWrite {
     }
 }
 
+/// Open a cycle-tracker span named `name` at the current cycle.
+fn start_cycle_tracker(rt: &mut Runtime, name: &str) {
+    let depth = rt.cycle_tracker.len();
+    rt.cycle_tracker
+        .push((name.to_string(), rt.state.global_clk));
+    let padding = "│ ".repeat(depth);
+    log::debug!("{}┌╴{}", padding, name);
+}
+
+/// Close the innermost open cycle-tracker span named `name`, recording its cycles in the report.
+fn end_cycle_tracker(rt: &mut Runtime, name: &str) {
+    let Some(depth) = rt.cycle_tracker.iter().rposition(|(n, _)| n == name) else {
+        log::warn!("cycle-tracker-end for {} without a matching start", name);
+        return;
+    };
+    let (_, start) = rt.cycle_tracker.remove(depth);
+    let cycles = rt.state.global_clk - start;
+    if rt.print_report && !rt.unconstrained {
+        rt.report
+            .record_cycle_tracker_span(name, depth as u32, cycles);
+    }
+    // Leftpad by 2 spaces for each depth.
+    let padding = "│ ".repeat(depth);
+    log::info!("{}└╴{} cycles", padding, num_to_comma_separated(cycles));
+}
+
 pub fn update_io_buf(ctx: &mut SyscallContext, fd: u32, s: &str) -> Vec<String> {
     let rt = &mut ctx.rt;
     let entry = rt.io_buf.entry(fd).or_default();
@@ -102,3 +130,63 @@ pub fn update_io_buf(ctx: &mut SyscallContext, fd: u32, s: &str) -> Vec<String>
         vec![]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{end_cycle_tracker, start_cycle_tracker};
+    use crate::runtime::{OutputMode, Program, Runtime};
+    use crate::utils::tests::{BLS12381_ADD_ELF, CYCLE_TRACKER_ELF};
+    use crate::utils::SP1CoreOpts;
+
+    #[test]
+    fn test_cycle_tracker_report() {
+        let program = Program::from(CYCLE_TRACKER_ELF);
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        runtime.run_untraced().unwrap();
+
+        for name in ["f", "g"] {
+            let span = runtime.report.cycle_tracker_span(name).unwrap();
+            assert_eq!(span.depth, 0);
+            assert_eq!(span.count, 1);
+            assert!(span.total_cycles > 0);
+        }
+        assert!(runtime.cycle_tracker.is_empty());
+    }
+
+    #[test]
+    fn test_nested_cycle_tracker() {
+        let mut runtime = Runtime::new(Program::default(), SP1CoreOpts::default());
+        runtime.print_report = true;
+
+        start_cycle_tracker(&mut runtime, "rec");
+        runtime.state.global_clk += 10;
+        start_cycle_tracker(&mut runtime, "rec");
+        runtime.state.global_clk += 5;
+        end_cycle_tracker(&mut runtime, "rec");
+        start_cycle_tracker(&mut runtime, "rec");
+        runtime.state.global_clk += 3;
+        end_cycle_tracker(&mut runtime, "rec");
+        end_cycle_tracker(&mut runtime, "rec");
+        end_cycle_tracker(&mut runtime, "unmatched");
+
+        let spans = &runtime.report.cycle_tracker;
+        assert_eq!(spans.len(), 2);
+        assert_eq!((spans[0].depth, spans[0].count), (1, 2));
+        assert_eq!((spans[0].min_cycles, spans[0].max_cycles), (3, 5));
+        assert_eq!((spans[1].depth, spans[1].count), (0, 1));
+        assert_eq!(spans[1].total_cycles, 18);
+        assert_eq!(runtime.report.cycle_tracker_span("rec"), Some(&spans[1]));
+    }
+
+    #[test]
+    fn test_capture_output() {
+        let program = Program::from(BLS12381_ADD_ELF);
+        let mut runtime = Runtime::new(program, SP1CoreOpts::default());
+        runtime.stdout = OutputMode::capture();
+        runtime.stderr = OutputMode::Discard;
+        runtime.run_untraced().unwrap();
+
+        assert_eq!(runtime.stdout.captured().unwrap(), b"done\n");
+        assert!(runtime.io_buf.values().all(|buf| buf.is_empty()));
+    }
+}
@@ -9,6 +9,7 @@ use typenum::{U48, U94};
 use super::{SwCurve, WeierstrassParameters};
 use crate::operations::field::params::FieldParameters;
 use crate::operations::field::params::NumLimbs;
+use crate::operations::field::params::{FieldType, FpOpField};
 use crate::utils::ec::CurveType;
 use crate::utils::ec::EllipticCurveParameters;
 
@@ -58,6 +59,10 @@ impl NumLimbs for Bls12381BaseField {
     type Witness = U94;
 }
 
+impl FpOpField for Bls12381BaseField {
+    const FIELD_TYPE: FieldType = FieldType::Bls12381;
+}
+
 impl EllipticCurveParameters for Bls12381Parameters {
     type BaseField = Bls12381BaseField;
     const CURVE_TYPE: CurveType = CurveType::Bls12381;
@@ -6,6 +6,7 @@ use typenum::{U32, U62};
 use super::{SwCurve, WeierstrassParameters};
 use crate::operations::field::params::FieldParameters;
 use crate::operations::field::params::NumLimbs;
+use crate::operations::field::params::{FieldType, FpOpField};
 use crate::utils::ec::CurveType;
 use crate::utils::ec::EllipticCurveParameters;
 
@@ -44,6 +45,10 @@ impl NumLimbs for Bn254BaseField {
     type Witness = U62;
 }
 
+impl FpOpField for Bn254BaseField {
+    const FIELD_TYPE: FieldType = FieldType::Bn254;
+}
+
 impl EllipticCurveParameters for Bn254Parameters {
     type BaseField = Bn254BaseField;
 
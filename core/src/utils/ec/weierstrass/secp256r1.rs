@@ -0,0 +1,178 @@
+//! Modulo defining the Secp256r1 curve and its base field. The constants are all taken from
+//! https://neuromancer.sk/std/secg/secp256r1.
+
+use std::str::FromStr;
+
+use generic_array::GenericArray;
+use num::BigUint;
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use super::{SwCurve, WeierstrassParameters};
+use crate::operations::field::params::FieldParameters;
+use crate::operations::field::params::NumLimbs;
+use crate::utils::ec::AffinePoint;
+use crate::utils::ec::CurveType;
+use crate::utils::ec::EllipticCurve;
+use crate::utils::ec::EllipticCurveParameters;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Secp256r1 curve parameter
+pub struct Secp256r1Parameters;
+
+pub type Secp256r1 = SwCurve<Secp256r1Parameters>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Secp256r1 base field parameter
+pub struct Secp256r1BaseField;
+
+impl FieldParameters for Secp256r1BaseField {
+    const MODULUS: &'static [u8] = &[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(Self::MODULUS)
+    }
+}
+
+impl NumLimbs for Secp256r1BaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for Secp256r1Parameters {
+    type BaseField = Secp256r1BaseField;
+    const CURVE_TYPE: CurveType = CurveType::Secp256r1;
+}
+
+impl WeierstrassParameters for Secp256r1Parameters {
+    /// `a = p - 3`.
+    const A: GenericArray<u8, U32> = GenericArray::from_array([
+        0xfc, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff,
+    ]);
+
+    const B: GenericArray<u8, U32> = GenericArray::from_array([
+        0x4b, 0x60, 0xd2, 0x27, 0x3e, 0x3c, 0xce, 0x3b, 0xf6, 0xb0, 0x53, 0xcc, 0xb0, 0x06, 0x1d,
+        0x65, 0xbc, 0x86, 0x98, 0x76, 0x55, 0xbd, 0xeb, 0xb3, 0xe7, 0x93, 0x3a, 0xaa, 0xd8, 0x35,
+        0xc6, 0x5a,
+    ]);
+
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str(
+            "48439561293906451759052585252797914202762949526041747995844080717082404635286",
+        )
+        .unwrap();
+        let y = BigUint::from_str(
+            "36134250956749795798585127919587881956611106672985015071877198253568414405109",
+        )
+        .unwrap();
+        (x, y)
+    }
+
+    fn prime_group_order() -> num::BigUint {
+        BigUint::from_slice(&[
+            0xFC632551, 0xF3B9CAC2, 0xA7179E84, 0xBCE6FAAD, 0xFFFFFFFF, 0xFFFFFFFF, 0x00000000,
+            0xFFFFFFFF,
+        ])
+    }
+}
+
+pub fn secp256r1_decompress<E: EllipticCurve>(bytes_be: &[u8], sign: u32) -> AffinePoint<E> {
+    let modulus = Secp256r1BaseField::modulus();
+    let x = BigUint::from_bytes_be(bytes_be);
+    assert!(x < modulus, "x is not a field element");
+
+    let y_2 = (&x * &x * &x + Secp256r1Parameters::a_int() * &x + Secp256r1Parameters::b_int())
+        % &modulus;
+    let y = secp256r1_sqrt(&y_2);
+    assert_eq!((&y * &y) % &modulus, y_2, "x is not on the curve");
+
+    let y = if y.bit(0) == (sign == 1) {
+        y
+    } else {
+        (&modulus - &y) % &modulus
+    };
+    AffinePoint::<E>::new(x, y)
+}
+
+/// Computes a square root of `n` in the base field of secp256r1.
+///
+/// Since the modulus is `3 mod 4`, a square root is given by `n^((p + 1) / 4)`. The result is only
+/// meaningful if `n` is a quadratic residue.
+pub fn secp256r1_sqrt(n: &BigUint) -> BigUint {
+    let modulus = Secp256r1BaseField::modulus();
+    let exponent = (&modulus + 1u32) >> 2;
+    n.modpow(&exponent, &modulus)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::ec::utils::biguint_from_limbs;
+    use crate::utils::ec::weierstrass::SwCurve;
+    use num::bigint::RandBigInt;
+    use rand::thread_rng;
+
+    #[test]
+    fn test_weierstrass_biguint_scalar_mul() {
+        assert_eq!(
+            biguint_from_limbs(Secp256r1BaseField::MODULUS),
+            Secp256r1BaseField::modulus()
+        );
+    }
+
+    #[test]
+    fn test_secp256r1_params() {
+        let modulus = Secp256r1BaseField::modulus();
+        assert_eq!(Secp256r1Parameters::a_int(), &modulus - BigUint::from(3u32));
+
+        // Check that the generator lies on the curve.
+        let (x, y) = Secp256r1Parameters::generator();
+        let lhs = (&y * &y) % &modulus;
+        let rhs = (&x * &x * &x + Secp256r1Parameters::a_int() * &x + Secp256r1Parameters::b_int())
+            % &modulus;
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn test_secp256r1_sqrt() {
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            // Check that sqrt(x^2)^2 == x^2
+            // We use x^2 since not all field elements have a square root
+            let x = rng.gen_biguint(256) % Secp256r1BaseField::modulus();
+            let x_2 = (&x * &x) % Secp256r1BaseField::modulus();
+            let sqrt = secp256r1_sqrt(&x_2);
+
+            let sqrt_2 = (&sqrt * &sqrt) % Secp256r1BaseField::modulus();
+
+            assert_eq!(sqrt_2, x_2);
+        }
+    }
+
+    #[test]
+    fn test_secp256r1_decompress() {
+        // Decompress the generator and its multiples and compare against the affine coordinates.
+        let generator = SwCurve::<Secp256r1Parameters>::generator();
+        let mut point = generator.clone();
+        for _ in 0..10 {
+            let mut compressed = point.x.to_bytes_be();
+            while compressed.len() < 32 {
+                compressed.insert(0, 0);
+            }
+            let is_odd = (&point.y % 2u32) == BigUint::from(1u32);
+            let decompressed = secp256r1_decompress::<Secp256r1>(&compressed, is_odd as u32);
+            assert_eq!(decompressed, point);
+            point = point.sw_double();
+        }
+    }
+}
@@ -1,28 +1,34 @@
 mod buffer;
 mod config;
 pub mod ec;
+#[cfg(feature = "std")]
 mod logger;
 mod options;
 #[cfg(any(test, feature = "programs"))]
 mod programs;
 mod prove;
 mod serde;
+#[cfg(feature = "std")]
 mod tracer;
+mod work_dir;
 
 pub use buffer::*;
 pub use config::*;
+#[cfg(feature = "std")]
 pub use logger::*;
 pub use options::*;
 pub use prove::*;
 pub use serde::*;
+#[cfg(feature = "std")]
 pub use tracer::*;
+pub use work_dir::*;
 
 #[cfg(any(test, feature = "programs"))]
 pub use programs::*;
 
 use crate::{memory::MemoryCols, operations::field::params::Limbs};
 use generic_array::ArrayLength;
-use p3_maybe_rayon::prelude::{ParallelBridge, ParallelIterator};
+use p3_maybe_rayon::prelude::*;
 
 pub const fn indices_arr<const N: usize>() -> [usize; N] {
     let mut indices_arr = [0; N];
@@ -206,9 +212,8 @@ where
     let ceil_div = (len + cpus - 1) / cpus;
     let chunk_size = std::cmp::max(ceil_div, cpus);
 
-    vec.chunks_mut(chunk_size * num_elements_per_event)
+    vec.par_chunks_mut(chunk_size * num_elements_per_event)
         .enumerate()
-        .par_bridge()
         .for_each(|(i, chunk)| {
             chunk
                 .chunks_mut(num_elements_per_event)
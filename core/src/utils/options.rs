@@ -1,5 +1,7 @@
 use std::env;
 
+use serde::{Deserialize, Serialize};
+
 use crate::runtime::{SplitOpts, DEFERRED_SPLIT_THRESHOLD};
 
 // const DEFAULT_SHARD_SIZE: usize = 1 << 22;
@@ -23,7 +25,7 @@ impl Default for SP1ProverOpts {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SP1CoreOpts {
     pub shard_size: usize,
     pub shard_batch_size: usize,
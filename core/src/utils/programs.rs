@@ -66,6 +66,12 @@ pub mod tests {
     pub const SHA_EXTEND_ELF: &[u8] =
         include_bytes!("../../../tests/sha-extend/elf/riscv32im-succinct-zkvm-elf");
 
+    pub const SHA512_COMPRESS_ELF: &[u8] =
+        include_bytes!("../../../tests/sha512-compress/elf/riscv32im-succinct-zkvm-elf");
+
+    pub const SHA512_EXTEND_ELF: &[u8] =
+        include_bytes!("../../../tests/sha512-extend/elf/riscv32im-succinct-zkvm-elf");
+
     pub const SHA2_ELF: &[u8] =
         include_bytes!("../../../tests/sha2/elf/riscv32im-succinct-zkvm-elf");
 
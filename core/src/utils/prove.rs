@@ -1,6 +1,7 @@
 use std::fs::File;
 use std::io::Seek;
 use std::io::{self};
+use std::path::PathBuf;
 use std::sync::mpsc::sync_channel;
 use std::sync::Arc;
 use web_time::Instant;
@@ -17,20 +18,25 @@ use p3_baby_bear::BabyBear;
 use p3_field::PrimeField32;
 
 use crate::air::MachineAir;
+use crate::distributed::{ShardBatch, WorkerError, WorkerPool};
 use crate::io::{SP1PublicValues, SP1Stdin};
 use crate::lookup::InteractionBuilder;
-use crate::runtime::{ExecutionError, NoOpSubproofVerifier, SP1Context};
-use crate::runtime::{ExecutionRecord, ExecutionReport};
+use crate::runtime::{
+    CheckpointError, ExecutionError, NoOpSubproofVerifier, OutputMode, ProgressEvent,
+    ProvingCancelled, SP1Context,
+};
+use crate::runtime::{ExecutionRecord, ExecutionReport, ExecutionState};
 use crate::stark::DebugConstraintBuilder;
 use crate::stark::MachineProof;
 use crate::stark::MachineProver;
 use crate::stark::ProverConstraintFolder;
+use crate::stark::ShardProof;
 use crate::stark::StarkVerifyingKey;
 use crate::stark::Val;
 use crate::stark::VerifierConstraintFolder;
 use crate::stark::{Com, PcsProverData, RiscvAir, StarkProvingKey, UniConfig};
 use crate::stark::{MachineRecord, StarkMachine};
-use crate::utils::SP1CoreOpts;
+use crate::utils::{ProvingWorkDir, SP1CoreOpts};
 use crate::{
     runtime::{Program, Runtime},
     stark::StarkGenericConfig,
@@ -47,6 +53,14 @@ pub enum SP1CoreProverError {
     IoError(io::Error),
     #[error("serialization error: {0}")]
     SerializationError(bincode::Error),
+    #[error("invalid checkpoint: {0}")]
+    CheckpointError(CheckpointError),
+    #[error("working directory {0} was used to prove a different program or input")]
+    WorkDirMismatch(PathBuf),
+    #[error("distributed proving failed: {0}")]
+    WorkerError(WorkerError),
+    #[error("{0}")]
+    Cancelled(ProvingCancelled),
 }
 
 pub fn prove_simple<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
@@ -136,6 +150,51 @@ where
     // Record the start of the process.
     let proving_start = Instant::now();
 
+    // Keep the progress handler and the cancellation of the context, since the runtime takes it.
+    let proving_context = context.proving_context();
+    proving_context
+        .check_cancelled()
+        .map_err(SP1CoreProverError::Cancelled)?;
+
+    // Proving from a checkpoint executes the program from its start on the input of the checkpoint
+    // again, since the shards before it have to be proven as well.
+    let mut context = context;
+    let resume_stdin = context
+        .checkpoint
+        .take()
+        .map(|checkpoint| {
+            checkpoint
+                .validate_opts(&program, opts)
+                .map(|_| checkpoint.stdin())
+        })
+        .transpose()
+        .map_err(SP1CoreProverError::CheckpointError)?;
+    let stdin = resume_stdin.as_ref().unwrap_or(stdin);
+
+    // Open the working directory persisting the progress of proving, if any.
+    let work_dir = context
+        .work_dir
+        .clone()
+        .map(|path| ProvingWorkDir::open(path, &program, stdin, opts))
+        .transpose()?;
+
+    // Connect to the workers proving the shards, if any.
+    let workers = if context.workers.is_empty() {
+        None
+    } else {
+        let secret = context
+            .worker_secret
+            .as_ref()
+            .ok_or(SP1CoreProverError::WorkerError(WorkerError::MissingSecret))?;
+        let workers = WorkerPool::<SC>::connect(&context.workers, secret, &program, opts)
+            .map_err(SP1CoreProverError::WorkerError)?;
+        Some(workers)
+    };
+
+    // Unknown syscalls cannot be proven, so fail execution with `UnsupportedSyscall` instead of
+    // handling them and failing to trace the shards they are in afterwards.
+    context.unsupported_syscall_handler = None;
+
     // Execute the program.
     let mut runtime = Runtime::with_context(program.clone(), opts, context);
     runtime.write_vecs(&stdin.buffer);
@@ -150,13 +209,17 @@ where
     let create_checkpoints_span = tracing::debug_span!("create checkpoints").entered();
     let mut checkpoints = Vec::new();
     let (public_values_stream, public_values) = loop {
+        proving_context
+            .check_cancelled()
+            .map_err(SP1CoreProverError::Cancelled)?;
+
         // Execute the runtime until we reach a checkpoint.
         let (checkpoint, done) = runtime
             .execute_state()
             .map_err(SP1CoreProverError::ExecutionError)?;
 
         // Save the checkpoint to a temp file.
-        let mut checkpoint_file = tempfile::tempfile().map_err(SP1CoreProverError::IoError)?;
+        let mut checkpoint_file = checkpoint_file().map_err(SP1CoreProverError::IoError)?;
         checkpoint
             .save(&mut checkpoint_file)
             .map_err(SP1CoreProverError::IoError)?;
@@ -175,6 +238,9 @@ where
         }
     };
     create_checkpoints_span.exit();
+    proving_context.report_progress(ProgressEvent::ExecutionFinished {
+        cycles: runtime.state.global_clk,
+    });
 
     // Commit to the shards.
     #[cfg(debug_assertions)]
@@ -186,104 +252,171 @@ where
     let mut challenger = prover.config().challenger();
     vk.observe_into(&mut challenger);
 
+    // If the shards were already committed to, replay the observations of the challenger instead.
+    let persisted_commitments = match work_dir {
+        Some(ref work_dir) => work_dir.read_commitments::<SC>()?,
+        None => None,
+    };
+
+    let work_dir = &work_dir;
+    let workers = &workers;
+    let proving_context = &proving_context;
     let scope_span = tracing::Span::current().clone();
     std::thread::scope(move |s| {
         let _span = scope_span.enter();
 
-        // Spawn a thread for commiting to the shards.
-        let span = tracing::Span::current().clone();
-        let (records_tx, records_rx) =
-            sync_channel::<Vec<ExecutionRecord>>(opts.commit_stream_capacity);
-        let challenger_handle = s.spawn(move || {
-            let _span = span.enter();
-            tracing::debug_span!("phase 1 commiter").in_scope(|| {
-                for records in records_rx.iter() {
-                    let commitments = tracing::debug_span!("batch").in_scope(|| {
-                        let span = tracing::Span::current().clone();
-                        records
-                            .par_iter()
+        let (challenger, observations) = if let Some(commitments) = persisted_commitments {
+            tracing::info!("skipping phase 1, the shards were already committed to");
+            for (shard, (commit, public_values)) in commitments.iter().enumerate() {
+                prover.update(&mut challenger, commit.clone(), public_values);
+                proving_context.report_progress(ProgressEvent::ShardCommitted { shard });
+            }
+            (challenger, commitments)
+        } else {
+            // Spawn a thread for commiting to the shards.
+            let span = tracing::Span::current().clone();
+            let (records_tx, records_rx) = sync_channel::<TracedBatch>(opts.commit_stream_capacity);
+            let challenger_handle = s.spawn(move || {
+                let _span = span.enter();
+                let mut observations = Vec::new();
+                tracing::debug_span!("phase 1 commiter").in_scope(|| {
+                    for mut batch in records_rx.iter() {
+                        proving_context
+                            .check_cancelled()
+                            .map_err(SP1CoreProverError::Cancelled)?;
+                        let public_values = batch
+                            .records
+                            .iter()
                             .map(|record| {
-                                let _span = span.enter();
-                                prover.commit(record)
+                                record.public_values::<SC::Val>()[0..prover.machine().num_pv_elts()]
+                                    .to_vec()
                             })
-                            .collect::<Vec<_>>()
-                    });
-                    for (commit, record) in commitments.into_iter().zip(records) {
-                        prover.update(
-                            &mut challenger,
-                            commit,
-                            &record.public_values::<SC::Val>()[0..prover.machine().num_pv_elts()],
-                        );
+                            .collect::<Vec<_>>();
+                        let commitments = tracing::debug_span!("batch").in_scope(|| {
+                            if let Some(workers) = workers {
+                                let indices = 0..batch.records.len();
+                                return workers
+                                    .commit(batch.shard_batch(indices))
+                                    .map_err(SP1CoreProverError::WorkerError);
+                            }
+                            let records = batch.records;
+                            let span = tracing::Span::current().clone();
+                            Ok(records
+                                .par_iter()
+                                .map(|record| {
+                                    let _span = span.enter();
+                                    prover.commit(record)
+                                })
+                                .collect::<Vec<_>>())
+                        })?;
+                        for (commit, public_values) in commitments.into_iter().zip(public_values) {
+                            proving_context.report_progress(ProgressEvent::ShardCommitted {
+                                shard: observations.len(),
+                            });
+                            prover.update(&mut challenger, commit.clone(), &public_values);
+                            observations.push((commit, public_values));
+                        }
                     }
-                }
+                    Ok::<_, SP1CoreProverError>(())
+                })?;
+
+                Ok::<_, SP1CoreProverError>((challenger, observations))
             });
 
-            challenger
-        });
+            tracing::debug_span!("phase 1 record generator").in_scope(|| {
+                for (checkpoint_idx, checkpoint_file) in checkpoints.iter_mut().enumerate() {
+                    // Stop if proving was cancelled, the error is returned below.
+                    if proving_context.check_cancelled().is_err() {
+                        break;
+                    }
 
-        tracing::debug_span!("phase 1 record generator").in_scope(|| {
-            for (checkpoint_idx, checkpoint_file) in checkpoints.iter_mut().enumerate() {
-                // Trace the checkpoint and reconstruct the execution records.
-                let (mut records, _) = tracing::debug_span!("trace checkpoint")
-                    .in_scope(|| trace_checkpoint(program.clone(), checkpoint_file, opts));
-                reset_seek(&mut *checkpoint_file);
+                    // Trace the checkpoint and reconstruct the execution records.
+                    let (mut records, _) = tracing::debug_span!("trace checkpoint")
+                        .in_scope(|| trace_checkpoint(program.clone(), checkpoint_file, opts));
+                    reset_seek(&mut *checkpoint_file);
+                    let nb_traced = records.len();
+
+                    // Update the public values & prover state for the shards which contain "cpu events".
+                    for record in records.iter_mut() {
+                        state.shard += 1;
+                        state.execution_shard = record.public_values.execution_shard;
+                        state.start_pc = record.public_values.start_pc;
+                        state.next_pc = record.public_values.next_pc;
+                        record.public_values = state;
+                    }
 
-                // Update the public values & prover state for the shards which contain "cpu events".
-                for record in records.iter_mut() {
-                    state.shard += 1;
-                    state.execution_shard = record.public_values.execution_shard;
-                    state.start_pc = record.public_values.start_pc;
-                    state.next_pc = record.public_values.next_pc;
-                    record.public_values = state;
-                }
+                    // Generate the dependencies.
+                    tracing::debug_span!("generate dependencies")
+                        .in_scope(|| prover.machine().generate_dependencies(&mut records, &opts));
 
-                // Generate the dependencies.
-                tracing::debug_span!("generate dependencies")
-                    .in_scope(|| prover.machine().generate_dependencies(&mut records, &opts));
+                    // Defer events that are too expensive to include in every shard.
+                    for record in records.iter_mut() {
+                        deferred.append(&mut record.defer());
+                    }
 
-                // Defer events that are too expensive to include in every shard.
-                for record in records.iter_mut() {
-                    deferred.append(&mut record.defer());
-                }
+                    // See if any deferred shards are ready to be commited to.
+                    let is_last_checkpoint = checkpoint_idx == nb_checkpoints - 1;
+                    let mut deferred = deferred.split(is_last_checkpoint, opts.split_opts);
 
-                // See if any deferred shards are ready to be commited to.
-                let is_last_checkpoint = checkpoint_idx == nb_checkpoints - 1;
-                let mut deferred = deferred.split(is_last_checkpoint, opts.split_opts);
+                    // Update the public values & prover state for the shards which do not contain "cpu events"
+                    // before committing to them.
+                    if !is_last_checkpoint {
+                        state.execution_shard += 1;
+                    }
+                    for record in deferred.iter_mut() {
+                        state.shard += 1;
+                        state.previous_init_addr_bits =
+                            record.public_values.previous_init_addr_bits;
+                        state.last_init_addr_bits = record.public_values.last_init_addr_bits;
+                        state.previous_finalize_addr_bits =
+                            record.public_values.previous_finalize_addr_bits;
+                        state.last_finalize_addr_bits =
+                            record.public_values.last_finalize_addr_bits;
+                        state.start_pc = state.next_pc;
+                        record.public_values = state;
+                    }
+                    records.append(&mut deferred);
 
-                // Update the public values & prover state for the shards which do not contain "cpu events"
-                // before committing to them.
-                if !is_last_checkpoint {
-                    state.execution_shard += 1;
-                }
-                for record in deferred.iter_mut() {
-                    state.shard += 1;
-                    state.previous_init_addr_bits = record.public_values.previous_init_addr_bits;
-                    state.last_init_addr_bits = record.public_values.last_init_addr_bits;
-                    state.previous_finalize_addr_bits =
-                        record.public_values.previous_finalize_addr_bits;
-                    state.last_finalize_addr_bits = record.public_values.last_finalize_addr_bits;
-                    state.start_pc = state.next_pc;
-                    record.public_values = state;
-                }
-                records.append(&mut deferred);
+                    #[cfg(debug_assertions)]
+                    {
+                        debug_records.extend(records.clone());
+                    }
 
-                #[cfg(debug_assertions)]
-                {
-                    debug_records.extend(records.clone());
+                    // Stop if the committer failed, its error is returned below.
+                    let batch = TracedBatch::new(workers, checkpoint_file, nb_traced, records);
+                    if records_tx.send(batch).is_err() {
+                        break;
+                    }
                 }
+            });
+            drop(records_tx);
+            let (challenger, observations) = challenger_handle.join().unwrap()?;
+            proving_context
+                .check_cancelled()
+                .map_err(SP1CoreProverError::Cancelled)?;
+
+            // Persist the observations, so that phase 1 can be skipped when restarting.
+            if let Some(work_dir) = work_dir {
+                work_dir.write_commitments::<SC>(&observations)?;
+            }
 
-                records_tx.send(records).unwrap();
+            // Debug the constraints if debug assertions are enabled.
+            #[cfg(debug_assertions)]
+            {
+                let mut challenger = prover.config().challenger();
+                prover.debug_constraints(&pk, debug_records, &mut challenger);
             }
-        });
-        drop(records_tx);
-        let challenger = challenger_handle.join().unwrap();
 
-        // Debug the constraints if debug assertions are enabled.
-        #[cfg(debug_assertions)]
-        {
-            let mut challenger = prover.config().challenger();
-            prover.debug_constraints(&pk, debug_records, &mut challenger);
+            (challenger, observations)
+        };
+
+        // Hand the state of the challenger over to the workers.
+        if let Some(workers) = workers {
+            workers
+                .observe(&observations)
+                .map_err(SP1CoreProverError::WorkerError)?;
         }
+        let nb_shards = observations.len();
 
         // Prove the shards.
         let mut deferred = ExecutionRecord::new(program.clone().into());
@@ -291,36 +424,88 @@ where
         let mut report_aggregate = ExecutionReport::default();
 
         // Spawn a thread for proving the shards.
-        let (records_tx, records_rx) =
-            sync_channel::<Vec<ExecutionRecord>>(opts.prove_stream_capacity);
+        let (records_tx, records_rx) = sync_channel::<TracedBatch>(opts.prove_stream_capacity);
 
         let commit_and_open = tracing::Span::current().clone();
         let shard_proofs = s.spawn(move || {
             let _span = commit_and_open.enter();
             let mut shard_proofs = Vec::new();
             tracing::debug_span!("phase 2 prover").in_scope(|| {
-                for records in records_rx.iter() {
+                for batch in records_rx.iter() {
                     tracing::debug_span!("batch").in_scope(|| {
                         let span = tracing::Span::current().clone();
-                        shard_proofs.par_extend(records.into_par_iter().map(|record| {
-                            let _span = span.enter();
-                            prover
-                                .commit_and_open(&pk, record, &mut challenger.clone())
-                                .unwrap()
-                        }));
-                    });
+                        let offset = shard_proofs.len();
+                        if let Some(workers) = workers {
+                            proving_context
+                                .check_cancelled()
+                                .map_err(SP1CoreProverError::Cancelled)?;
+                            let proofs = prove_remotely(workers, work_dir, offset, batch)?;
+                            for i in 0..proofs.len() {
+                                proving_context.report_progress(ProgressEvent::ShardProven {
+                                    shard: offset + i,
+                                    total: nb_shards,
+                                });
+                            }
+                            shard_proofs.extend(proofs);
+                            return Ok(());
+                        }
+                        let proofs = batch
+                            .records
+                            .into_par_iter()
+                            .enumerate()
+                            .map(|(i, record)| {
+                                let _span = span.enter();
+                                let index = offset + i;
+
+                                // Skip the shards that were already proven.
+                                let persisted = match work_dir {
+                                    Some(work_dir) => work_dir.read_shard_proof(index)?,
+                                    None => None,
+                                };
+                                let proof = match persisted {
+                                    Some(proof) => proof,
+                                    None => {
+                                        proving_context
+                                            .check_cancelled()
+                                            .map_err(SP1CoreProverError::Cancelled)?;
+                                        let proof = prover
+                                            .commit_and_open(&pk, record, &mut challenger.clone())
+                                            .unwrap();
+                                        if let Some(work_dir) = work_dir {
+                                            work_dir.write_shard_proof(index, &proof)?;
+                                        }
+                                        proof
+                                    }
+                                };
+                                proving_context.report_progress(ProgressEvent::ShardProven {
+                                    shard: index,
+                                    total: nb_shards,
+                                });
+                                Ok(proof)
+                            })
+                            .collect::<Result<Vec<_>, SP1CoreProverError>>()?;
+                        shard_proofs.extend(proofs);
+                        Ok::<_, SP1CoreProverError>(())
+                    })?;
                 }
-            });
-            shard_proofs
+                Ok::<_, SP1CoreProverError>(())
+            })?;
+            Ok::<_, SP1CoreProverError>(shard_proofs)
         });
 
         tracing::debug_span!("phase 2 record generator").in_scope(|| {
             for (checkpoint_idx, mut checkpoint_file) in checkpoints.into_iter().enumerate() {
+                // Stop if proving was cancelled, the error is returned below.
+                if proving_context.check_cancelled().is_err() {
+                    break;
+                }
+
                 // Trace the checkpoint and reconstruct the execution records.
                 let (mut records, report) = tracing::debug_span!("trace checkpoint")
                     .in_scope(|| trace_checkpoint(program.clone(), &checkpoint_file, opts));
                 report_aggregate += report;
                 reset_seek(&mut checkpoint_file);
+                let nb_traced = records.len();
 
                 // Update the public values & prover state for the shards which contain "cpu events".
                 for record in records.iter_mut() {
@@ -361,11 +546,18 @@ where
                 }
                 records.append(&mut deferred);
 
-                records_tx.send(records).unwrap();
+                // Stop if the prover failed, its error is returned below.
+                let batch = TracedBatch::new(workers, &mut checkpoint_file, nb_traced, records);
+                if records_tx.send(batch).is_err() {
+                    break;
+                }
             }
         });
         drop(records_tx);
-        let shard_proofs = shard_proofs.join().unwrap();
+        let shard_proofs = shard_proofs.join().unwrap()?;
+        proving_context
+            .check_cancelled()
+            .map_err(SP1CoreProverError::Cancelled)?;
 
         // Log some of the `ExecutionReport` information.
         tracing::info!(
@@ -402,6 +594,96 @@ where
     })
 }
 
+/// Prove the shards of `batch` on `workers`, skipping the ones persisted in `work_dir`.
+fn prove_remotely<SC: StarkGenericConfig>(
+    workers: &WorkerPool<SC>,
+    work_dir: &Option<ProvingWorkDir>,
+    offset: usize,
+    mut batch: TracedBatch,
+) -> Result<Vec<ShardProof<SC>>, SP1CoreProverError>
+where
+    Com<SC>: Send + Sync,
+    OpeningProof<SC>: Send,
+{
+    let mut proofs = (0..batch.records.len())
+        .map(|i| match work_dir {
+            Some(work_dir) => work_dir.read_shard_proof(offset + i),
+            None => Ok(None),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let indices = (0..proofs.len())
+        .filter(|i| proofs[*i].is_none())
+        .collect::<Vec<_>>();
+    let remote_proofs = workers
+        .prove(batch.shard_batch(indices.iter().copied()))
+        .map_err(SP1CoreProverError::WorkerError)?;
+    for (i, proof) in indices.into_iter().zip(remote_proofs) {
+        if let Some(work_dir) = work_dir {
+            work_dir.write_shard_proof(offset + i, &proof)?;
+        }
+        proofs[i] = Some(proof);
+    }
+    Ok(proofs.into_iter().map(Option::unwrap).collect())
+}
+
+/// The records of a batch of shards traced from a checkpoint, followed by the shards of the
+/// events deferred so far.
+struct TracedBatch {
+    /// The state of the checkpoint, to send to the workers instead of the traced records.
+    checkpoint: Option<ExecutionState>,
+
+    /// The number of shards traced from the checkpoint.
+    nb_traced: usize,
+
+    records: Vec<ExecutionRecord>,
+}
+
+impl TracedBatch {
+    /// The batch of `records` traced from `checkpoint_file`, which is only read if the shards are
+    /// proven on `workers`.
+    fn new<W>(
+        workers: &Option<W>,
+        checkpoint_file: &mut File,
+        nb_traced: usize,
+        records: Vec<ExecutionRecord>,
+    ) -> Self {
+        let checkpoint = workers.as_ref().map(|_| {
+            let mut reader = std::io::BufReader::new(&*checkpoint_file);
+            let state =
+                bincode::deserialize_from(&mut reader).expect("failed to deserialize state");
+            reset_seek(checkpoint_file);
+            state
+        });
+        Self {
+            checkpoint,
+            nb_traced,
+            records,
+        }
+    }
+
+    /// The shards at `indices` to send to the workers, the traced ones as their checkpoint and
+    /// the deferred ones moved out of the batch.
+    fn shard_batch(&mut self, indices: impl IntoIterator<Item = usize>) -> ShardBatch {
+        let mut shards = Vec::new();
+        let mut deferred = Vec::new();
+        for i in indices {
+            if i < self.nb_traced {
+                shards.push((i, self.records[i].public_values));
+            } else {
+                deferred.push(std::mem::take(&mut self.records[i]));
+            }
+        }
+        ShardBatch {
+            checkpoint: self
+                .checkpoint
+                .as_ref()
+                .expect("the checkpoint was not read"),
+            shards,
+            deferred,
+        }
+    }
+}
+
 /// Runs a program and returns the public values stream.
 pub fn run_test_io<P: MachineProver<BabyBearPoseidon2, RiscvAir<BabyBear>>>(
     program: Program,
@@ -505,12 +787,39 @@ pub fn trace_checkpoint(
 ) -> (Vec<ExecutionRecord>, ExecutionReport) {
     let mut reader = std::io::BufReader::new(file);
     let state = bincode::deserialize_from(&mut reader).expect("failed to deserialize state");
-    let mut runtime = Runtime::recover(program.clone(), state, opts);
+    trace_state(program, state, opts).unwrap()
+}
+
+/// Execute `program` from `state` for a batch of shards, reconstructing their execution records.
+pub fn trace_state(
+    program: Program,
+    state: ExecutionState,
+    opts: SP1CoreOpts,
+) -> Result<(Vec<ExecutionRecord>, ExecutionReport), ExecutionError> {
+    let mut runtime = Runtime::recover(program, state, opts);
     // We already passed the deferred proof verifier when creating checkpoints, so the proofs were
     // already verified. So here we use a noop verifier to not print any warnings.
     runtime.subproof_verifier = Arc::new(NoOpSubproofVerifier);
-    let (events, _) = runtime.execute_record().unwrap();
-    (events, runtime.report)
+    // The output of the program was already handled when creating checkpoints.
+    runtime.stdout = OutputMode::Discard;
+    runtime.stderr = OutputMode::Discard;
+    let (events, _) = runtime.execute_record()?;
+    Ok((events, runtime.report))
+}
+
+/// Creates an anonymous file holding an execution checkpoint.
+#[cfg(feature = "std")]
+fn checkpoint_file() -> io::Result<File> {
+    tempfile::tempfile()
+}
+
+/// Creates an anonymous file holding an execution checkpoint.
+#[cfg(not(feature = "std"))]
+fn checkpoint_file() -> io::Result<File> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "checkpoint files require the `std` feature",
+    ))
 }
 
 pub fn reset_seek(file: &mut File) {
@@ -0,0 +1,261 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use p3_field::{AbstractField, PrimeField32};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    io::SP1Stdin,
+    runtime::Program,
+    stark::{Com, ShardProof, StarkGenericConfig},
+    utils::{SP1CoreOpts, SP1CoreProverError},
+};
+
+/// The file identifying the proving run a working directory belongs to.
+const MANIFEST_FILE: &str = "manifest";
+
+/// The file holding the observations of the challenger once every shard was committed to.
+const COMMITMENTS_FILE: &str = "commitments.bin";
+
+/// A working directory persisting the progress of proving a program, so that interrupted proving
+/// can restart without redoing the work already done.
+///
+/// Once all the shards are committed to, the commitments and public values observed by the
+/// challenger are persisted, and every shard proof is persisted as soon as it is generated.
+#[derive(Debug, Clone)]
+pub struct ProvingWorkDir {
+    path: PathBuf,
+}
+
+impl ProvingWorkDir {
+    /// Open the working directory at `path` for proving `program` on `stdin` with `opts`,
+    /// creating it if it doesn't exist.
+    ///
+    /// Fails with [SP1CoreProverError::WorkDirMismatch] if the directory was used to prove a
+    /// different program, input or shard configuration.
+    pub fn open(
+        path: impl Into<PathBuf>,
+        program: &Program,
+        stdin: &SP1Stdin,
+        opts: SP1CoreOpts,
+    ) -> Result<Self, SP1CoreProverError> {
+        let path = path.into();
+        fs::create_dir_all(&path).map_err(SP1CoreProverError::IoError)?;
+        let work_dir = Self { path };
+
+        let manifest = manifest(program, stdin, opts);
+        match fs::read_to_string(work_dir.file(MANIFEST_FILE)) {
+            Ok(existing) if existing == manifest => {}
+            Ok(_) => return Err(SP1CoreProverError::WorkDirMismatch(work_dir.path)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                work_dir.write_atomic(MANIFEST_FILE, |writer| {
+                    writer
+                        .write_all(manifest.as_bytes())
+                        .map_err(SP1CoreProverError::IoError)
+                })?;
+            }
+            Err(e) => return Err(SP1CoreProverError::IoError(e)),
+        }
+        Ok(work_dir)
+    }
+
+    /// The path of the working directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read the commitments and public values of every shard, if all the shards were committed
+    /// to.
+    pub fn read_commitments<SC: StarkGenericConfig>(
+        &self,
+    ) -> Result<Option<Vec<(Com<SC>, Vec<SC::Val>)>>, SP1CoreProverError>
+    where
+        SC::Val: PrimeField32,
+    {
+        let commitments: Option<Vec<(Com<SC>, Vec<u32>)>> = self.read(COMMITMENTS_FILE)?;
+        Ok(commitments.map(|commitments| {
+            commitments
+                .into_iter()
+                .map(|(commitment, public_values)| {
+                    let public_values = public_values
+                        .into_iter()
+                        .map(SC::Val::from_canonical_u32)
+                        .collect();
+                    (commitment, public_values)
+                })
+                .collect()
+        }))
+    }
+
+    /// Persist the commitments and public values of every shard.
+    pub fn write_commitments<SC: StarkGenericConfig>(
+        &self,
+        commitments: &[(Com<SC>, Vec<SC::Val>)],
+    ) -> Result<(), SP1CoreProverError>
+    where
+        SC::Val: PrimeField32,
+    {
+        let commitments = commitments
+            .iter()
+            .map(|(commitment, public_values)| {
+                let public_values = public_values
+                    .iter()
+                    .map(|value| value.as_canonical_u32())
+                    .collect::<Vec<_>>();
+                (commitment, public_values)
+            })
+            .collect::<Vec<_>>();
+        self.write(COMMITMENTS_FILE, &commitments)
+    }
+
+    /// Read the proof of the shard at `index`, if it was already generated.
+    pub fn read_shard_proof<SC: StarkGenericConfig>(
+        &self,
+        index: usize,
+    ) -> Result<Option<ShardProof<SC>>, SP1CoreProverError> {
+        self.read(&shard_proof_file(index))
+    }
+
+    /// Persist the proof of the shard at `index`.
+    pub fn write_shard_proof<SC: StarkGenericConfig>(
+        &self,
+        index: usize,
+        proof: &ShardProof<SC>,
+    ) -> Result<(), SP1CoreProverError> {
+        self.write(&shard_proof_file(index), proof)
+    }
+
+    fn file(&self, name: &str) -> PathBuf {
+        self.path.join(name)
+    }
+
+    fn read<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>, SP1CoreProverError> {
+        let file = match File::open(self.file(name)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(SP1CoreProverError::IoError(e)),
+        };
+        bincode::deserialize_from(BufReader::new(file))
+            .map(Some)
+            .map_err(SP1CoreProverError::SerializationError)
+    }
+
+    fn write<T: Serialize>(&self, name: &str, value: &T) -> Result<(), SP1CoreProverError> {
+        self.write_atomic(name, |writer| {
+            bincode::serialize_into(writer, value).map_err(SP1CoreProverError::SerializationError)
+        })
+    }
+
+    /// Write a file with `f` to a temporary path first and move it in place once complete, so
+    /// that an interrupted write never leaves a truncated file behind.
+    fn write_atomic(
+        &self,
+        name: &str,
+        f: impl FnOnce(&mut BufWriter<File>) -> Result<(), SP1CoreProverError>,
+    ) -> Result<(), SP1CoreProverError> {
+        let tmp_path = self.file(&format!("{}.tmp", name));
+        let mut writer =
+            BufWriter::new(File::create(&tmp_path).map_err(SP1CoreProverError::IoError)?);
+        f(&mut writer)?;
+        writer.flush().map_err(SP1CoreProverError::IoError)?;
+        fs::rename(tmp_path, self.file(name)).map_err(SP1CoreProverError::IoError)
+    }
+}
+
+fn shard_proof_file(index: usize) -> String {
+    format!("shard-{}.bin", index)
+}
+
+/// The manifest identifying a proving run: the digest of everything the shard proofs depend on.
+fn manifest(program: &Program, stdin: &SP1Stdin, opts: SP1CoreOpts) -> String {
+    let split_opts = opts.split_opts;
+    let bytes = bincode::serialize(&(
+        program,
+        &stdin.buffer,
+        &stdin.proofs,
+        opts.shard_size,
+        opts.shard_batch_size,
+        split_opts.deferred_shift_threshold,
+        split_opts.keccak_split_threshold,
+        split_opts.sha_extend_split_threshold,
+        split_opts.sha_compress_split_threshold,
+        split_opts.sha512_extend_split_threshold,
+        split_opts.sha512_compress_split_threshold,
+        split_opts.blake2b_compress_split_threshold,
+        split_opts.memory_split_threshold,
+    ))
+    .expect("failed to serialize manifest");
+    blake3::hash(&bytes).to_hex().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::ProvingWorkDir;
+    use crate::io::SP1Stdin;
+    use crate::runtime::tests::{fibonacci_program, simple_program};
+    use crate::runtime::SP1Context;
+    use crate::stark::{DefaultProver, MachineProver, RiscvAir};
+    use crate::utils::{prove_with_context, BabyBearPoseidon2, SP1CoreOpts, SP1CoreProverError};
+
+    #[test]
+    fn test_work_dir_mismatch() {
+        let dir = tempdir().unwrap();
+        let stdin = SP1Stdin::new();
+        let opts = SP1CoreOpts::default();
+        ProvingWorkDir::open(dir.path(), &simple_program(), &stdin, opts).unwrap();
+        ProvingWorkDir::open(dir.path(), &simple_program(), &stdin, opts).unwrap();
+        assert!(matches!(
+            ProvingWorkDir::open(dir.path(), &fibonacci_program(), &stdin, opts),
+            Err(SP1CoreProverError::WorkDirMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_work_dir_resume_prove() {
+        let dir = tempdir().unwrap();
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let prover = DefaultProver::new(machine);
+        let prove = || {
+            let context = SP1Context::builder().work_dir(dir.path()).build();
+            let (proof, _, _) = prove_with_context::<_, _>(
+                &prover,
+                simple_program(),
+                &SP1Stdin::new(),
+                SP1CoreOpts::default(),
+                context,
+            )
+            .unwrap();
+
+            // The chip ordering is a hash map, so compare it separately from the rest of the proof.
+            proof
+                .shard_proofs
+                .into_iter()
+                .map(|mut shard_proof| {
+                    let mut chip_ordering = shard_proof.chip_ordering.drain().collect::<Vec<_>>();
+                    chip_ordering.sort();
+                    (bincode::serialize(&shard_proof).unwrap(), chip_ordering)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let proof = prove();
+
+        // Simulate an interruption before the last shard was proven.
+        let last_shard = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name.starts_with("shard-"))
+            .max()
+            .unwrap();
+        fs::remove_file(dir.path().join(last_shard)).unwrap();
+
+        assert_eq!(prove(), proof);
+    }
+}
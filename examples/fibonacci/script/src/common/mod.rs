@@ -49,7 +49,7 @@ pub fn bootstrap<'a>(
 
     context
         .subproof_verifier
-        .get_or_insert_with(|| Arc::new(client.prover.sp1_prover()));
+        .get_or_insert_with(|| Arc::new(client.prover().sp1_prover()));
 
     let program = Program::from(pk.elf.as_slice());
 
@@ -95,21 +95,26 @@ pub fn operator_phase1_impl(
 
     // Setup the machine.
     let (_, stark_vk) = client
-        .prover
+        .prover()
         .sp1_prover()
         .core_prover
         .setup(runtime.program.as_ref());
 
-    let mut challenger = client.prover.sp1_prover().core_prover.config().challenger();
+    let mut challenger = client
+        .prover()
+        .sp1_prover()
+        .core_prover
+        .config()
+        .challenger();
     stark_vk.observe_into(&mut challenger);
 
     for (commitments, records) in commitments_vec.into_iter().zip(records_vec.into_iter()) {
         for (commitment, record) in commitments.into_iter().zip(records.into_iter()) {
-            client.prover.sp1_prover().core_prover.update(
+            client.prover().sp1_prover().core_prover.update(
                 &mut challenger,
                 commitment,
                 &record.public_values::<BabyBear>()[0..client
-                    .prover
+                    .prover()
                     .sp1_prover()
                     .core_prover
                     .machine()
@@ -153,7 +158,7 @@ pub fn operator_phase2_impl(
         proof: SP1Proof::Core(sp1_core_proof.proof.0),
         stdin: sp1_core_proof.stdin,
         public_values: sp1_core_proof.public_values,
-        sp1_version: client.prover.version().to_string(),
+        sp1_version: client.prover().version().to_string(),
     };
 
     client.verify(&proof, &vk).expect("failed to verify proof");
@@ -45,7 +45,7 @@ pub fn worker_phase2(
     let args_obj = ProveArgs::from_slice(args.as_slice());
     let (client, _, _, _) = common::init_client(args_obj.clone());
     let challenger = ChallengerState::from_bytes(challenger_state.as_slice())
-        .to_challenger(&client.prover.sp1_prover().core_prover.config().perm);
+        .to_challenger(&client.prover().sp1_prover().core_prover.config().perm);
     let records: Vec<RecordType> = bincode::deserialize(records).unwrap();
 
     let shard_proofs = worker_phase2_impl(args_obj, challenger, records).unwrap();
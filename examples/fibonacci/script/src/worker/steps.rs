@@ -55,7 +55,7 @@ pub fn worker_phase1_impl(
 
     // Generate the dependencies.
     client
-        .prover
+        .prover()
         .sp1_prover()
         .core_prover
         .machine()
@@ -89,7 +89,7 @@ pub fn worker_phase1_impl(
     // Committing to the shards.
     let commitments = records
         .iter()
-        .map(|record| client.prover.sp1_prover().core_prover.commit(record))
+        .map(|record| client.prover().sp1_prover().core_prover.commit(record))
         .collect::<Vec<_>>();
 
     Ok((commitments, records))
@@ -106,7 +106,7 @@ pub fn worker_phase2_impl(
     let runtime = common::build_runtime(program, &stdin, core_opts, context);
 
     let (stark_pk, _) = client
-        .prover
+        .prover()
         .sp1_prover()
         .core_prover
         .setup(runtime.program.as_ref());
@@ -114,7 +114,7 @@ pub fn worker_phase2_impl(
     let mut shard_proofs = Vec::new();
     for record in records {
         let shard_proof = client
-            .prover
+            .prover()
             .sp1_prover()
             .core_prover
             .commit_and_open(&stark_pk, record, &mut challenger.clone())
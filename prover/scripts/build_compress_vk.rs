@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use sp1_core::utils::setup_logger;
+use sp1_prover::SP1Prover;
+
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(short, long, default_value = "verifier/vk/compress_vk.bin")]
+    out: PathBuf,
+}
+
+pub fn main() {
+    setup_logger();
+    let args = Args::parse();
+    let prover: SP1Prover = SP1Prover::new();
+    let bytes = bincode::serialize(&prover.compress_vk).unwrap();
+    std::fs::write(args.out, bytes).unwrap();
+}
@@ -29,7 +29,9 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use rayon::prelude::*;
 use sp1_core::air::{PublicValues, Word};
 pub use sp1_core::io::{SP1PublicValues, SP1Stdin};
-use sp1_core::runtime::{ExecutionError, ExecutionReport, Runtime, SP1Context};
+use sp1_core::runtime::{
+    ExecutionCheckpoint, ExecutionError, ExecutionReport, ProgressEvent, Runtime, SP1Context,
+};
 use sp1_core::stark::MachineProver;
 use sp1_core::stark::{Challenge, StarkProvingKey};
 use sp1_core::stark::{Challenger, MachineVerificationError};
@@ -49,6 +51,8 @@ use sp1_recursion_core::{
     runtime::{RecursionProgram, Runtime as RecursionRuntime},
     stark::{config::BabyBearPoseidon2Outer, RecursionAir},
 };
+pub use sp1_recursion_gnark_ffi::groth16_bn254::Groth16Bn254Proof;
+use sp1_recursion_gnark_ffi::groth16_bn254::Groth16Bn254Prover;
 pub use sp1_recursion_gnark_ffi::plonk_bn254::PlonkBn254Proof;
 use sp1_recursion_gnark_ffi::plonk_bn254::PlonkBn254Prover;
 use sp1_recursion_program::hints::Hintable;
@@ -247,6 +251,27 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         ))
     }
 
+    /// Execute an SP1 program until at least `cycles` cycles have been executed, returning a
+    /// checkpoint to resume execution or proving from, or `None` if the program finished first.
+    ///
+    /// The checkpoint can only be proven with the shard size and shard batch size of `opts`.
+    #[instrument(name = "execute_until", level = "info", skip_all)]
+    pub fn execute_until(
+        elf: &[u8],
+        stdin: &SP1Stdin,
+        opts: SP1CoreOpts,
+        context: SP1Context,
+        cycles: u64,
+    ) -> Result<Option<ExecutionCheckpoint>, ExecutionError> {
+        let program = Program::from(elf);
+        let mut runtime = Runtime::with_context(program, opts, context);
+        runtime.write_vecs(&stdin.buffer);
+        for (proof, vkey) in stdin.proofs.iter() {
+            runtime.write_proof(proof.clone(), vkey.clone());
+        }
+        runtime.execute_until(cycles)
+    }
+
     /// Generate shard proofs which split up and prove the valid execution of a RISC-V program with
     /// the core prover. Uses the provided context.
     #[instrument(name = "prove_core", level = "info", skip_all)]
@@ -403,13 +428,28 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
     }
 
     /// Reduce shards proofs to a single shard proof using the recursion prover.
-    #[instrument(name = "compress", level = "info", skip_all)]
     pub fn compress(
         &self,
         vk: &SP1VerifyingKey,
         proof: SP1CoreProof,
         deferred_proofs: Vec<ShardProof<InnerSC>>,
         opts: SP1ProverOpts,
+    ) -> Result<SP1ReduceProof<InnerSC>, SP1RecursionProverError> {
+        self.compress_with_context(vk, proof, deferred_proofs, opts, &SP1Context::default())
+    }
+
+    /// Reduce shards proofs to a single shard proof using the recursion prover, reporting the
+    /// completed layers of the recursion tree to the progress handler of the context.
+    ///
+    /// Fails with [SP1RecursionProverError::Cancelled] if proving is cancelled between layers.
+    #[instrument(name = "compress", level = "info", skip_all)]
+    pub fn compress_with_context(
+        &self,
+        vk: &SP1VerifyingKey,
+        proof: SP1CoreProof,
+        deferred_proofs: Vec<ShardProof<InnerSC>>,
+        opts: SP1ProverOpts,
+        context: &SP1Context,
     ) -> Result<SP1ReduceProof<InnerSC>, SP1RecursionProverError> {
         // Set the batch size for the reduction tree.
         let batch_size = 2;
@@ -436,6 +476,9 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         let mut reduce_proofs = Vec::new();
         let shard_batch_size = opts.recursion_opts.shard_batch_size;
         for inputs in core_inputs.chunks(shard_batch_size) {
+            context
+                .check_cancelled()
+                .map_err(SP1RecursionProverError::Cancelled)?;
             let proofs = inputs
                 .into_par_iter()
                 .map(|input| {
@@ -448,6 +491,9 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
         // Run the deferred proofs programs.
         for inputs in deferred_inputs.chunks(shard_batch_size) {
+            context
+                .check_cancelled()
+                .map_err(SP1RecursionProverError::Cancelled)?;
             let proofs = inputs
                 .into_par_iter()
                 .map(|input| {
@@ -462,10 +508,18 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                 .collect::<Result<Vec<_>, _>>()?;
             reduce_proofs.extend(proofs);
         }
+        context.report_progress(ProgressEvent::RecursionLayerCompleted {
+            layer: 0,
+            proofs: reduce_proofs.len(),
+        });
 
         // Iterate over the recursive proof batches until there is one proof remaining.
         let mut is_complete;
+        let mut layer = 0;
         loop {
+            context
+                .check_cancelled()
+                .map_err(SP1RecursionProverError::Cancelled)?;
             tracing::debug!("Recursive proof layer size: {}", reduce_proofs.len());
             is_complete = reduce_proofs.len() <= batch_size;
 
@@ -500,6 +554,11 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                         .collect::<Vec<_>>()
                 })
                 .collect::<Result<Vec<_>, _>>()?;
+            layer += 1;
+            context.report_progress(ProgressEvent::RecursionLayerCompleted {
+                layer,
+                proofs: reduce_proofs.len(),
+            });
 
             if reduce_proofs.len() == 1 {
                 break;
@@ -694,6 +753,37 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         proof
     }
 
+    /// Wrap the STARK proven over a SNARK-friendly field into a Groth16 proof.
+    #[instrument(name = "wrap_groth16_bn254", level = "info", skip_all)]
+    pub fn wrap_groth16_bn254(
+        &self,
+        proof: SP1ReduceProof<OuterSC>,
+        build_dir: &Path,
+    ) -> Groth16Bn254Proof {
+        let vkey_digest = proof.sp1_vkey_digest_bn254();
+        let commited_values_digest = proof.sp1_commited_values_digest_bn254();
+
+        let mut witness = Witness::default();
+        proof.proof.write(&mut witness);
+        witness.write_commited_values_digest(commited_values_digest);
+        witness.write_vkey_hash(vkey_digest);
+
+        let prover = Groth16Bn254Prover::new();
+        let proof = prover.prove(witness, build_dir.to_path_buf());
+
+        // Verify the proof.
+        prover
+            .verify(
+                &proof,
+                &vkey_digest.as_canonical_biguint(),
+                &commited_values_digest.as_canonical_biguint(),
+                build_dir,
+            )
+            .expect("failed to verify proof");
+
+        proof
+    }
+
     /// Accumulate deferred proofs into a single digest.
     pub fn hash_deferred_proofs(
         prev_digest: [Val<CoreSC>; DIGEST_SIZE],
@@ -730,7 +820,7 @@ pub mod tests {
     use super::*;
 
     use anyhow::Result;
-    use build::try_build_plonk_bn254_artifacts_dev;
+    use build::{try_build_groth16_bn254_artifacts_dev, try_build_plonk_bn254_artifacts_dev};
     use p3_field::PrimeField32;
     use sp1_core::io::SP1Stdin;
 
@@ -746,6 +836,7 @@ pub mod tests {
         Shrink,
         Wrap,
         Plonk,
+        Groth16,
     }
 
     pub fn test_e2e_prover<C: SP1ProverComponents>(
@@ -822,6 +913,24 @@ pub mod tests {
         let vk_digest_bn254 = wrapped_bn254_proof.sp1_vkey_digest_bn254();
         assert_eq!(vk_digest_bn254, vk.hash_bn254());
 
+        if test_kind == Test::Groth16 {
+            tracing::info!("generate groth16 bn254 proof");
+            let artifacts_dir =
+                try_build_groth16_bn254_artifacts_dev(&prover.wrap_vk, &wrapped_bn254_proof.proof);
+            let groth16_bn254_proof =
+                prover.wrap_groth16_bn254(wrapped_bn254_proof, &artifacts_dir);
+            println!("{:?}", groth16_bn254_proof);
+
+            prover.verify_groth16_bn254(
+                &groth16_bn254_proof,
+                &vk,
+                &public_values,
+                &artifacts_dir,
+            )?;
+
+            return Ok(());
+        }
+
         tracing::info!("generate plonk bn254 proof");
         let artifacts_dir =
             try_build_plonk_bn254_artifacts_dev(&prover.wrap_vk, &wrapped_bn254_proof.proof);
@@ -931,6 +1040,17 @@ pub mod tests {
         test_e2e_prover::<DefaultProverComponents>(elf, opts, Test::Plonk)
     }
 
+    /// Tests an end-to-end workflow of proving a program and wrapping it into a Groth16 proof.
+    /// Like [test_e2e], this always re-builds the groth16 bn254 artifacts with a local setup.
+    #[test]
+    #[serial]
+    fn test_e2e_groth16() -> Result<()> {
+        let elf = include_bytes!("../../tests/fibonacci/elf/riscv32im-succinct-zkvm-elf");
+        setup_logger();
+        let opts = SP1ProverOpts::default();
+        test_e2e_prover::<DefaultProverComponents>(elf, opts, Test::Groth16)
+    }
+
     /// Tests an end-to-end workflow of proving a program across the entire proof generation
     /// pipeline in addition to verifying deferred proofs.
     #[test]
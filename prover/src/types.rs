@@ -10,6 +10,7 @@ use p3_field::{AbstractField, PrimeField32, TwoAdicField};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sp1_core::{
     io::{SP1PublicValues, SP1Stdin},
+    runtime::ProvingCancelled,
     stark::{ShardProof, StarkGenericConfig, StarkProvingKey, StarkVerifyingKey},
     utils::DIGEST_SIZE,
 };
@@ -202,4 +203,6 @@ pub enum SP1ReduceProofWrapper {
 pub enum SP1RecursionProverError {
     #[error("Runtime error: {0}")]
     RuntimeError(String),
+    #[error("{0}")]
+    Cancelled(ProvingCancelled),
 }
@@ -10,9 +10,7 @@ use crate::{
 };
 use p3_field::{extension::BinomiallyExtendable, PrimeField32};
 use p3_matrix::dense::RowMajorMatrix;
-use p3_maybe_rayon::prelude::IndexedParallelIterator;
-use p3_maybe_rayon::prelude::ParallelIterator;
-use p3_maybe_rayon::prelude::ParallelSliceMut;
+use p3_maybe_rayon::prelude::*;
 use sp1_core::{
     air::{BinomialExtension, MachineAir},
     utils::{next_power_of_two, par_for_each_row},
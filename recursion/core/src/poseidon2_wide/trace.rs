@@ -3,9 +3,7 @@ use std::borrow::Borrow;
 use p3_air::BaseAir;
 use p3_field::PrimeField32;
 use p3_matrix::dense::RowMajorMatrix;
-use p3_maybe_rayon::prelude::IndexedParallelIterator;
-use p3_maybe_rayon::prelude::ParallelIterator;
-use p3_maybe_rayon::prelude::ParallelSliceMut;
+use p3_maybe_rayon::prelude::*;
 use sp1_core::air::MachineAir;
 use sp1_core::utils::next_power_of_two;
 use sp1_core::utils::par_for_each_row;
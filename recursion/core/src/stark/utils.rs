@@ -1,6 +1,5 @@
 use p3_baby_bear::BabyBear;
 use sp1_core::stark::StarkGenericConfig;
-use sp1_core::utils;
 use sp1_core::utils::BabyBearPoseidon2;
 
 use crate::air::Block;
@@ -30,7 +29,8 @@ pub fn run_test_recursion(
     witness: Option<VecDeque<Vec<Block<BabyBear>>>>,
     test_config: TestConfig,
 ) {
-    utils::setup_logger();
+    #[cfg(feature = "std")]
+    sp1_core::utils::setup_logger();
     let config = BabyBearPoseidon2::default();
 
     let mut runtime = Runtime::<Val, Challenge, _>::new(&program, config.perm.clone());
@@ -2,7 +2,8 @@
 //! native feature is disabled.
 
 use sp1_recursion_gnark_ffi::ffi::{
-    build_plonk_bn254, prove_plonk_bn254, test_plonk_bn254, verify_plonk_bn254,
+    build_groth16_bn254, build_plonk_bn254, prove_groth16_bn254, prove_plonk_bn254,
+    test_groth16_bn254, test_plonk_bn254, verify_groth16_bn254, verify_plonk_bn254,
 };
 
 use clap::{Args, Parser, Subcommand};
@@ -24,6 +25,10 @@ enum Command {
     ProvePlonk(ProveArgs),
     VerifyPlonk(VerifyArgs),
     TestPlonk(TestArgs),
+    BuildGroth16(BuildArgs),
+    ProveGroth16(ProveArgs),
+    VerifyGroth16(VerifyArgs),
+    TestGroth16(TestArgs),
 }
 
 #[derive(Debug, Args)]
@@ -85,6 +90,38 @@ fn run_test(args: TestArgs) {
     test_plonk_bn254(&args.witness_json, &args.constraints_json);
 }
 
+fn run_build_groth16(args: BuildArgs) {
+    build_groth16_bn254(&args.data_dir);
+}
+
+fn run_prove_groth16(args: ProveArgs) {
+    let proof = prove_groth16_bn254(&args.data_dir, &args.witness_path);
+    let mut file = File::create(&args.output_path).unwrap();
+    bincode::serialize_into(&mut file, &proof).unwrap();
+}
+
+fn run_verify_groth16(args: VerifyArgs) {
+    // For proof, we read the string from file since it can be large.
+    let file = File::open(&args.proof_path).unwrap();
+    let proof = read_to_string(file).unwrap();
+    let result = verify_groth16_bn254(
+        &args.data_dir,
+        proof.trim(),
+        &args.vkey_hash,
+        &args.committed_values_digest,
+    );
+    let output = match result {
+        Ok(_) => "OK".to_string(),
+        Err(e) => e,
+    };
+    let mut file = File::create(&args.output_path).unwrap();
+    file.write_all(output.as_bytes()).unwrap();
+}
+
+fn run_test_groth16(args: TestArgs) {
+    test_groth16_bn254(&args.witness_json, &args.constraints_json);
+}
+
 fn main() {
     let cli = Cli::parse();
 
@@ -93,5 +130,9 @@ fn main() {
         Command::ProvePlonk(args) => run_prove(args),
         Command::VerifyPlonk(args) => run_verify(args),
         Command::TestPlonk(args) => run_test(args),
+        Command::BuildGroth16(args) => run_build_groth16(args),
+        Command::ProveGroth16(args) => run_prove_groth16(args),
+        Command::VerifyGroth16(args) => run_verify_groth16(args),
+        Command::TestGroth16(args) => run_test_groth16(args),
     }
 }
@@ -7,3 +7,7 @@ import (
 func TestCircuit(t *testing.T) {
 	TestMain()
 }
+
+func TestGroth16Circuit(t *testing.T) {
+	TestGroth16Main()
+}
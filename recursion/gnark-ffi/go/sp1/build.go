@@ -1,6 +1,7 @@
 package sp1
 
 import (
+	"crypto/sha256"
 	"encoding/json"
 	"fmt"
 	"log"
@@ -9,8 +10,11 @@ import (
 
 	"github.com/consensys/gnark-crypto/ecc"
 	"github.com/consensys/gnark-crypto/kzg"
+	"github.com/consensys/gnark/backend"
+	"github.com/consensys/gnark/backend/groth16"
 	"github.com/consensys/gnark/backend/plonk"
 	"github.com/consensys/gnark/frontend"
+	"github.com/consensys/gnark/frontend/cs/r1cs"
 	"github.com/consensys/gnark/frontend/cs/scs"
 	"github.com/consensys/gnark/test/unsafekzg"
 	"github.com/succinctlabs/sp1-recursion-gnark/sp1/trusted_setup"
@@ -192,3 +196,107 @@ func Build(dataDir string) {
 		panic(err)
 	}
 }
+
+func BuildGroth16(dataDir string) {
+	// Set the enviroment variable for the constraints file.
+	//
+	// TODO: There might be some non-determinism if a single process is running this command
+	// multiple times.
+	os.Setenv("CONSTRAINTS_JSON", dataDir+"/"+constraintsJsonFile)
+
+	// Read the file.
+	witnessInputPath := dataDir + "/witness.json"
+	data, err := os.ReadFile(witnessInputPath)
+	if err != nil {
+		panic(err)
+	}
+
+	// Deserialize the JSON data into a slice of Instruction structs
+	var witnessInput WitnessInput
+	err = json.Unmarshal(data, &witnessInput)
+	if err != nil {
+		panic(err)
+	}
+
+	// Initialize the circuit.
+	circuit := NewCircuit(witnessInput)
+
+	// Compile the circuit.
+	r1cs, err := frontend.Compile(ecc.BN254.ScalarField(), r1cs.NewBuilder, &circuit)
+	if err != nil {
+		panic(err)
+	}
+
+	// Generate the proving and verifying key. Unlike PLONK, the Groth16 setup is specific to the
+	// circuit, so the artifacts of a release come from a ceremony and this local setup is only
+	// suitable for development.
+	pk, vk, err := groth16.Setup(r1cs)
+	if err != nil {
+		panic(err)
+	}
+
+	// Generate proof.
+	assignment := NewCircuit(witnessInput)
+	witness, err := frontend.NewWitness(&assignment, ecc.BN254.ScalarField())
+	if err != nil {
+		panic(err)
+	}
+	proof, err := groth16.Prove(r1cs, pk, witness, backend.WithProverHashToFieldFunction(sha256.New()))
+	if err != nil {
+		panic(err)
+	}
+
+	// Verify proof.
+	publicWitness, err := witness.Public()
+	if err != nil {
+		panic(err)
+	}
+	err = groth16.Verify(proof, vk, publicWitness, backend.WithVerifierHashToFieldFunction(sha256.New()))
+	if err != nil {
+		panic(err)
+	}
+
+	// Create the build directory.
+	os.MkdirAll(dataDir, 0755)
+
+	// Write the solidity verifier.
+	solidityVerifierFile, err := os.Create(dataDir + "/" + groth16VerifierContractPath)
+	if err != nil {
+		panic(err)
+	}
+	vk.ExportSolidity(solidityVerifierFile)
+	defer solidityVerifierFile.Close()
+
+	// Write the R1CS.
+	r1csFile, err := os.Create(dataDir + "/" + groth16CircuitPath)
+	if err != nil {
+		panic(err)
+	}
+	defer r1csFile.Close()
+	_, err = r1cs.WriteTo(r1csFile)
+	if err != nil {
+		panic(err)
+	}
+
+	// Write the verifier key.
+	vkFile, err := os.Create(dataDir + "/" + groth16VkPath)
+	if err != nil {
+		panic(err)
+	}
+	defer vkFile.Close()
+	_, err = vk.WriteTo(vkFile)
+	if err != nil {
+		panic(err)
+	}
+
+	// Write the proving key.
+	pkFile, err := os.Create(dataDir + "/" + groth16PkPath)
+	if err != nil {
+		panic(err)
+	}
+	defer pkFile.Close()
+	_, err = pk.WriteTo(pkFile)
+	if err != nil {
+		panic(err)
+	}
+}
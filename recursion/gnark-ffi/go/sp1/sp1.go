@@ -18,6 +18,10 @@ var verifierContractPath string = "PlonkVerifier.sol"
 var circuitPath string = "circuit.bin"
 var vkPath string = "vk.bin"
 var pkPath string = "pk.bin"
+var groth16VerifierContractPath string = "Groth16Verifier.sol"
+var groth16CircuitPath string = "groth16_circuit.bin"
+var groth16VkPath string = "groth16_vk.bin"
+var groth16PkPath string = "groth16_pk.bin"
 
 type Circuit struct {
 	VkeyHash             frontend.Variable `gnark:",public"`
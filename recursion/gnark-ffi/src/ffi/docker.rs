@@ -1,6 +1,6 @@
 use sp1_core::SP1_CIRCUIT_VERSION;
 
-use crate::PlonkBn254Proof;
+use crate::{Groth16Bn254Proof, PlonkBn254Proof};
 use std::io::Write;
 use std::process::Command;
 
@@ -109,6 +109,79 @@ pub fn test_plonk_bn254(witness_json: &str, constraints_json: &str) {
         .expect("failed to test with docker");
 }
 
+pub fn prove_groth16_bn254(data_dir: &str, witness_path: &str) -> Groth16Bn254Proof {
+    let output_file = tempfile::NamedTempFile::new().unwrap();
+    let mounts = [
+        (data_dir, "/circuit"),
+        (witness_path, "/witness"),
+        (output_file.path().to_str().unwrap(), "/output"),
+    ];
+    assert_docker();
+    call_docker(
+        &["prove-groth16", "/circuit", "/witness", "/output"],
+        &mounts,
+    )
+    .expect("failed to prove with docker");
+    bincode::deserialize_from(&output_file).expect("failed to deserialize result")
+}
+
+pub fn build_groth16_bn254(data_dir: &str) {
+    let circuit_dir = if data_dir.ends_with("dev") {
+        "/circuit_dev"
+    } else {
+        "/circuit"
+    };
+    let mounts = [(data_dir, circuit_dir)];
+    assert_docker();
+    call_docker(&["build-groth16", circuit_dir], &mounts).expect("failed to build with docker");
+}
+
+pub fn verify_groth16_bn254(
+    data_dir: &str,
+    proof: &str,
+    vkey_hash: &str,
+    committed_values_digest: &str,
+) -> Result<(), String> {
+    // Write proof string to a file since it can be large.
+    let mut proof_file = tempfile::NamedTempFile::new().unwrap();
+    proof_file.write_all(proof.as_bytes()).unwrap();
+    let output_file = tempfile::NamedTempFile::new().unwrap();
+    let mounts = [
+        (data_dir, "/circuit"),
+        (proof_file.path().to_str().unwrap(), "/proof"),
+        (output_file.path().to_str().unwrap(), "/output"),
+    ];
+    assert_docker();
+    call_docker(
+        &[
+            "verify-groth16",
+            "/circuit",
+            "/proof",
+            vkey_hash,
+            committed_values_digest,
+            "/output",
+        ],
+        &mounts,
+    )
+    .expect("failed to verify with docker");
+    let result = std::fs::read_to_string(output_file.path()).unwrap();
+    if result == "OK" {
+        Ok(())
+    } else {
+        Err(result)
+    }
+}
+
+pub fn test_groth16_bn254(witness_json: &str, constraints_json: &str) {
+    let mounts = [
+        (constraints_json, "/constraints"),
+        (witness_json, "/witness"),
+    ];
+    assert_docker();
+    call_docker(&["test-groth16", "/constraints", "/witness"], &mounts)
+        .expect("failed to test with docker");
+}
+
 pub fn test_babybear_poseidon2() {
     unimplemented!()
 }
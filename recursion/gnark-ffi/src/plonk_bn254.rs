@@ -62,6 +62,17 @@ impl PlonkBn254Prover {
         );
     }
 
+    /// Returns the source of the `SP1Verifier.sol` contract for the circuit with the given vkey
+    /// hash, which wraps the gnark `PlonkVerifier.sol` of the same artifacts.
+    pub fn sp1_verifier_contract(vkey_hash: [u8; 32]) -> String {
+        include_str!("../assets/SP1Verifier.txt")
+            .replace("{SP1_CIRCUIT_VERSION}", SP1_CIRCUIT_VERSION)
+            .replace(
+                "{VERIFIER_HASH}",
+                format!("0x{}", hex::encode(vkey_hash)).as_str(),
+            )
+    }
+
     /// Builds the PLONK circuit locally.
     pub fn build<C: Config>(constraints: Vec<Constraint>, witness: Witness<C>, build_dir: PathBuf) {
         let serialized = serde_json::to_string(&constraints).unwrap();
@@ -83,12 +94,7 @@ impl PlonkBn254Prover {
         // Write the corresponding asset files to the build dir.
         let sp1_verifier_path = build_dir.join("SP1Verifier.sol");
         let vkey_hash = Self::get_vkey_hash(&build_dir);
-        let sp1_verifier_str = include_str!("../assets/SP1Verifier.txt")
-            .replace("{SP1_CIRCUIT_VERSION}", SP1_CIRCUIT_VERSION)
-            .replace(
-                "{VERIFIER_HASH}",
-                format!("0x{}", hex::encode(vkey_hash)).as_str(),
-            );
+        let sp1_verifier_str = Self::sp1_verifier_contract(vkey_hash);
         let mut sp1_verifier_file = File::create(sp1_verifier_path).unwrap();
         sp1_verifier_file
             .write_all(sp1_verifier_str.as_bytes())
@@ -1,15 +1,45 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
 use sp1_core::{
-    runtime::{ExecutionReport, HookEnv, SP1ContextBuilder},
+    distributed::{WorkerAddress, WorkerSecret},
+    runtime::{
+        CancellationToken, ExecutionCheckpoint, ExecutionReport, HookEnv, OutputMode,
+        ProfileFormat, Profiler, Program, ProgressHandler, SP1ContextBuilder, SyscallContext,
+    },
     utils::{SP1CoreOpts, SP1ProverOpts},
 };
 use sp1_prover::{
     components::DefaultProverComponents, SP1Prover, SP1ProvingKey, SP1PublicValues, SP1Stdin,
 };
 
-use anyhow::{Ok, Result};
+use anyhow::{anyhow, Ok, Result};
 
 use crate::{Prover, SP1ProofKind, SP1ProofWithPublicValues};
 
+/// The output of a program to stdout and stderr.
+///
+/// A stream is only captured if it was configured with [OutputMode::Capture], and is empty
+/// otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuestOutput {
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+}
+
+impl GuestOutput {
+    fn captured(stdout: &OutputMode, stderr: &OutputMode) -> Self {
+        Self {
+            stdout: stdout.captured().unwrap_or_default(),
+            stderr: stderr.captured().unwrap_or_default(),
+        }
+    }
+}
+
 /// Builder to prepare and configure execution of a program on an input.
 /// May be run with [Self::run].
 #[derive(Default)]
@@ -17,6 +47,9 @@ pub struct Execute<'a> {
     context_builder: SP1ContextBuilder<'a>,
     elf: &'a [u8],
     stdin: SP1Stdin,
+    stdout: OutputMode,
+    stderr: OutputMode,
+    core_opts: SP1CoreOpts,
 }
 
 impl<'a> Execute<'a> {
@@ -29,20 +62,62 @@ impl<'a> Execute<'a> {
             elf,
             stdin,
             context_builder: Default::default(),
+            stdout: Default::default(),
+            stderr: Default::default(),
+            core_opts: SP1CoreOpts::default(),
         }
     }
 
+    /// Execute the program on the input until at least `cycles` cycles have been executed,
+    /// consuming the built action `self`.
+    ///
+    /// Returns a checkpoint that execution or proving can be resumed from with
+    /// [Execute::resume] or [Prove::resume], or `None` if the program finished first. Proving
+    /// from the checkpoint requires the shard size and shard batch size set on this action.
+    pub fn run_until(self, cycles: u64) -> Result<Option<ExecutionCheckpoint>> {
+        let Self {
+            elf,
+            stdin,
+            mut context_builder,
+            stdout,
+            stderr,
+            core_opts,
+        } = self;
+        let context = context_builder.stdout(stdout).stderr(stderr).build();
+        let checkpoint = SP1Prover::<DefaultProverComponents>::execute_until(
+            elf, &stdin, core_opts, context, cycles,
+        )?;
+        Ok(checkpoint)
+    }
+
     /// Execute the program on the input, consuming the built action `self`.
     pub fn run(self) -> Result<(SP1PublicValues, ExecutionReport)> {
+        let (public_values, report, _) = self.run_with_output()?;
+        Ok((public_values, report))
+    }
+
+    /// Execute the program on the input, consuming the built action `self`, and return the
+    /// captured output of the program along with the results.
+    pub fn run_with_output(self) -> Result<(SP1PublicValues, ExecutionReport, GuestOutput)> {
         let Self {
             elf,
             stdin,
             mut context_builder,
+            stdout,
+            stderr,
+            ..
         } = self;
-        let context = context_builder.build();
-        Ok(SP1Prover::<DefaultProverComponents>::execute(
-            elf, &stdin, context,
-        )?)
+        let context = context_builder
+            .stdout(stdout.clone())
+            .stderr(stderr.clone())
+            .build();
+        let (public_values, report) =
+            SP1Prover::<DefaultProverComponents>::execute(elf, &stdin, context)?;
+        Ok((
+            public_values,
+            report,
+            GuestOutput::captured(&stdout, &stderr),
+        ))
     }
 
     /// Add a runtime [Hook](super::Hook) into the context.
@@ -75,6 +150,80 @@ impl<'a> Execute<'a> {
         self.context_builder.max_cycles(max_cycles);
         self
     }
+
+    /// Handle syscalls unknown to the runtime with `f` instead of failing with
+    /// [sp1_core::runtime::ExecutionError::UnsupportedSyscall].
+    ///
+    /// The handler is called with the syscall id and the values of registers X10 and X11, and
+    /// returns the value to write to register t0, if any. Programs relying on such syscalls can
+    /// only be executed, not proven.
+    pub fn unsupported_syscall_handler(
+        mut self,
+        f: impl Fn(&mut SyscallContext, u32, u32, u32) -> Option<u32> + Send + Sync + 'a,
+    ) -> Self {
+        self.context_builder.unsupported_syscall_handler(f);
+        self
+    }
+
+    /// Profile the cycles spent in each function of the program, writing the profile to `output`
+    /// in the given format once execution finishes.
+    ///
+    /// Folded profiles can be rendered with `inferno-flamegraph` and pprof profiles can be
+    /// inspected with `go tool pprof`.
+    pub fn profile(mut self, output: impl Into<PathBuf>, format: ProfileFormat) -> Self {
+        self.context_builder
+            .profiler(Profiler::new(self.elf, output, format));
+        self
+    }
+
+    /// Record the call stack of the program, so that the backtrace of a panic is logged along
+    /// with its message if the program halts with a non-zero exit code.
+    pub fn backtrace(mut self) -> Self {
+        self.context_builder.backtrace();
+        self
+    }
+
+    /// Resume execution from a checkpoint taken with [Execute::run_until], checking that it was
+    /// taken from the same program.
+    ///
+    /// The input of the program is restored from the checkpoint, replacing the input this action
+    /// was created with, and the report only covers the cycles executed after the checkpoint.
+    pub fn resume(mut self, checkpoint: ExecutionCheckpoint) -> Result<Self> {
+        checkpoint.validate(&Program::from(self.elf))?;
+        self.stdin = checkpoint.stdin();
+        self.context_builder.checkpoint(Arc::new(checkpoint));
+        Ok(self)
+    }
+
+    /// Set how the output of the program to stdout is handled. Defaults to
+    /// [OutputMode::Passthrough].
+    ///
+    /// Use [OutputMode::capture] to return the output from [Self::run_with_output].
+    pub fn stdout(mut self, mode: OutputMode) -> Self {
+        self.stdout = mode;
+        self
+    }
+
+    /// Set how the output of the program to stderr is handled. Defaults to
+    /// [OutputMode::Passthrough].
+    ///
+    /// Use [OutputMode::capture] to return the output from [Self::run_with_output].
+    pub fn stderr(mut self, mode: OutputMode) -> Self {
+        self.stderr = mode;
+        self
+    }
+
+    /// Set the shard size of the checkpoints taken with [Self::run_until].
+    pub fn shard_size(mut self, value: usize) -> Self {
+        self.core_opts.shard_size = value;
+        self
+    }
+
+    /// Set the shard batch size of the checkpoints taken with [Self::run_until].
+    pub fn shard_batch_size(mut self, value: usize) -> Self {
+        self.core_opts.shard_batch_size = value;
+        self
+    }
 }
 
 /// Builder to prepare and configure proving execution of a program on an input.
@@ -87,6 +236,8 @@ pub struct Prove<'a> {
     stdin: SP1Stdin,
     core_opts: SP1CoreOpts,
     recursion_opts: SP1CoreOpts,
+    stdout: OutputMode,
+    stderr: OutputMode,
 }
 
 impl<'a> Prove<'a> {
@@ -107,11 +258,22 @@ impl<'a> Prove<'a> {
             context_builder: Default::default(),
             core_opts: SP1CoreOpts::default(),
             recursion_opts: SP1CoreOpts::recursion(),
+            stdout: Default::default(),
+            stderr: Default::default(),
         }
     }
 
     /// Prove the execution of the program on the input, consuming the built action `self`.
     pub fn run(self) -> Result<SP1ProofWithPublicValues> {
+        let (proof, _) = self.run_with_output()?;
+        Ok(proof)
+    }
+
+    /// Prove the execution of the program on the input, consuming the built action `self`, and
+    /// return the captured output of the program along with the proof.
+    ///
+    /// The output is only captured by provers executing the program locally.
+    pub fn run_with_output(self) -> Result<(SP1ProofWithPublicValues, GuestOutput)> {
         let Self {
             prover,
             kind,
@@ -120,14 +282,20 @@ impl<'a> Prove<'a> {
             mut context_builder,
             core_opts,
             recursion_opts,
+            stdout,
+            stderr,
         } = self;
         let opts = SP1ProverOpts {
             core_opts,
             recursion_opts,
         };
-        let context = context_builder.build();
+        let context = context_builder
+            .stdout(stdout.clone())
+            .stderr(stderr.clone())
+            .build();
 
-        prover.prove(pk, stdin, opts, context, kind)
+        let proof = prover.prove(pk, stdin, opts, context, kind)?;
+        Ok((proof, GuestOutput::captured(&stdout, &stderr)))
     }
 
     /// Set the proof kind to the core mode. This is the default.
@@ -148,6 +316,28 @@ impl<'a> Prove<'a> {
         self
     }
 
+    /// Set the proof mode to the groth16 bn254 mode.
+    ///
+    /// Groth16 artifacts are not published for releases, so proving locally requires dev mode
+    /// (`SP1_DEV=true`), which builds them from a local setup.
+    pub fn groth16(mut self) -> Self {
+        self.kind = SP1ProofKind::Groth16;
+        self
+    }
+
+    /// Prove the execution of the program on the input of a checkpoint taken with
+    /// [Execute::run_until], checking that it was taken from the same program.
+    ///
+    /// The input of the program is restored from the checkpoint, replacing the input this action
+    /// was created with, and the program is executed from its start again. The checkpoint must
+    /// have been taken with the same shard size and shard batch size as the ones used for proving.
+    pub fn resume(mut self, checkpoint: ExecutionCheckpoint) -> Result<Self> {
+        checkpoint.validate(&Program::from(&self.pk.elf))?;
+        self.stdin = checkpoint.stdin();
+        self.context_builder.checkpoint(Arc::new(checkpoint));
+        Ok(self)
+    }
+
     /// Add a runtime [Hook](super::Hook) into the context.
     ///
     /// Hooks may be invoked from within SP1 by writing to the specified file descriptor `fd`
@@ -171,6 +361,24 @@ impl<'a> Prove<'a> {
         self
     }
 
+    /// Set how the output of the program to stdout is handled. Defaults to
+    /// [OutputMode::Passthrough].
+    ///
+    /// Use [OutputMode::capture] to return the output from [Self::run_with_output].
+    pub fn stdout(mut self, mode: OutputMode) -> Self {
+        self.stdout = mode;
+        self
+    }
+
+    /// Set how the output of the program to stderr is handled. Defaults to
+    /// [OutputMode::Passthrough].
+    ///
+    /// Use [OutputMode::capture] to return the output from [Self::run_with_output].
+    pub fn stderr(mut self, mode: OutputMode) -> Self {
+        self.stderr = mode;
+        self
+    }
+
     /// Set the shard size for proving.
     pub fn shard_size(mut self, value: usize) -> Self {
         self.core_opts.shard_size = value;
@@ -208,4 +416,205 @@ impl<'a> Prove<'a> {
         self.context_builder.max_cycles(cycle_limit);
         self
     }
+
+    /// Persist the finished shard proofs to the directory at `path`, so that proving the same
+    /// program on the same input can be interrupted and restarted without proving the shards
+    /// that were already proven again.
+    ///
+    /// The directory must not be reused to prove a different program or input.
+    pub fn work_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.context_builder.work_dir(path);
+        self
+    }
+
+    /// Commit to and prove the shards on the workers listening at `workers`, started with
+    /// `cargo prove worker` and the same `secret`, instead of locally.
+    ///
+    /// The program is still executed locally, and the recursion proofs are generated locally.
+    pub fn workers(mut self, workers: Vec<WorkerAddress>, secret: WorkerSecret) -> Self {
+        self.context_builder.workers(workers, secret);
+        self
+    }
+
+    /// Report the progress of proving to `handler`, either a closure or the sending half of a
+    /// channel receiving [ProgressEvent](crate::ProgressEvent)s.
+    ///
+    /// Only the local prover reports progress.
+    pub fn on_progress(mut self, handler: impl ProgressHandler + 'a) -> Self {
+        self.context_builder.progress_handler(handler);
+        self
+    }
+
+    /// Stop proving once `token` is cancelled, failing with
+    /// [ProvingCancelled::Cancelled](crate::ProvingCancelled::Cancelled).
+    ///
+    /// The token is checked between shards, between the layers of the recursion tree and before
+    /// wrapping.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.context_builder.cancellation_token(token);
+        self
+    }
+
+    /// Stop proving once `deadline` passed, failing with
+    /// [ProvingCancelled::DeadlineExceeded](crate::ProvingCancelled::DeadlineExceeded).
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.context_builder.deadline(deadline);
+        self
+    }
+
+    /// Stop proving once `timeout` elapsed from now, failing with
+    /// [ProvingCancelled::DeadlineExceeded](crate::ProvingCancelled::DeadlineExceeded).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.context_builder.timeout(timeout);
+        self
+    }
+}
+
+/// Builder to prepare and configure proving the execution of a program on many inputs.
+/// May be run with [Self::run].
+pub struct ProveBatch<'a> {
+    prover: &'a dyn Prover<DefaultProverComponents>,
+    kind: SP1ProofKind,
+    pk: &'a SP1ProvingKey,
+    stdins: Vec<SP1Stdin>,
+    core_opts: SP1CoreOpts,
+    recursion_opts: SP1CoreOpts,
+    max_cycles: Option<u64>,
+    concurrency: usize,
+}
+
+impl<'a> ProveBatch<'a> {
+    /// Prepare to prove the execution of the given program on each of the given inputs.
+    ///
+    /// Prefer using [ProverClient::prove_batch](super::ProverClient::prove_batch).
+    /// See there for more documentation.
+    pub fn new(
+        prover: &'a dyn Prover<DefaultProverComponents>,
+        pk: &'a SP1ProvingKey,
+        stdins: Vec<SP1Stdin>,
+    ) -> Self {
+        Self {
+            prover,
+            kind: Default::default(),
+            pk,
+            stdins,
+            core_opts: SP1CoreOpts::default(),
+            recursion_opts: SP1CoreOpts::recursion(),
+            max_cycles: None,
+            concurrency: 2,
+        }
+    }
+
+    /// Prove the execution of the program on every input, consuming the built action `self`.
+    ///
+    /// Returns the result of proving each input, in the order of the inputs. Proving an input
+    /// failing, or panicking, does not prevent proving the other inputs.
+    pub fn run(self) -> Vec<Result<SP1ProofWithPublicValues>> {
+        let Self {
+            prover,
+            kind,
+            pk,
+            stdins,
+            core_opts,
+            recursion_opts,
+            max_cycles,
+            concurrency,
+        } = self;
+        let opts = SP1ProverOpts {
+            core_opts,
+            recursion_opts,
+        };
+
+        let nb_inputs = stdins.len();
+        let queue = Mutex::new(stdins.into_iter().enumerate());
+        let results = Mutex::new((0..nb_inputs).map(|_| None).collect::<Vec<_>>());
+        std::thread::scope(|s| {
+            for _ in 0..concurrency.clamp(1, nb_inputs.max(1)) {
+                s.spawn(|| loop {
+                    let Some((i, stdin)) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    let mut context_builder = SP1ContextBuilder::new();
+                    if let Some(max_cycles) = max_cycles {
+                        context_builder.max_cycles(max_cycles);
+                    }
+                    let context = context_builder.build();
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                        prover.prove(pk, stdin, opts, context, kind)
+                    }))
+                    .unwrap_or_else(|payload| {
+                        let message = payload
+                            .downcast_ref::<&str>()
+                            .map(|message| message.to_string())
+                            .or_else(|| payload.downcast_ref::<String>().cloned())
+                            .unwrap_or_default();
+                        Err(anyhow!("proving panicked: {}", message))
+                    });
+                    results.lock().unwrap()[i] = Some(result);
+                });
+            }
+        });
+
+        results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(Option::unwrap)
+            .collect()
+    }
+
+    /// Set the proof kind to the core mode. This is the default.
+    pub fn core(mut self) -> Self {
+        self.kind = SP1ProofKind::Core;
+        self
+    }
+
+    /// Set the proof kind to the compressed mode.
+    pub fn compressed(mut self) -> Self {
+        self.kind = SP1ProofKind::Compressed;
+        self
+    }
+
+    /// Set the proof mode to the plonk bn254 mode.
+    pub fn plonk(mut self) -> Self {
+        self.kind = SP1ProofKind::Plonk;
+        self
+    }
+
+    /// Set the proof mode to the groth16 bn254 mode.
+    ///
+    /// Groth16 artifacts are not published for releases, so proving locally requires dev mode
+    /// (`SP1_DEV=true`), which builds them from a local setup.
+    pub fn groth16(mut self) -> Self {
+        self.kind = SP1ProofKind::Groth16;
+        self
+    }
+
+    /// Set the number of inputs proven at the same time. Defaults to 2.
+    ///
+    /// Executing a program is single-threaded while proving its shards uses every core, so
+    /// proving several inputs at the same time keeps the cores busy while one of them executes.
+    /// Each input being proven holds its shards in memory.
+    pub fn concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value;
+        self
+    }
+
+    /// Set the shard size for proving.
+    pub fn shard_size(mut self, value: usize) -> Self {
+        self.core_opts.shard_size = value;
+        self
+    }
+
+    /// Set the shard batch size for proving.
+    pub fn shard_batch_size(mut self, value: usize) -> Self {
+        self.core_opts.shard_batch_size = value;
+        self
+    }
+
+    /// Set the maximum number of cpu cycles to use for executing each input.
+    pub fn cycle_limit(mut self, cycle_limit: u64) -> Self {
+        self.max_cycles = Some(cycle_limit);
+        self
+    }
 }
@@ -1,12 +1,26 @@
-use std::{cmp::min, fs::File, io::Write, path::PathBuf};
+use std::{
+    cmp::min,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use anyhow::{Context, Result};
+use ethers::{abi::Token, utils::id};
 use futures::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
+use num_bigint::BigUint;
 use reqwest::Client;
+use sha2::{Digest, Sha256};
 pub use sp1_prover::build::build_plonk_bn254_artifacts_with_dummy;
+use sp1_prover::build::PlonkBn254Prover;
 
 use crate::install::try_install_plonk_bn254_artifacts;
+use crate::{SP1Proof, SP1ProofWithPublicValues};
+
+/// The signature of the function of `SP1Verifier.sol` that verifies a proof.
+pub const VERIFY_PROOF_SIGNATURE: &str = "verifyProof(bytes32,bytes,bytes)";
 
 /// Exports the solidity verifier for PLONK proofs to the specified output directory.
 ///
@@ -40,6 +54,105 @@ pub fn export_solidity_plonk_bn254_verifier(output_dir: impl Into<PathBuf>) -> R
     Ok(())
 }
 
+/// Generates the solidity verifier for PLONK proofs from locally built artifacts, such as the ones
+/// of [build_plonk_bn254_artifacts_with_dummy], and exports it to the specified output directory.
+///
+/// This writes `SP1Verifier.sol` for the verifying key in `build_dir` next to the gnark
+/// `PlonkVerifier.sol` it inherits from, without accessing the network.
+pub fn export_solidity_plonk_bn254_verifier_from_dir(
+    build_dir: impl AsRef<Path>,
+    output_dir: impl Into<PathBuf>,
+) -> Result<()> {
+    let build_dir = build_dir.as_ref();
+    let output_dir: PathBuf = output_dir.into();
+
+    let vk_path = build_dir.join("vk.bin");
+    let vk = std::fs::read(&vk_path)
+        .with_context(|| format!("failed to read verifying key at {:?}", vk_path))?;
+    let plonk_verifier_path = build_dir.join("PlonkVerifier.sol");
+    if !plonk_verifier_path.exists() {
+        return Err(anyhow::anyhow!(
+            "verifier file not found at {:?}",
+            plonk_verifier_path
+        ));
+    }
+
+    std::fs::create_dir_all(&output_dir).context("Failed to create output directory.")?;
+    std::fs::copy(&plonk_verifier_path, output_dir.join("PlonkVerifier.sol"))
+        .context("Failed to copy verifier file.")?;
+    let output_path = output_dir.join("SP1Verifier.sol");
+    let vkey_hash: [u8; 32] = Sha256::digest(vk).into();
+    std::fs::write(
+        &output_path,
+        PlonkBn254Prover::sp1_verifier_contract(vkey_hash),
+    )
+    .context("Failed to write verifier file.")?;
+    tracing::info!(
+        "exported verifier for {} to {}",
+        build_dir.display(),
+        output_path.display()
+    );
+
+    Ok(())
+}
+
+/// The arguments of `verifyProof` in `SP1Verifier.sol` for a PLONK proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlonkBn254Calldata {
+    /// The hash of the verifying key of the program, `programVKey`.
+    pub program_vkey: [u8; 32],
+    /// The public values committed by the program, `publicValues`.
+    pub public_values: Vec<u8>,
+    /// The first four bytes of the PLONK vkey hash followed by the encoded proof, `proofBytes`.
+    pub proof: Vec<u8>,
+}
+
+impl PlonkBn254Calldata {
+    /// Extracts the arguments of `verifyProof` from a PLONK proof.
+    pub fn new(proof: &SP1ProofWithPublicValues) -> Result<Self> {
+        let SP1Proof::Plonk(plonk_proof) = &proof.proof else {
+            return Err(anyhow::anyhow!(
+                "only Plonk proofs can be encoded for the PLONK verifier"
+            ));
+        };
+
+        let vkey_hash = BigUint::from_str(&plonk_proof.public_inputs[0])?.to_bytes_be();
+        if vkey_hash.len() > 32 {
+            return Err(anyhow::anyhow!("vkey hash does not fit in 32 bytes"));
+        }
+        let mut program_vkey = [0u8; 32];
+        program_vkey[32 - vkey_hash.len()..].copy_from_slice(&vkey_hash);
+
+        let mut proof_bytes = plonk_proof.plonk_vkey_hash[..4].to_vec();
+        proof_bytes.extend(
+            hex::decode(&plonk_proof.encoded_proof).context("invalid encoding of plonk proof")?,
+        );
+
+        Ok(Self {
+            program_vkey,
+            public_values: proof.public_values.to_vec(),
+            proof: proof_bytes,
+        })
+    }
+
+    /// Returns the calldata of the `verifyProof` call, i.e. the function selector followed by the
+    /// ABI encoded arguments.
+    pub fn abi_encode(&self) -> Vec<u8> {
+        let mut calldata = id(VERIFY_PROOF_SIGNATURE).to_vec();
+        calldata.extend(ethers::abi::encode(&[
+            Token::FixedBytes(self.program_vkey.to_vec()),
+            Token::Bytes(self.public_values.clone()),
+            Token::Bytes(self.proof.clone()),
+        ]));
+        calldata
+    }
+}
+
+/// Encodes a PLONK proof into the calldata of `verifyProof` in `SP1Verifier.sol`.
+pub fn encode_plonk_bn254_calldata(proof: &SP1ProofWithPublicValues) -> Result<Vec<u8>> {
+    Ok(PlonkBn254Calldata::new(proof)?.abi_encode())
+}
+
 pub async fn download_file(
     client: &Client,
     url: &str,
@@ -79,9 +192,166 @@ pub async fn download_file(
 
 #[cfg(test)]
 mod tests {
+    use std::process::Command;
+
+    use anyhow::{bail, ensure};
+    use revm::primitives::{Address, ExecutionResult, Output, TransactTo};
+    use revm::{Evm, InMemoryDB};
+
+    use super::*;
+    use crate::{PlonkBn254Proof, SP1PublicValues, SP1Stdin};
+
+    const PLONK_VK: &[u8] = include_bytes!("../../verifier/vk/plonk_vk.bin");
+
+    /// The gnark verifier and the interface `SP1Verifier.sol` imports, for the PLONK circuit of
+    /// [PLONK_VK].
+    const SP1_CONTRACTS_DIR: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../examples/fibonacci/contracts/lib/sp1-contracts/contracts/src"
+    );
+
+    /// A proof of the fibonacci example, with the arguments its `SP1Verifier.sol` was called with.
+    const FIXTURE: &str =
+        include_str!("../../examples/fibonacci/contracts/src/fixtures/fixture.json");
+
+    fn fixture() -> (SP1ProofWithPublicValues, PlonkBn254Calldata) {
+        let fixture: serde_json::Value = serde_json::from_str(FIXTURE).unwrap();
+        let decode = |key: &str| hex::decode(&fixture[key].as_str().unwrap()[2..]).unwrap();
+        let calldata = PlonkBn254Calldata {
+            program_vkey: decode("vkey").try_into().unwrap(),
+            public_values: decode("publicValues"),
+            proof: decode("proof"),
+        };
+
+        let public_values = SP1PublicValues::from(&calldata.public_values);
+        let proof = SP1ProofWithPublicValues {
+            proof: SP1Proof::Plonk(PlonkBn254Proof {
+                public_inputs: [
+                    BigUint::from_bytes_be(&calldata.program_vkey).to_string(),
+                    public_values.hash().to_string(),
+                ],
+                encoded_proof: hex::encode(&calldata.proof[4..]),
+                raw_proof: String::new(),
+                plonk_vkey_hash: Sha256::digest(PLONK_VK).into(),
+            }),
+            stdin: SP1Stdin::new(),
+            public_values,
+            sp1_version: crate::SP1_CIRCUIT_VERSION.to_string(),
+        };
+        (proof, calldata)
+    }
+
+    /// Exports `SP1Verifier.sol` for [PLONK_VK] and compiles it with `solc`, returning the creation
+    /// bytecode and the exported source.
+    fn compile_sp1_verifier() -> Result<(Vec<u8>, String)> {
+        let build_dir = tempfile::tempdir()?;
+        std::fs::write(build_dir.path().join("vk.bin"), PLONK_VK)?;
+        std::fs::copy(
+            Path::new(SP1_CONTRACTS_DIR).join("v1.0.1/PlonkVerifier.sol"),
+            build_dir.path().join("PlonkVerifier.sol"),
+        )?;
+
+        // `SP1Verifier.sol` imports the interface from its parent directory.
+        let contracts_dir = tempfile::tempdir()?;
+        std::fs::copy(
+            Path::new(SP1_CONTRACTS_DIR).join("ISP1Verifier.sol"),
+            contracts_dir.path().join("ISP1Verifier.sol"),
+        )?;
+        let output_dir = contracts_dir.path().join("verifier");
+        export_solidity_plonk_bn254_verifier_from_dir(build_dir.path(), &output_dir)?;
+        ensure!(output_dir.join("PlonkVerifier.sol").exists());
+        let source_path = output_dir.join("SP1Verifier.sol");
+
+        let output = Command::new("solc")
+            .args(["--optimize", "--combined-json", "bin", "--allow-paths"])
+            .arg(contracts_dir.path())
+            .arg(&source_path)
+            .output()
+            .context("failed to run solc")?;
+        ensure!(
+            output.status.success(),
+            "solc failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let compiled: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        let bytecode = compiled["contracts"]
+            .as_object()
+            .and_then(|contracts| {
+                contracts
+                    .iter()
+                    .find(|(name, _)| name.ends_with(":SP1Verifier"))
+            })
+            .and_then(|(_, contract)| contract["bin"].as_str())
+            .context("SP1Verifier not found in the solc output")?;
+        Ok((
+            hex::decode(bytecode)?,
+            std::fs::read_to_string(source_path)?,
+        ))
+    }
+
+    /// Deploys the contract with the given creation bytecode in an in-memory EVM.
+    fn deploy(evm: &mut Evm<'_, (), InMemoryDB>, bytecode: Vec<u8>) -> Result<Address> {
+        let tx = evm.tx_mut();
+        tx.transact_to = TransactTo::Create;
+        tx.data = bytecode.into();
+        match evm.transact_commit()? {
+            ExecutionResult::Success {
+                output: Output::Create(_, Some(address)),
+                ..
+            } => Ok(address),
+            result => bail!("failed to deploy contract: {:?}", result),
+        }
+    }
+
+    /// Calls `verifyProof` of the deployed `SP1Verifier.sol` with the calldata, returning the name
+    /// of the custom error it reverts with.
+    fn run_verify_proof(
+        evm: &mut Evm<'_, (), InMemoryDB>,
+        verifier: Address,
+        calldata: Vec<u8>,
+    ) -> Result<()> {
+        let tx = evm.tx_mut();
+        tx.transact_to = TransactTo::Call(verifier);
+        tx.data = calldata.into();
+        match evm.transact_commit()? {
+            ExecutionResult::Success { .. } => Ok(()),
+            ExecutionResult::Revert { output, .. } => {
+                let error = ["InvalidProof()", "WrongVerifierSelector(bytes4,bytes4)"]
+                    .into_iter()
+                    .find(|error| output.starts_with(&id(error)))
+                    .context("unknown revert reason")?;
+                bail!("{}", &error[..error.find('(').unwrap()])
+            }
+            result => bail!("verifyProof halted: {:?}", result),
+        }
+    }
+
     #[test]
     fn test_verifier_export() {
         crate::artifacts::export_solidity_plonk_bn254_verifier(tempfile::tempdir().unwrap().path())
             .expect("failed to export verifier");
     }
+
+    #[test]
+    fn test_plonk_bn254_calldata() {
+        let (bytecode, contract) = compile_sp1_verifier().unwrap();
+        assert!(contract.contains(&hex::encode(Sha256::digest(PLONK_VK))));
+        let mut evm = Evm::builder().with_db(InMemoryDB::default()).build();
+        let verifier = deploy(&mut evm, bytecode).unwrap();
+
+        let (proof, expected) = fixture();
+        assert_eq!(PlonkBn254Calldata::new(&proof).unwrap(), expected);
+        let calldata = encode_plonk_bn254_calldata(&proof).unwrap();
+        run_verify_proof(&mut evm, verifier, calldata).unwrap();
+
+        let mut tampered = expected.clone();
+        tampered.public_values[31] ^= 1;
+        let err = run_verify_proof(&mut evm, verifier, tampered.abi_encode()).unwrap_err();
+        assert_eq!(err.to_string(), "InvalidProof");
+
+        let mut tampered = expected;
+        tampered.proof[0] ^= 1;
+        let err = run_verify_proof(&mut evm, verifier, tampered.abi_encode()).unwrap_err();
+        assert_eq!(err.to_string(), "WrongVerifierSelector");
+    }
 }
@@ -1,5 +1,6 @@
 use std::{cmp::min, io::Write, path::PathBuf, process::Command};
 
+use anyhow::{bail, Result};
 use futures::{Future, StreamExt};
 use indicatif::{ProgressBar, ProgressStyle};
 use reqwest::Client;
@@ -45,14 +46,33 @@ pub fn try_install_plonk_bn254_artifacts() -> PathBuf {
 /// This function will download the latest plonk bn254 artifacts from the S3 bucket and extract them to
 /// the directory specified by [plonk_bn254_artifacts_dir()].
 pub fn install_plonk_bn254_artifacts(build_dir: PathBuf) {
-    // Create the build directory.
-    std::fs::create_dir_all(&build_dir).expect("failed to create build directory");
-
-    // Download the artifacts.
     let download_url = format!(
         "{}/{}.tar.gz",
         PLONK_BN254_ARTIFACTS_URL_BASE, SP1_CIRCUIT_VERSION
     );
+    install_circuit_artifacts(build_dir, &download_url);
+}
+
+/// Checks that Groth16 artifacts can be obtained for the current circuit.
+///
+/// Unlike the PLONK artifacts, no Groth16 artifacts are published for releases, so Groth16 proofs
+/// are only supported with artifacts built from a local setup in dev mode (`SP1_DEV=true`).
+pub fn ensure_groth16_bn254_artifacts_available() -> Result<()> {
+    if !sp1_prover::build::sp1_dev_mode() {
+        bail!(
+            "groth16 bn254 artifacts are not published for version {}. set SP1_DEV=true to build them locally",
+            SP1_CIRCUIT_VERSION
+        );
+    }
+    Ok(())
+}
+
+/// Downloads the circuit artifacts tarball at `download_url` and extracts it to `build_dir`.
+fn install_circuit_artifacts(build_dir: PathBuf, download_url: &str) {
+    // Create the build directory.
+    std::fs::create_dir_all(&build_dir).expect("failed to create build directory");
+
+    // Download the artifacts.
     let mut artifacts_tar_gz_file =
         tempfile::NamedTempFile::new().expect("failed to create tempfile");
     let client = Client::builder()
@@ -60,7 +80,7 @@ pub fn install_plonk_bn254_artifacts(build_dir: PathBuf) {
         .expect("failed to create reqwest client");
     block_on(download_file(
         &client,
-        &download_url,
+        download_url,
         &mut artifacts_tar_gz_file,
     ))
     .expect("failed to download file");
@@ -15,33 +15,45 @@ pub mod install;
 #[cfg(feature = "network")]
 pub mod network;
 #[cfg(feature = "network")]
-pub use crate::network::prover::NetworkProver;
+pub use crate::network::{
+    client::{NetworkError, ProofRequest, RetryPolicy},
+    prover::NetworkProver,
+};
 
 pub mod proof;
 pub mod provers;
+#[cfg(feature = "verifier")]
+pub use sp1_verifier as verifier;
 pub mod utils {
     pub use sp1_core::utils::setup_logger;
 }
 
+use anyhow::Result;
 use cfg_if::cfg_if;
 pub use proof::*;
 pub use provers::SP1VerificationError;
 use sp1_prover::components::DefaultProverComponents;
-use std::env;
+use std::{env, sync::Arc};
 
 pub use provers::{LocalProver, MockProver, Prover};
 
-pub use sp1_core::runtime::{ExecutionReport, Hook, HookEnv, SP1Context, SP1ContextBuilder};
-use sp1_core::SP1_CIRCUIT_VERSION;
+pub use action::GuestOutput;
+pub use sp1_core::distributed::{WorkerAddress, WorkerSecret};
+pub use sp1_core::runtime::{
+    CancellationToken, ExecutionCheckpoint, ExecutionReport, Hook, HookEnv, OutputBuffer,
+    OutputMode, ProfileFormat, ProgressEvent, ProgressHandler, ProvingCancelled, SP1Context,
+    SP1ContextBuilder,
+};
+use sp1_core::{utils::SP1ProverOpts, SP1_CIRCUIT_VERSION};
 pub use sp1_prover::{
-    CoreSC, HashableKey, InnerSC, OuterSC, PlonkBn254Proof, SP1Prover, SP1ProvingKey,
-    SP1PublicValues, SP1Stdin, SP1VerifyingKey,
+    CoreSC, Groth16Bn254Proof, HashableKey, InnerSC, OuterSC, PlonkBn254Proof, SP1Prover,
+    SP1ProvingKey, SP1PublicValues, SP1Stdin, SP1VerifyingKey,
 };
 
 /// A client for interacting with SP1.
 pub struct ProverClient {
     /// The underlying prover implementation.
-    pub prover: Box<dyn Prover<DefaultProverComponents>>,
+    prover: Arc<dyn Prover<DefaultProverComponents>>,
 }
 
 impl ProverClient {
@@ -67,16 +79,16 @@ impl ProverClient {
             .as_str()
         {
             "mock" => Self {
-                prover: Box::new(MockProver::new()),
+                prover: Arc::new(MockProver::new()),
             },
             "local" => Self {
-                prover: Box::new(LocalProver::new()),
+                prover: Arc::new(LocalProver::new()),
             },
             "network" => {
                 cfg_if! {
                     if #[cfg(feature = "network")] {
                         Self {
-                            prover: Box::new(NetworkProver::new()),
+                            prover: Arc::new(NetworkProver::new()),
                         }
                     } else {
                         panic!("network feature is not enabled")
@@ -103,7 +115,7 @@ impl ProverClient {
     /// ```
     pub fn mock() -> Self {
         Self {
-            prover: Box::new(MockProver::new()),
+            prover: Arc::new(MockProver::new()),
         }
     }
 
@@ -121,7 +133,7 @@ impl ProverClient {
     /// ```
     pub fn local() -> Self {
         Self {
-            prover: Box::new(LocalProver::new()),
+            prover: Arc::new(LocalProver::new()),
         }
     }
 
@@ -141,7 +153,7 @@ impl ProverClient {
         cfg_if! {
             if #[cfg(feature = "network")] {
                 Self {
-                    prover: Box::new(NetworkProver::new()),
+                    prover: Arc::new(NetworkProver::new()),
                 }
             } else {
                 panic!("network feature is not enabled")
@@ -177,6 +189,33 @@ impl ProverClient {
         action::Execute::new(elf, stdin)
     }
 
+    /// Execute the given program on the given input (without generating a proof) on the blocking
+    /// thread pool of the tokio runtime, returning the public values and execution report of the
+    /// program.
+    ///
+    /// Must be called from within a tokio runtime.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProverClient, SP1Stdin};
+    ///
+    /// # async fn run() {
+    /// let elf = include_bytes!("../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let mut stdin = SP1Stdin::new();
+    /// stdin.write(&10usize);
+    /// let (public_values, report) = client.execute_async(elf, stdin).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn execute_async(
+        &self,
+        elf: &[u8],
+        stdin: SP1Stdin,
+    ) -> Result<(SP1PublicValues, ExecutionReport)> {
+        let elf = elf.to_vec();
+        tokio::task::spawn_blocking(move || action::Execute::new(&elf, stdin).run()).await?
+    }
+
     /// Prepare to prove the execution of the given program with the given input in the default mode.
     /// The returned [action::Prove] may be configured via its methods before running.
     /// For example, calling [action::Prove::compress] sets the mode to compressed mode.
@@ -210,6 +249,80 @@ impl ProverClient {
         action::Prove::new(self.prover.as_ref(), pk, stdin)
     }
 
+    /// Prepare to prove the execution of the given program on each of the given inputs.
+    /// The returned [action::ProveBatch] may be configured via its methods before running.
+    ///
+    /// To prove, call [action::ProveBatch::run], which returns the result of proving each input.
+    /// The proving key, and the recursion programs and keys of the prover, are shared by every
+    /// input, and several inputs are proven at the same time to keep every core busy.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProverClient, SP1Stdin};
+    ///
+    /// let elf = include_bytes!("../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (pk, vk) = client.setup(elf);
+    /// let stdins = (0..10usize)
+    ///     .map(|n| {
+    ///         let mut stdin = SP1Stdin::new();
+    ///         stdin.write(&n);
+    ///         stdin
+    ///     })
+    ///     .collect();
+    /// for result in client.prove_batch(&pk, stdins).compressed().run() {
+    ///     client.verify(&result.unwrap(), &vk).unwrap();
+    /// }
+    /// ```
+    pub fn prove_batch<'a>(
+        &'a self,
+        pk: &'a SP1ProvingKey,
+        stdins: Vec<SP1Stdin>,
+    ) -> action::ProveBatch<'a> {
+        action::ProveBatch::new(self.prover.as_ref(), pk, stdins)
+    }
+
+    /// Prove the execution of the given program with the given input in the given mode, without
+    /// blocking the current task, so that several proofs can be awaited concurrently.
+    ///
+    /// Local proving runs on the blocking thread pool of the tokio runtime, and network proving
+    /// is awaited natively. Must be called from within a tokio runtime. To configure the options
+    /// and the context of proving, use [Prover::prove_async] on [Self::prover] instead.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProverClient, SP1ProofKind, SP1Stdin};
+    ///
+    /// # async fn run() {
+    /// let elf = include_bytes!("../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (pk, vk) = client.setup(elf);
+    /// let mut stdin = SP1Stdin::new();
+    /// stdin.write(&10usize);
+    /// let proof = client
+    ///     .prove_async(&pk, stdin, SP1ProofKind::Compressed)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn prove_async(
+        &self,
+        pk: &SP1ProvingKey,
+        stdin: SP1Stdin,
+        kind: SP1ProofKind,
+    ) -> Result<SP1ProofWithPublicValues> {
+        self.prover
+            .clone()
+            .prove_async(
+                pk.clone(),
+                stdin,
+                SP1ProverOpts::default(),
+                SP1Context::default(),
+                kind,
+            )
+            .await
+    }
+
     /// Verifies that the given proof is valid and matches the given verification key produced by
     /// [Self::setup].
     ///
@@ -233,6 +346,13 @@ impl ProverClient {
         self.prover.verify(proof, vk)
     }
 
+    /// The underlying prover implementation.
+    ///
+    /// It is shared, so that it can be moved into [Prover::prove_async] with [Arc::clone].
+    pub fn prover(&self) -> &Arc<dyn Prover<DefaultProverComponents>> {
+        &self.prover
+    }
+
     /// Gets the current version of the SP1 zkVM.
     ///
     /// Note: This is not the same as the version of the SP1 SDK.
@@ -274,7 +394,7 @@ mod tests {
 
     use sp1_core::runtime::{hook_ecrecover, FD_ECRECOVER_HOOK};
 
-    use crate::{utils, ProverClient, SP1Stdin};
+    use crate::{utils, OutputMode, ProverClient, SP1ProofKind, SP1Stdin};
 
     #[test]
     fn test_execute() {
@@ -287,6 +407,64 @@ mod tests {
         client.execute(elf, stdin).run().unwrap();
     }
 
+    #[tokio::test]
+    async fn test_execute_async() {
+        utils::setup_logger();
+        let client = ProverClient::local();
+        let elf =
+            include_bytes!("../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        let (public_values, _) = client.execute(elf, stdin.clone()).run().unwrap();
+        let (async_public_values, _) = client.execute_async(elf, stdin).await.unwrap();
+        assert_eq!(async_public_values.as_slice(), public_values.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_prove_async_concurrently() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let elf =
+            include_bytes!("../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let (pk, vk) = client.setup(elf);
+        let proofs = futures::future::join_all((0..2usize).map(|n| {
+            let mut stdin = SP1Stdin::new();
+            stdin.write(&n);
+            client.prove_async(&pk, stdin, SP1ProofKind::Core)
+        }))
+        .await;
+        for proof in proofs {
+            client.verify(&proof.unwrap(), &vk).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_prove_batch() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let elf =
+            include_bytes!("../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let (pk, vk) = client.setup(elf);
+        // The program panics on inputs larger than 186.
+        let stdins = [5u32, 1000, 10]
+            .into_iter()
+            .map(|n| {
+                let mut stdin = SP1Stdin::new();
+                stdin.write(&n);
+                stdin
+            })
+            .collect::<Vec<_>>();
+        let results = client.prove_batch(&pk, stdins.clone()).run();
+        assert_eq!(results.len(), 3);
+        assert!(results[1].is_err());
+        for i in [0, 2] {
+            let proof = results[i].as_ref().unwrap();
+            client.verify(proof, &vk).unwrap();
+            let (public_values, _) = client.execute(elf, stdins[i].clone()).run().unwrap();
+            assert_eq!(proof.public_values.as_slice(), public_values.as_slice());
+        }
+    }
+
     #[test]
     fn test_execute_new() {
         // Wrap the hook and check that it was called.
@@ -326,6 +504,21 @@ mod tests {
         assert_ne!(call_ct.into_inner(), 0);
     }
 
+    #[test]
+    fn test_execute_capture_output() {
+        utils::setup_logger();
+        let client = ProverClient::local();
+        let elf = include_bytes!("../../tests/bls12381-add/elf/riscv32im-succinct-zkvm-elf");
+        let (_, _, output) = client
+            .execute(elf, SP1Stdin::new())
+            .stdout(OutputMode::capture())
+            .stderr(OutputMode::Discard)
+            .run_with_output()
+            .unwrap();
+        assert_eq!(output.stdout, b"done\n");
+        assert!(output.stderr.is_empty());
+    }
+
     #[test]
     #[should_panic]
     fn test_execute_panic() {
@@ -373,4 +566,17 @@ mod tests {
         let proof = client.prove(&pk, stdin).plonk().run().unwrap();
         client.verify(&proof, &vk).unwrap();
     }
+
+    #[test]
+    fn test_e2e_prove_groth16_mock() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let elf =
+            include_bytes!("../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let (pk, vk) = client.setup(elf);
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        let proof = client.prove(&pk, stdin).groth16().run().unwrap();
+        client.verify(&proof, &vk).unwrap();
+    }
 }
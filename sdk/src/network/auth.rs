@@ -5,7 +5,7 @@ use alloy_sol_types::{sol, Eip712Domain, SolStruct};
 use anyhow::Result;
 use ethers::{
     signers::{LocalWallet, Signer},
-    types::H256,
+    types::{Signature, H256},
 };
 
 use crate::proto::network::UnclaimReason;
@@ -80,6 +80,14 @@ impl NetworkAuth {
         Ok(signature.to_vec())
     }
 
+    // Generic function to recover the address that signed a message based on the SolStruct.
+    fn recover_signer<T: SolStruct>(signature: &[u8], type_struct: T) -> Result<[u8; 20]> {
+        let domain_separator = Self::get_domain_separator();
+        let message_hash = type_struct.eip712_signing_hash(&domain_separator);
+        let signature = Signature::try_from(signature)?;
+        Ok(signature.recover(H256(message_hash.0))?.0)
+    }
+
     /// Signs a message to to request ot create a proof.
     pub async fn sign_create_proof_message(
         &self,
@@ -141,4 +149,34 @@ impl NetworkAuth {
         };
         self.sign_message(type_struct).await
     }
+
+    /// Recovers the address that signed a message to request to create a proof.
+    pub fn recover_create_proof_signer(
+        signature: &[u8],
+        nonce: u64,
+        deadline: u64,
+        mode: i32,
+        version: &str,
+    ) -> Result<[u8; 20]> {
+        let type_struct = CreateProof {
+            nonce,
+            deadline,
+            mode: mode as u32,
+            version: version.to_string(),
+        };
+        Self::recover_signer(signature, type_struct)
+    }
+
+    /// Recovers the address that signed a message to mark a proof as ready for proof generation.
+    pub fn recover_submit_proof_signer(
+        signature: &[u8],
+        nonce: u64,
+        proof_id: &str,
+    ) -> Result<[u8; 20]> {
+        let type_struct = SubmitProof {
+            nonce,
+            proof_id: proof_id.to_string(),
+        };
+        Self::recover_signer(signature, type_struct)
+    }
 }
@@ -1,17 +1,19 @@
-use std::{env, time::Duration};
+use std::{env, error::Error as StdError, time::Duration};
 
 use crate::{
     network::auth::NetworkAuth,
     proto::network::{UnclaimProofRequest, UnclaimReason},
 };
 use anyhow::{Context, Ok, Result};
-use futures::{future::join_all, Future};
+use futures::{future::try_join, Future};
 use reqwest::{Client as HttpClient, Url};
 use reqwest_middleware::ClientWithMiddleware as HttpClientWithMiddleware;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sp1_prover::SP1Stdin;
 use std::result::Result::Ok as StdOk;
 use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+use tokio::time::sleep;
 use twirp::{Client as TwirpClient, ClientError};
 
 use crate::proto::network::{
@@ -24,13 +26,124 @@ use crate::proto::network::{
 /// The default RPC endpoint for the Succinct prover network.
 pub const DEFAULT_PROVER_NETWORK_RPC: &str = "https://rpc.succinct.xyz/";
 
-/// The timeout for a proof request to be fulfilled.
-const TIMEOUT: Duration = Duration::from_secs(60 * 60);
+/// The default timeout for a proof request to be fulfilled.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Error, Debug)]
+pub enum NetworkError {
+    #[error("failed to reach the prover network: {0}")]
+    Transport(#[source] Box<dyn StdError + Send + Sync>),
+    #[error("the prover network rejected the request: {code}: {msg}")]
+    Rejected { code: String, msg: String },
+    #[error("proof request {proof_id} cannot be fulfilled: {description}")]
+    Unfulfillable {
+        proof_id: String,
+        description: String,
+    },
+    #[error("proof request {proof_id} was unclaimed: {description}")]
+    Unclaimed {
+        proof_id: String,
+        description: String,
+    },
+    #[error("timed out waiting for proof request {0} to be fulfilled")]
+    Timeout(String),
+}
+
+/// A proof request created on the prover network.
+///
+/// The request can be serialized to resume waiting for its proof after restarting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofRequest {
+    pub proof_id: String,
+    /// The time after which the request is not fulfilled anymore, in seconds since the epoch.
+    pub deadline: u64,
+}
+
+impl NetworkError {
+    /// Whether the error is a transport failure, which may succeed when retried.
+    pub fn is_transport(&self) -> bool {
+        matches!(self, Self::Transport(_))
+    }
+}
+
+impl From<ClientError> for NetworkError {
+    fn from(error: ClientError) -> Self {
+        match error {
+            // Errors of the server itself, rather than of the request, are transient.
+            ClientError::TwirpError(err) if !err.code.http_status_code().is_server_error() => {
+                Self::Rejected {
+                    code: format!("{:?}", err.code),
+                    msg: err.msg,
+                }
+            }
+            err => Self::Transport(Box::new(err)),
+        }
+    }
+}
+
+impl From<reqwest::Error> for NetworkError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Transport(Box::new(error))
+    }
+}
+
+impl From<reqwest_middleware::Error> for NetworkError {
+    fn from(error: reqwest_middleware::Error) -> Self {
+        match error {
+            reqwest_middleware::Error::Reqwest(err) => err.into(),
+            reqwest_middleware::Error::Middleware(err) => Self::Transport(err.into()),
+        }
+    }
+}
+
+/// How requests to the prover network failing with a transport error are retried.
+///
+/// The delay between attempts starts at `initial_backoff` and doubles after each attempt, up to
+/// `max_backoff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry.
+    pub const fn none() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// The delay before the retry following the given number of failed attempts.
+    fn backoff(&self, attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
 
 pub struct NetworkClient {
     pub rpc: TwirpClient,
     pub http: HttpClientWithMiddleware,
     pub auth: NetworkAuth,
+    /// How failed requests are retried.
+    pub retry_policy: RetryPolicy,
+    /// How long the prover network has to fulfill the created proof requests.
+    pub timeout: Duration,
 }
 
 impl NetworkClient {
@@ -41,6 +154,12 @@ impl NetworkClient {
 
     /// Create a new NetworkClient with the given private key for authentication.
     pub fn new(private_key: &str) -> Self {
+        Self::new_with_rpc_url(private_key, &Self::rpc_url())
+    }
+
+    /// Create a new NetworkClient with the given private key for authentication, sending requests
+    /// to the given RPC endpoint.
+    pub fn new_with_rpc_url(private_key: &str, rpc_url: &str) -> Self {
         let auth = NetworkAuth::new(private_key);
 
         let twirp_http_client = HttpClient::builder()
@@ -49,9 +168,8 @@ impl NetworkClient {
             .build()
             .unwrap();
 
-        let rpc_url = Self::rpc_url();
         let rpc =
-            TwirpClient::new(Url::parse(&rpc_url).unwrap(), twirp_http_client, vec![]).unwrap();
+            TwirpClient::new(Url::parse(rpc_url).unwrap(), twirp_http_client, vec![]).unwrap();
 
         let http_client = HttpClient::builder()
             .pool_max_idle_per_host(0)
@@ -63,23 +181,52 @@ impl NetworkClient {
             auth,
             rpc,
             http: http_client.into(),
+            retry_policy: RetryPolicy::default(),
+            timeout: DEFAULT_TIMEOUT,
         }
     }
 
     /// Gets the latest nonce for this auth's account.
     pub async fn get_nonce(&self) -> Result<u64> {
         let res = self
-            .with_error_handling(self.rpc.get_nonce(GetNonceRequest {
-                address: self.auth.get_address().to_vec(),
-            }))
+            .with_retries("get nonce", || {
+                self.with_error_handling(self.rpc.get_nonce(GetNonceRequest {
+                    address: self.auth.get_address().to_vec(),
+                }))
+            })
             .await?;
         Ok(res.nonce)
     }
 
     /// Upload a file to the specified url.
-    async fn upload_file(&self, url: &str, data: Vec<u8>) -> Result<()> {
-        self.http.put(url).body(data).send().await?;
-        Ok(())
+    async fn upload_file(&self, url: &str, data: &[u8]) -> Result<()> {
+        self.with_retries("upload file", || async {
+            self.http
+                .put(url)
+                .body(data.to_vec())
+                .send()
+                .await
+                .and_then(|res| res.error_for_status().map_err(Into::into))
+                .map_err(NetworkError::from)?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Download the file at the specified url.
+    async fn download_file(&self, url: &str) -> Result<Vec<u8>> {
+        self.with_retries("download file", || async {
+            let res = self
+                .http
+                .get(url)
+                .send()
+                .await
+                .map_err(NetworkError::from)?
+                .error_for_status()
+                .map_err(NetworkError::from)?;
+            Ok(res.bytes().await.map_err(NetworkError::from)?.to_vec())
+        })
+        .await
     }
 
     /// Get the status of a given proof. If the status is ProofFulfilled, the proof is also returned.
@@ -88,9 +235,11 @@ impl NetworkClient {
         proof_id: &str,
     ) -> Result<(GetProofStatusResponse, Option<P>)> {
         let res = self
-            .with_error_handling(self.rpc.get_proof_status(GetProofStatusRequest {
-                proof_id: proof_id.to_string(),
-            }))
+            .with_retries("get proof status", || {
+                self.with_error_handling(self.rpc.get_proof_status(GetProofStatusRequest {
+                    proof_id: proof_id.to_string(),
+                }))
+            })
             .await
             .context("Failed to get proof status")?;
 
@@ -98,14 +247,9 @@ impl NetworkClient {
             ProofStatus::ProofFulfilled => {
                 log::info!("Proof request fulfilled");
                 let proof_bytes = self
-                    .http
-                    .get(res.proof_url.as_ref().expect("no proof url"))
-                    .send()
+                    .download_file(res.proof_url.as_ref().expect("no proof url"))
                     .await
-                    .context("Failed to send HTTP request for proof")?
-                    .bytes()
-                    .await
-                    .context("Failed to load proof bytes")?;
+                    .context("Failed to download proof")?;
 
                 Some(bincode::deserialize(&proof_bytes).context("Failed to deserialize proof")?)
             }
@@ -120,9 +264,11 @@ impl NetworkClient {
         &self,
         status: ProofStatus,
     ) -> Result<GetProofRequestsResponse> {
-        self.with_error_handling(self.rpc.get_proof_requests(GetProofRequestsRequest {
-            status: status.into(),
-        }))
+        self.with_retries("get proof requests", || {
+            self.with_error_handling(self.rpc.get_proof_requests(GetProofRequestsRequest {
+                status: status.into(),
+            }))
+        })
         .await
     }
 
@@ -133,52 +279,83 @@ impl NetworkClient {
         stdin: &SP1Stdin,
         mode: ProofMode,
         version: &str,
-    ) -> Result<String> {
+    ) -> Result<ProofRequest> {
         let start = SystemTime::now();
         let since_the_epoch = start
             .duration_since(UNIX_EPOCH)
             .expect("Invalid start time");
-        let deadline = since_the_epoch.as_secs() + TIMEOUT.as_secs();
+        let deadline = since_the_epoch.as_secs() + self.timeout.as_secs();
 
+        // Signed requests are retried with the same nonce, so that an attempt which reached the
+        // network before failing is not repeated, e.g. creating a second proof request.
         let nonce = self.get_nonce().await?;
         let create_proof_signature = self
             .auth
             .sign_create_proof_message(nonce, deadline, mode.into(), version)
             .await?;
-
         let res = self
-            .with_error_handling(self.rpc.create_proof(CreateProofRequest {
-                signature: create_proof_signature.to_vec(),
-                nonce,
-                deadline,
-                mode: mode.into(),
-                version: version.to_string(),
-            }))
+            .with_retries("create proof", || {
+                self.with_error_handling(self.rpc.create_proof(CreateProofRequest {
+                    signature: create_proof_signature.to_vec(),
+                    nonce,
+                    deadline,
+                    mode: mode.into(),
+                    version: version.to_string(),
+                }))
+            })
             .await?;
 
         let program_bytes = bincode::serialize(elf)?;
         let stdin_bytes = bincode::serialize(&stdin)?;
-        let program_promise = self.upload_file(&res.program_url, program_bytes);
-        let stdin_promise = self.upload_file(&res.stdin_url, stdin_bytes);
-        let v = vec![program_promise, stdin_promise];
-        let mut results = join_all(v).await;
-        results.pop().expect("Failed to upload stdin")?;
-        results.pop().expect("Failed to upload program")?;
+        try_join(
+            self.upload_file(&res.program_url, &program_bytes),
+            self.upload_file(&res.stdin_url, &stdin_bytes),
+        )
+        .await?;
 
         let nonce = self.get_nonce().await?;
         let submit_proof_signature = self
             .auth
             .sign_submit_proof_message(nonce, &res.proof_id)
             .await?;
+        let mut attempts = 0;
+        let submitted = self
+            .with_retries("submit proof", || {
+                attempts += 1;
+                self.with_error_handling(self.rpc.submit_proof(SubmitProofRequest {
+                    signature: submit_proof_signature.to_vec(),
+                    nonce,
+                    proof_id: res.proof_id.clone(),
+                }))
+            })
+            .await;
+        if let Err(e) = submitted {
+            // If an attempt reached the network before failing, the retries are rejected since
+            // its nonce was used up, but the proof was submitted.
+            if attempts == 1 || !self.is_submitted(&res.proof_id).await? {
+                return Err(e);
+            }
+        }
 
-        self.with_error_handling(self.rpc.submit_proof(SubmitProofRequest {
-            signature: submit_proof_signature.to_vec(),
-            nonce,
-            proof_id: res.proof_id.clone(),
-        }))
-        .await?;
+        Ok(ProofRequest {
+            proof_id: res.proof_id,
+            deadline,
+        })
+    }
 
-        Ok(res.proof_id)
+    /// Whether the proof request was submitted, i.e. is no longer waiting for its artifacts.
+    async fn is_submitted(&self, proof_id: &str) -> Result<bool> {
+        let res = self
+            .with_retries("get proof status", || {
+                self.with_error_handling(self.rpc.get_proof_status(GetProofStatusRequest {
+                    proof_id: proof_id.to_string(),
+                }))
+            })
+            .await?;
+        Ok(!matches!(
+            res.status(),
+            ProofStatus::ProofUnspecifiedStatus | ProofStatus::ProofPreparing
+        ))
     }
 
     /// Claim a proof that was requested. This commits to generating a proof and fulfilling it.
@@ -250,15 +427,82 @@ impl NetworkClient {
         self.handle_twirp_error(result)
     }
 
-    /// Handles Twirp errors by formatting them into more readable error messages.
+    /// Handles Twirp errors by converting them into a [NetworkError].
     fn handle_twirp_error<T>(&self, result: std::result::Result<T, ClientError>) -> Result<T> {
         match result {
             StdOk(response) => StdOk(response),
-            Err(ClientError::TwirpError(err)) => {
-                let display_err = format!("error: \"{:?}\" message: {:?}", err.code, err.msg);
-                Err(anyhow::anyhow!(display_err))
+            Err(err) => Err(NetworkError::from(err).into()),
+        }
+    }
+
+    /// Runs the request made by `f`, retrying it according to the retry policy while it fails
+    /// with a transport error.
+    async fn with_retries<T, F, Fut>(&self, name: &str, mut f: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempts = 0;
+        loop {
+            match f().await {
+                Err(e)
+                    if attempts < self.retry_policy.max_retries
+                        && e.downcast_ref::<NetworkError>()
+                            .map_or(false, NetworkError::is_transport) =>
+                {
+                    attempts += 1;
+                    let backoff = self.retry_policy.backoff(attempts);
+                    log::warn!(
+                        "Failed to {} (attempt {}), retrying in {:?}: {}",
+                        name,
+                        attempts,
+                        backoff,
+                        e
+                    );
+                    sleep(backoff).await;
+                }
+                result => return result,
             }
-            Err(err) => Err(err.into()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{NetworkClient, NetworkError, RetryPolicy};
+
+    #[test]
+    fn test_retry_backoff() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+        };
+        let backoffs = (1..=6)
+            .map(|i| policy.backoff(i).as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(backoffs, [1, 2, 4, 8, 10, 10]);
+    }
+
+    #[tokio::test]
+    async fn test_retry_transport_errors() {
+        // Nothing listens on the port once the listener is dropped.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let rpc_url = format!("http://{}/", listener.local_addr().unwrap());
+        drop(listener);
+
+        let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let mut client = NetworkClient::new_with_rpc_url(private_key, &rpc_url);
+        client.retry_policy = RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(100),
+        };
+        let start = std::time::Instant::now();
+        let error = client.get_nonce().await.unwrap_err();
+        assert!(error.downcast_ref::<NetworkError>().unwrap().is_transport());
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+}
@@ -1,3 +1,4 @@
 pub mod auth;
 pub mod client;
 pub mod prover;
+pub mod server;
@@ -1,14 +1,21 @@
-use std::{env, time::Duration};
+use std::{
+    env,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use crate::install::block_on;
-use crate::proto::network::ProofMode;
+use crate::proto::network::{ProofMode, UnclaimReason};
 use crate::{
-    network::client::{NetworkClient, DEFAULT_PROVER_NETWORK_RPC},
+    network::client::{
+        NetworkClient, NetworkError, ProofRequest, RetryPolicy, DEFAULT_PROVER_NETWORK_RPC,
+    },
     proto::network::ProofStatus,
     Prover,
 };
 use crate::{SP1Context, SP1ProofKind, SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey};
 use anyhow::Result;
+use async_trait::async_trait;
 use serde::de::DeserializeOwned;
 use sp1_core::utils::SP1ProverOpts;
 use sp1_prover::components::DefaultProverComponents;
@@ -17,10 +24,14 @@ use tokio::time::sleep;
 
 use crate::provers::{LocalProver, ProverType};
 
+/// The default interval between two polls of the status of a proof request.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
 /// An implementation of [crate::ProverClient] that can generate proofs on a remote RPC server.
 pub struct NetworkProver {
     client: NetworkClient,
     local_prover: LocalProver<DefaultProverComponents>,
+    poll_interval: Duration,
 }
 
 impl NetworkProver {
@@ -33,23 +44,56 @@ impl NetworkProver {
 
     /// Creates a new [NetworkProver] with the given private key.
     pub fn new_from_key(private_key: &str) -> Self {
+        Self::new_with_client(NetworkClient::new(private_key))
+    }
+
+    /// Creates a new [NetworkProver] with the given private key, requesting proofs from the given
+    /// RPC endpoint instead of the one set in `PROVER_NETWORK_RPC`.
+    pub fn new_with_rpc_url(private_key: &str, rpc_url: &str) -> Self {
+        Self::new_with_client(NetworkClient::new_with_rpc_url(private_key, rpc_url))
+    }
+
+    fn new_with_client(client: NetworkClient) -> Self {
         let version = SP1_CIRCUIT_VERSION;
         log::info!("Client circuit version: {}", version);
 
         let local_prover = LocalProver::new();
         Self {
-            client: NetworkClient::new(private_key),
+            client,
             local_prover,
+            poll_interval: DEFAULT_POLL_INTERVAL,
         }
     }
 
-    /// Requests a proof from the prover network, returning the proof ID.
+    /// Sets how requests failing with a transport error, including uploads and status polls, are
+    /// retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets how long a proof request has to be fulfilled, after which waiting for it fails with
+    /// [NetworkError::Timeout]. The deadline is set when the proof is requested, so resuming
+    /// waiting for it doesn't extend it. Defaults to one hour.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client.timeout = timeout;
+        self
+    }
+
+    /// Sets the interval between two polls of the status of a proof request. Defaults to two
+    /// seconds.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Requests a proof from the prover network, returning the proof request.
     pub async fn request_proof(
         &self,
         elf: &[u8],
         stdin: SP1Stdin,
         mode: ProofMode,
-    ) -> Result<String> {
+    ) -> Result<ProofRequest> {
         let client = &self.client;
 
         let skip_simulation = env::var("SKIP_SIMULATION")
@@ -57,8 +101,11 @@ impl NetworkProver {
             .unwrap_or(false);
 
         if !skip_simulation {
-            let (_, report) =
-                SP1Prover::<DefaultProverComponents>::execute(elf, &stdin, Default::default())?;
+            let (elf, stdin) = (elf.to_vec(), stdin.clone());
+            let (_, report) = tokio::task::spawn_blocking(move || {
+                SP1Prover::<DefaultProverComponents>::execute(&elf, &stdin, Default::default())
+            })
+            .await??;
             log::info!(
                 "Simulation complete, cycles: {}",
                 report.total_instruction_count()
@@ -68,21 +115,27 @@ impl NetworkProver {
         }
 
         let version = SP1_CIRCUIT_VERSION;
-        let proof_id = client.create_proof(elf, &stdin, mode, version).await?;
-        log::info!("Created {}", proof_id);
+        let request = client.create_proof(elf, &stdin, mode, version).await?;
+        log::info!("Created {}", request.proof_id);
 
         if NetworkClient::rpc_url() == DEFAULT_PROVER_NETWORK_RPC {
             log::info!(
                 "View in explorer: https://explorer.succinct.xyz/{}",
-                proof_id
+                request.proof_id
             );
         }
-        Ok(proof_id)
+        Ok(request)
     }
 
     /// Waits for a proof to be generated and returns the proof.
-    pub async fn wait_proof<P: DeserializeOwned>(&self, proof_id: &str) -> Result<P> {
+    ///
+    /// The proof may have been requested by another process, e.g. one that was restarted after
+    /// requesting it, see [NetworkProver::resume_proof]. Fails with a [NetworkError] if the
+    /// request is unclaimed or not fulfilled before its deadline.
+    pub async fn wait_proof<P: DeserializeOwned>(&self, request: &ProofRequest) -> Result<P> {
         let client = &self.client;
+        let proof_id = request.proof_id.as_str();
+        let deadline = UNIX_EPOCH + Duration::from_secs(request.deadline);
         let mut is_claimed = false;
         loop {
             let (status, maybe_proof) = client.get_proof_status::<P>(proof_id).await?;
@@ -98,17 +151,36 @@ impl NetworkProver {
                     }
                 }
                 ProofStatus::ProofUnclaimed => {
-                    return Err(anyhow::anyhow!(
-                        "Proof generation failed: {}",
-                        status.unclaim_description()
-                    ));
+                    let proof_id = proof_id.to_string();
+                    let description = status.unclaim_description().to_string();
+                    return Err(match status.unclaim_reason() {
+                        UnclaimReason::Invalid => NetworkError::Unfulfillable {
+                            proof_id,
+                            description,
+                        },
+                        _ => NetworkError::Unclaimed {
+                            proof_id,
+                            description,
+                        },
+                    }
+                    .into());
                 }
                 _ => {}
             }
-            sleep(Duration::from_secs(2)).await;
+            if SystemTime::now() + self.poll_interval > deadline {
+                return Err(NetworkError::Timeout(proof_id.to_string()).into());
+            }
+            sleep(self.poll_interval).await;
         }
     }
 
+    /// Resumes waiting for a proof requested earlier with [NetworkProver::request_proof], e.g.
+    /// before the process was restarted, and returns the proof.
+    pub async fn resume_proof(&self, request: &ProofRequest) -> Result<SP1ProofWithPublicValues> {
+        log::info!("Resuming waiting for {}", request.proof_id);
+        self.wait_proof(request).await
+    }
+
     /// Requests a proof from the prover network and waits for it to be generated.
     pub async fn prove(
         &self,
@@ -116,11 +188,12 @@ impl NetworkProver {
         stdin: SP1Stdin,
         mode: ProofMode,
     ) -> Result<SP1ProofWithPublicValues> {
-        let proof_id = self.request_proof(elf, stdin, mode).await?;
-        self.wait_proof(&proof_id).await
+        let request = self.request_proof(elf, stdin, mode).await?;
+        self.wait_proof(&request).await
     }
 }
 
+#[async_trait]
 impl Prover<DefaultProverComponents> for NetworkProver {
     fn id(&self) -> ProverType {
         ProverType::Network
@@ -145,6 +218,18 @@ impl Prover<DefaultProverComponents> for NetworkProver {
         warn_if_not_default(&opts, &context);
         block_on(self.prove(&pk.elf, stdin, kind.into()))
     }
+
+    async fn prove_async(
+        self: Arc<Self>,
+        pk: SP1ProvingKey,
+        stdin: SP1Stdin,
+        opts: SP1ProverOpts,
+        context: SP1Context<'static>,
+        kind: SP1ProofKind,
+    ) -> Result<SP1ProofWithPublicValues> {
+        warn_if_not_default(&opts, &context);
+        self.prove(&pk.elf, stdin, kind.into()).await
+    }
 }
 
 impl Default for NetworkProver {
@@ -164,6 +249,9 @@ fn warn_if_not_default(opts: &SP1ProverOpts, context: &SP1Context) {
     let SP1Context {
         hook_registry,
         subproof_verifier,
+        progress_handler,
+        cancellation_token,
+        deadline,
         ..
     } = context;
     if hook_registry.is_some() {
@@ -178,6 +266,12 @@ fn warn_if_not_default(opts: &SP1ProverOpts, context: &SP1Context) {
         tracing::warn!("non-default context.subproof_verifier will be ignored");
         tracing::warn!("custom subproof verifiers are currently unsupported by the network prover");
     }
+    if progress_handler.is_some() {
+        tracing::warn!("progress events are currently unsupported by the network prover");
+    }
+    if cancellation_token.is_some() || deadline.is_some() {
+        tracing::warn!("cancellation is currently unsupported by the network prover");
+    }
 }
 
 impl From<SP1ProofKind> for ProofMode {
@@ -186,6 +280,7 @@ impl From<SP1ProofKind> for ProofMode {
             SP1ProofKind::Core => Self::Core,
             SP1ProofKind::Compressed => Self::Compressed,
             SP1ProofKind::Plonk => Self::Plonk,
+            SP1ProofKind::Groth16 => Self::Groth16,
         }
     }
 }
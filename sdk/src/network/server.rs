@@ -0,0 +1,721 @@
+//! A self-hosted prover service implementing the API of the Succinct prover network.
+//!
+//! The service accepts proof requests from [crate::NetworkProver] pointed at it with
+//! `PROVER_NETWORK_RPC`, stores them with their program and input in an on-disk job queue, and
+//! proves them one at a time with a local prover. Since the queue is on disk, requests that were
+//! not proven yet when the service stopped are proven once it is restarted.
+//!
+//! Requests are authenticated like on the prover network, but any key is allowed to request
+//! proofs. The artifact URLs handed out to the requester carry a random token, without which the
+//! artifacts of a request can't be uploaded or downloaded.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    future::IntoFuture,
+    io::ErrorKind,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{Arc, Condvar, Mutex},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Context as _, Result};
+use axum::{
+    body::Bytes,
+    extract::{DefaultBodyLimit, Path as UrlPath, Query, State},
+    http::StatusCode,
+    routing::put,
+    Router,
+};
+use rand::RngCore;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sp1_prover::{components::DefaultProverComponents, SP1Stdin};
+use tokio::net::TcpListener;
+use twirp::{Context, TwirpErrorResponse};
+
+use crate::{
+    network::auth::NetworkAuth,
+    proto::network::{
+        self, ClaimProofRequest, ClaimProofResponse, CreateProofRequest, CreateProofResponse,
+        FulfillProofRequest, FulfillProofResponse, GetNonceRequest, GetNonceResponse,
+        GetProofRequestsRequest, GetProofRequestsResponse, GetProofStatusRequest,
+        GetProofStatusResponse, GetRelayStatusRequest, GetRelayStatusResponse, NetworkService,
+        ProofMode, ProofStatus, RelayProofRequest, RelayProofResponse, RequestedProof,
+        SubmitProofRequest, SubmitProofResponse, UnclaimProofRequest, UnclaimProofResponse,
+        UnclaimReason,
+    },
+    Prover, SP1ProofKind, SP1ProvingKey,
+};
+
+/// The artifacts of a proof request, uploaded and downloaded through the service.
+const PROGRAM: &str = "program";
+const STDIN: &str = "stdin";
+const PROOF: &str = "proof";
+
+/// A proof request, stored as `job.json` in the directory of the request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Job {
+    proof_id: String,
+    mode: i32,
+    requester: Vec<u8>,
+    status: i32,
+    created_at: u64,
+    /// The time after which the request is abandoned, in seconds since the epoch.
+    deadline: u64,
+    /// The token authorizing the uploads and downloads of the artifacts of the request.
+    token: String,
+    unclaim_description: Option<String>,
+}
+
+impl Job {
+    fn expired(&self) -> bool {
+        now() > self.deadline
+    }
+}
+
+/// The query of the artifact URLs.
+#[derive(Deserialize)]
+struct ArtifactQuery {
+    token: String,
+}
+
+/// The proof requests of the service, each stored in a directory named after its proof id along
+/// with its artifacts.
+struct JobQueue {
+    dir: PathBuf,
+    state: Mutex<QueueState>,
+    requested: Condvar,
+}
+
+struct QueueState {
+    jobs: HashMap<String, Job>,
+    /// The ids of the requested proofs, in the order they were requested.
+    pending: VecDeque<String>,
+    /// The next nonce of each requester, by hex-encoded address, stored as `nonces.json`.
+    nonces: HashMap<String, u64>,
+}
+
+impl JobQueue {
+    /// Open the queue stored in `dir`, creating it if needed.
+    ///
+    /// Proofs that were being proven are requested again.
+    fn open(dir: PathBuf) -> Result<Self> {
+        fs::create_dir_all(&dir)?;
+        let nonces = match fs::read(dir.join("nonces.json")) {
+            Ok(nonces) => serde_json::from_slice(&nonces).context("failed to read nonces.json")?,
+            Err(e) if e.kind() == ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let mut jobs = HashMap::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path().join("job.json");
+            if !path.exists() {
+                continue;
+            }
+            let mut job: Job = serde_json::from_slice(&fs::read(&path)?)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            if job.status == ProofStatus::ProofClaimed as i32 {
+                job.status = ProofStatus::ProofRequested as i32;
+            }
+            jobs.insert(job.proof_id.clone(), job);
+        }
+
+        let mut pending = jobs
+            .values()
+            .filter(|job| job.status == ProofStatus::ProofRequested as i32)
+            .collect::<Vec<_>>();
+        pending.sort_by_key(|job| job.created_at);
+        let pending = pending
+            .into_iter()
+            .map(|job| job.proof_id.clone())
+            .collect();
+
+        Ok(Self {
+            dir,
+            state: Mutex::new(QueueState {
+                jobs,
+                pending,
+                nonces,
+            }),
+            requested: Condvar::new(),
+        })
+    }
+
+    /// The path of the artifact `name` of the proof request `proof_id`.
+    fn artifact_path(&self, proof_id: &str, name: &str) -> PathBuf {
+        self.dir.join(proof_id).join(name)
+    }
+
+    /// Write `job` to disk, replacing its previous state atomically.
+    fn save(&self, job: &Job) -> Result<()> {
+        let dir = self.dir.join(&job.proof_id);
+        fs::create_dir_all(&dir)?;
+        write_atomically(&dir.join("job.json"), &serde_json::to_vec(job)?)?;
+        Ok(())
+    }
+
+    fn get(&self, proof_id: &str) -> Option<Job> {
+        self.state.lock().unwrap().jobs.get(proof_id).cloned()
+    }
+
+    fn nonce(&self, address: &[u8]) -> u64 {
+        let state = self.state.lock().unwrap();
+        state
+            .nonces
+            .get(&hex::encode(address))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Check that `nonce` is the current nonce of `address`, and increment it.
+    ///
+    /// The nonces are saved to disk before returning, so that a signed request can't be replayed
+    /// after the service restarts.
+    fn use_nonce(&self, address: &[u8], nonce: u64) -> Result<(), TwirpErrorResponse> {
+        let mut state = self.state.lock().unwrap();
+        let current = state.nonces.entry(hex::encode(address)).or_default();
+        if *current != nonce {
+            return Err(twirp::invalid_argument(format!(
+                "invalid nonce {}, expected {}",
+                nonce, current
+            )));
+        }
+        *current += 1;
+        let nonces = serde_json::to_vec(&state.nonces).map_err(twirp::internal)?;
+        write_atomically(&self.dir.join("nonces.json"), &nonces).map_err(twirp::internal)
+    }
+
+    /// Create a proof request waiting for its artifacts to be uploaded.
+    fn create(&self, requester: &[u8], mode: i32, deadline: u64) -> Result<Job> {
+        let created_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+        let hash = Sha256::new()
+            .chain_update(requester)
+            .chain_update(created_at.to_le_bytes())
+            .finalize();
+        let mut token = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut token);
+        let job = Job {
+            proof_id: format!("proofrequest_{}", hex::encode(&hash[..16])),
+            mode,
+            requester: requester.to_vec(),
+            status: ProofStatus::ProofPreparing as i32,
+            created_at,
+            deadline,
+            token: hex::encode(token),
+            unclaim_description: None,
+        };
+        self.save(&job)?;
+        self.state
+            .lock()
+            .unwrap()
+            .jobs
+            .insert(job.proof_id.clone(), job.clone());
+        Ok(job)
+    }
+
+    /// Set the status of a proof request, saving it to disk.
+    fn update(&self, job: &mut Job, status: ProofStatus) -> Result<()> {
+        job.status = status as i32;
+        self.save(job)?;
+        let mut state = self.state.lock().unwrap();
+        state.jobs.insert(job.proof_id.clone(), job.clone());
+        if status == ProofStatus::ProofRequested {
+            state.pending.push_back(job.proof_id.clone());
+            self.requested.notify_one();
+        }
+        Ok(())
+    }
+
+    /// The proof requests with the given status, in the order they were created.
+    fn list(&self, status: ProofStatus) -> Vec<Job> {
+        let state = self.state.lock().unwrap();
+        let mut jobs = state
+            .jobs
+            .values()
+            .filter(|job| job.status == status as i32)
+            .cloned()
+            .collect::<Vec<_>>();
+        jobs.sort_by_key(|job| job.created_at);
+        jobs
+    }
+
+    /// Wait for a proof to be requested, and claim it.
+    fn claim(&self) -> Result<Job> {
+        let mut state = self.state.lock().unwrap();
+        let proof_id = loop {
+            match state.pending.pop_front() {
+                Some(proof_id) => break proof_id,
+                None => state = self.requested.wait(state).unwrap(),
+            }
+        };
+        let mut job = state.jobs[&proof_id].clone();
+        drop(state);
+        self.update(&mut job, ProofStatus::ProofClaimed)?;
+        Ok(job)
+    }
+}
+
+/// A self-hosted prover service implementing the API of the Succinct prover network.
+pub struct ProverServer {
+    queue: Arc<JobQueue>,
+    public_url: Url,
+}
+
+impl ProverServer {
+    /// Create a service storing its job queue in `dir`, and telling clients to upload and
+    /// download artifacts at `public_url`, the URL the service is reachable at.
+    ///
+    /// Proof requests left in `dir` by a previous run of the service are resumed.
+    pub fn new(dir: impl Into<PathBuf>, public_url: Url) -> Result<Self> {
+        Ok(Self {
+            queue: Arc::new(JobQueue::open(dir.into())?),
+            public_url,
+        })
+    }
+
+    /// The router serving the API of the service at `/network.NetworkService/`, along with the
+    /// uploads and downloads of artifacts.
+    pub fn router(&self) -> Router {
+        let api = Arc::new(Api {
+            queue: self.queue.clone(),
+            public_url: self.public_url.clone(),
+        });
+        let artifacts = Router::new()
+            .route(
+                "/artifacts/:proof_id/:name",
+                put(upload_artifact).get(download_artifact),
+            )
+            .layer(DefaultBodyLimit::disable())
+            .with_state(self.queue.clone());
+        Router::new()
+            .nest("/network.NetworkService", network::router(api))
+            .merge(artifacts)
+    }
+
+    /// Prove the requested proofs with `prover`, one at a time in the order they were requested.
+    ///
+    /// Never returns unless writing to the job queue fails.
+    pub fn prove_requests(&self, prover: &dyn Prover<DefaultProverComponents>) -> Result<()> {
+        // Programs are usually proven many times in a row, so keep the last proving key around.
+        let mut last_setup: Option<(Vec<u8>, SP1ProvingKey)> = None;
+        loop {
+            let mut job = self.queue.claim()?;
+            if job.expired() {
+                tracing::warn!("deadline of {} exceeded", job.proof_id);
+                job.unclaim_description = Some("deadline exceeded".to_string());
+                self.queue.update(&mut job, ProofStatus::ProofUnclaimed)?;
+                continue;
+            }
+            tracing::info!("proving {}", job.proof_id);
+            let start = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                self.prove_request(prover, &job, &mut last_setup)
+            }))
+            .unwrap_or_else(|_| Err(anyhow!("proving panicked")));
+            match result {
+                Ok(()) => {
+                    tracing::info!("proved {} in {:?}", job.proof_id, start.elapsed());
+                    self.queue.update(&mut job, ProofStatus::ProofFulfilled)?;
+                }
+                Err(e) => {
+                    tracing::warn!("failed to prove {}: {:?}", job.proof_id, e);
+                    job.unclaim_description = Some(e.to_string());
+                    self.queue.update(&mut job, ProofStatus::ProofUnclaimed)?;
+                }
+            }
+        }
+    }
+
+    fn prove_request(
+        &self,
+        prover: &dyn Prover<DefaultProverComponents>,
+        job: &Job,
+        last_setup: &mut Option<(Vec<u8>, SP1ProvingKey)>,
+    ) -> Result<()> {
+        let kind = match ProofMode::try_from(job.mode) {
+            Ok(ProofMode::Core) => SP1ProofKind::Core,
+            Ok(ProofMode::Compressed) => SP1ProofKind::Compressed,
+            Ok(ProofMode::Plonk) => SP1ProofKind::Plonk,
+            Ok(ProofMode::Groth16) => SP1ProofKind::Groth16,
+            _ => return Err(anyhow!("unsupported proof mode {}", job.mode)),
+        };
+        let elf: Vec<u8> =
+            bincode::deserialize(&fs::read(self.queue.artifact_path(&job.proof_id, PROGRAM))?)?;
+        let stdin: SP1Stdin =
+            bincode::deserialize(&fs::read(self.queue.artifact_path(&job.proof_id, STDIN))?)?;
+
+        let pk = match last_setup {
+            Some((last_elf, pk)) if *last_elf == elf => pk,
+            _ => {
+                let (pk, _) = prover.setup(&elf);
+                &last_setup.insert((elf, pk)).1
+            }
+        };
+        let proof = prover.prove(pk, stdin, Default::default(), Default::default(), kind)?;
+
+        let path = self.queue.artifact_path(&job.proof_id, PROOF);
+        write_atomically(&path, &bincode::serialize(&proof)?)?;
+        Ok(())
+    }
+
+    /// Serve the API of the service on `listener` and prove the requested proofs with `prover`.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        prover: Arc<dyn Prover<DefaultProverComponents>>,
+    ) -> Result<()> {
+        let router = self.router();
+        // Proving never returns, so it runs on its own thread rather than on the blocking thread
+        // pool, which would keep the runtime from shutting down.
+        let (tx, proving) = tokio::sync::oneshot::channel();
+        std::thread::spawn(move || tx.send(self.prove_requests(prover.as_ref())));
+        tokio::select! {
+            result = axum::serve(listener, router).into_future() => Ok(result?),
+            result = proving => result?,
+        }
+    }
+}
+
+/// The implementation of the API of the prover network.
+struct Api {
+    queue: Arc<JobQueue>,
+    public_url: Url,
+}
+
+impl Api {
+    fn artifact_url(&self, job: &Job, name: &str) -> Result<String, TwirpErrorResponse> {
+        let mut url = self
+            .public_url
+            .join(&format!("artifacts/{}/{}", job.proof_id, name))
+            .map_err(twirp::internal)?;
+        url.query_pairs_mut().append_pair("token", &job.token);
+        Ok(url.into())
+    }
+}
+
+#[async_trait::async_trait]
+impl NetworkService for Api {
+    async fn create_proof(
+        &self,
+        _: Context,
+        req: CreateProofRequest,
+    ) -> Result<CreateProofResponse, TwirpErrorResponse> {
+        let requester = NetworkAuth::recover_create_proof_signer(
+            &req.signature,
+            req.nonce,
+            req.deadline,
+            req.mode,
+            &req.version,
+        )
+        .map_err(twirp::unauthenticated)?;
+        self.queue.use_nonce(&requester, req.nonce)?;
+        if req.deadline < now() {
+            return Err(twirp::invalid_argument("deadline has passed"));
+        }
+        if !matches!(
+            ProofMode::try_from(req.mode),
+            Ok(ProofMode::Core | ProofMode::Compressed | ProofMode::Plonk | ProofMode::Groth16)
+        ) {
+            return Err(twirp::invalid_argument("unsupported proof mode"));
+        }
+
+        let job = self
+            .queue
+            .create(&requester, req.mode, req.deadline)
+            .map_err(twirp::internal)?;
+        Ok(CreateProofResponse {
+            program_url: self.artifact_url(&job, PROGRAM)?,
+            stdin_url: self.artifact_url(&job, STDIN)?,
+            proof_id: job.proof_id,
+        })
+    }
+
+    async fn submit_proof(
+        &self,
+        _: Context,
+        req: SubmitProofRequest,
+    ) -> Result<SubmitProofResponse, TwirpErrorResponse> {
+        let requester =
+            NetworkAuth::recover_submit_proof_signer(&req.signature, req.nonce, &req.proof_id)
+                .map_err(twirp::unauthenticated)?;
+        let mut job = self
+            .queue
+            .get(&req.proof_id)
+            .ok_or_else(|| twirp::not_found("proof request not found"))?;
+        if job.requester != requester {
+            return Err(twirp::permission_denied("not the requester of the proof"));
+        }
+        self.queue.use_nonce(&requester, req.nonce)?;
+        if job.status != ProofStatus::ProofPreparing as i32 {
+            return Err(twirp::failed_precondition("proof was already submitted"));
+        }
+        if job.expired() {
+            return Err(twirp::failed_precondition("deadline has passed"));
+        }
+        for name in [PROGRAM, STDIN] {
+            if !self.queue.artifact_path(&job.proof_id, name).exists() {
+                return Err(twirp::failed_precondition(format!(
+                    "{} was not uploaded",
+                    name
+                )));
+            }
+        }
+
+        self.queue
+            .update(&mut job, ProofStatus::ProofRequested)
+            .map_err(twirp::internal)?;
+        Ok(SubmitProofResponse {})
+    }
+
+    async fn claim_proof(
+        &self,
+        _: Context,
+        _: ClaimProofRequest,
+    ) -> Result<ClaimProofResponse, TwirpErrorResponse> {
+        Err(twirp::unimplemented("proofs are claimed by the service"))
+    }
+
+    async fn unclaim_proof(
+        &self,
+        _: Context,
+        _: UnclaimProofRequest,
+    ) -> Result<UnclaimProofResponse, TwirpErrorResponse> {
+        Err(twirp::unimplemented("proofs are claimed by the service"))
+    }
+
+    async fn fulfill_proof(
+        &self,
+        _: Context,
+        _: FulfillProofRequest,
+    ) -> Result<FulfillProofResponse, TwirpErrorResponse> {
+        Err(twirp::unimplemented("proofs are fulfilled by the service"))
+    }
+
+    async fn relay_proof(
+        &self,
+        _: Context,
+        _: RelayProofRequest,
+    ) -> Result<RelayProofResponse, TwirpErrorResponse> {
+        Err(twirp::unimplemented("relaying proofs is not supported"))
+    }
+
+    async fn get_nonce(
+        &self,
+        _: Context,
+        req: GetNonceRequest,
+    ) -> Result<GetNonceResponse, TwirpErrorResponse> {
+        Ok(GetNonceResponse {
+            nonce: self.queue.nonce(&req.address),
+        })
+    }
+
+    async fn get_proof_status(
+        &self,
+        _: Context,
+        req: GetProofStatusRequest,
+    ) -> Result<GetProofStatusResponse, TwirpErrorResponse> {
+        let job = self
+            .queue
+            .get(&req.proof_id)
+            .ok_or_else(|| twirp::not_found("proof request not found"))?;
+        let fulfilled = job.status == ProofStatus::ProofFulfilled as i32;
+        let unclaimed = job.status == ProofStatus::ProofUnclaimed as i32;
+        Ok(GetProofStatusResponse {
+            status: job.status,
+            proof_url: fulfilled
+                .then(|| self.artifact_url(&job, PROOF))
+                .transpose()?,
+            unclaim_reason: unclaimed.then_some(UnclaimReason::Abandoned as i32),
+            unclaim_description: job.unclaim_description,
+        })
+    }
+
+    async fn get_proof_requests(
+        &self,
+        _: Context,
+        req: GetProofRequestsRequest,
+    ) -> Result<GetProofRequestsResponse, TwirpErrorResponse> {
+        let status = ProofStatus::try_from(req.status)
+            .map_err(|_| twirp::invalid_argument("invalid status"))?;
+        let proofs = self
+            .queue
+            .list(status)
+            .into_iter()
+            .map(|job| RequestedProof {
+                proof_id: job.proof_id,
+                mode: job.mode,
+                requester: job.requester,
+            })
+            .collect();
+        Ok(GetProofRequestsResponse { proofs })
+    }
+
+    async fn get_relay_status(
+        &self,
+        _: Context,
+        _: GetRelayStatusRequest,
+    ) -> Result<GetRelayStatusResponse, TwirpErrorResponse> {
+        Err(twirp::unimplemented("relaying proofs is not supported"))
+    }
+}
+
+/// Upload the program or the input of a proof request that was not submitted yet.
+async fn upload_artifact(
+    State(queue): State<Arc<JobQueue>>,
+    UrlPath((proof_id, name)): UrlPath<(String, String)>,
+    Query(query): Query<ArtifactQuery>,
+    body: Bytes,
+) -> Result<(), (StatusCode, String)> {
+    let job = authorize(&queue, &proof_id, &query)?;
+    if name != PROGRAM && name != STDIN {
+        return Err((StatusCode::NOT_FOUND, "unknown artifact".to_string()));
+    }
+    if job.status != ProofStatus::ProofPreparing as i32 {
+        return Err((
+            StatusCode::CONFLICT,
+            "proof was already submitted".to_string(),
+        ));
+    }
+    write_atomically(&queue.artifact_path(&job.proof_id, &name), &body)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Download the proof of a fulfilled proof request.
+async fn download_artifact(
+    State(queue): State<Arc<JobQueue>>,
+    UrlPath((proof_id, name)): UrlPath<(String, String)>,
+    Query(query): Query<ArtifactQuery>,
+) -> Result<Vec<u8>, (StatusCode, String)> {
+    let job = authorize(&queue, &proof_id, &query)?;
+    if name != PROOF {
+        return Err((StatusCode::NOT_FOUND, "unknown artifact".to_string()));
+    }
+    if job.status != ProofStatus::ProofFulfilled as i32 {
+        return Err((StatusCode::NOT_FOUND, "proof not found".to_string()));
+    }
+    fs::read(queue.artifact_path(&job.proof_id, PROOF))
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Look up the proof request `proof_id` in the queue and check the token of the artifact URL.
+///
+/// Artifact paths are only ever built from the ids of requests in the queue, never from the URL.
+fn authorize(
+    queue: &JobQueue,
+    proof_id: &str,
+    query: &ArtifactQuery,
+) -> Result<Job, (StatusCode, String)> {
+    let job = queue
+        .get(proof_id)
+        .ok_or((StatusCode::NOT_FOUND, "proof request not found".to_string()))?;
+    // Compare digests so that the time taken doesn't depend on how much of the token matches.
+    if Sha256::digest(&query.token) != Sha256::digest(&job.token) {
+        return Err((StatusCode::FORBIDDEN, "invalid token".to_string()));
+    }
+    Ok(job)
+}
+
+/// The current time, in seconds since the epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+fn write_atomically(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, data)?;
+    fs::rename(tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use reqwest::Url;
+    use sp1_prover::{components::DefaultProverComponents, SP1Prover, SP1Stdin};
+    use tokio::net::TcpListener;
+
+    use super::{authorize, ArtifactQuery, JobQueue, ProverServer};
+    use crate::{
+        proto::network::{ProofMode, ProofStatus},
+        utils, MockProver, NetworkProver, SP1ProofWithPublicValues,
+    };
+
+    #[test]
+    fn test_resume_claimed_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let queue = JobQueue::open(dir.path().to_path_buf()).unwrap();
+        let mut job = queue
+            .create(&[1; 20], ProofMode::Core as i32, u64::MAX)
+            .unwrap();
+        queue.update(&mut job, ProofStatus::ProofRequested).unwrap();
+        let claimed = queue.claim().unwrap();
+        assert_eq!(claimed.proof_id, job.proof_id);
+        drop(queue);
+
+        let queue = JobQueue::open(dir.path().to_path_buf()).unwrap();
+        let requested = queue.list(ProofStatus::ProofRequested);
+        assert_eq!(requested.len(), 1);
+        assert_eq!(requested[0].proof_id, job.proof_id);
+        assert_eq!(queue.claim().unwrap().proof_id, job.proof_id);
+    }
+
+    #[test]
+    fn test_nonces_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let queue = JobQueue::open(dir.path().to_path_buf()).unwrap();
+        queue.use_nonce(&[1; 20], 0).unwrap();
+        queue.use_nonce(&[1; 20], 1).unwrap();
+        drop(queue);
+
+        let queue = JobQueue::open(dir.path().to_path_buf()).unwrap();
+        assert_eq!(queue.nonce(&[1; 20]), 2);
+        assert_eq!(queue.nonce(&[2; 20]), 0);
+        assert!(queue.use_nonce(&[1; 20], 1).is_err());
+    }
+
+    #[test]
+    fn test_artifact_authorization() {
+        let dir = tempfile::tempdir().unwrap();
+        let queue = JobQueue::open(dir.path().to_path_buf()).unwrap();
+        let job = queue
+            .create(&[1; 20], ProofMode::Core as i32, u64::MAX)
+            .unwrap();
+        let query = |token: &str| ArtifactQuery {
+            token: token.to_string(),
+        };
+
+        assert!(authorize(&queue, &job.proof_id, &query(&job.token)).is_ok());
+        assert!(authorize(&queue, &job.proof_id, &query("")).is_err());
+        assert!(authorize(&queue, "../nonces.json", &query(&job.token)).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_network_prover_with_server() {
+        utils::setup_logger();
+        let dir = tempfile::tempdir().unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = ProverServer::new(dir.path(), Url::parse(&url).unwrap()).unwrap();
+        tokio::spawn(server.serve(listener, Arc::new(MockProver::new())));
+
+        let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let prover = NetworkProver::new_with_rpc_url(private_key, &url);
+        let elf =
+            include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10u32);
+        let proof: SP1ProofWithPublicValues = prover
+            .prove(elf, stdin.clone(), ProofMode::Core)
+            .await
+            .unwrap();
+        let (public_values, _) =
+            SP1Prover::<DefaultProverComponents>::execute(elf, &stdin, Default::default()).unwrap();
+        assert_eq!(proof.public_values.as_slice(), public_values.as_slice());
+    }
+}
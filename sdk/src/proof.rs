@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use strum_macros::{EnumDiscriminants, EnumTryAs};
 
 use sp1_core::stark::{MachineVerificationError, ShardProof};
-use sp1_prover::{CoreSC, InnerSC, PlonkBn254Proof, SP1PublicValues, SP1Stdin};
+use sp1_prover::{CoreSC, Groth16Bn254Proof, InnerSC, PlonkBn254Proof, SP1PublicValues, SP1Stdin};
 
 /// A proof generated with SP1 of a particular proof mode.
 #[derive(Debug, Clone, Serialize, Deserialize, EnumDiscriminants, EnumTryAs)]
@@ -16,6 +16,7 @@ pub enum SP1Proof {
     Core(Vec<ShardProof<CoreSC>>),
     Compressed(ShardProof<InnerSC>),
     Plonk(PlonkBn254Proof),
+    Groth16(Groth16Bn254Proof),
 }
 
 /// A proof generated with SP1, bundled together with stdin, public values, and the SP1 version.
@@ -44,12 +45,14 @@ impl SP1ProofWithPublicValues {
     pub fn raw(&self) -> String {
         match &self.proof {
             SP1Proof::Plonk(plonk) => plonk.raw_proof.clone(),
+            SP1Proof::Groth16(groth16) => groth16.raw_proof.clone(),
             _ => unimplemented!(),
         }
     }
 
-    /// For Plonk proofs, returns the proof in a byte encoding the onchain verifier accepts.
-    /// The bytes consist of the first four bytes of Plonk vkey hash followed by the encoded proof.
+    /// For Plonk and Groth16 proofs, returns the proof in a byte encoding the onchain verifier
+    /// accepts. The bytes consist of the first four bytes of the circuit's vkey hash followed by
+    /// the encoded proof.
     pub fn bytes(&self) -> Vec<u8> {
         match &self.proof {
             SP1Proof::Plonk(plonk_proof) => {
@@ -60,7 +63,15 @@ impl SP1ProofWithPublicValues {
                 );
                 bytes
             }
-            _ => unimplemented!("only Plonk proofs are verifiable onchain"),
+            SP1Proof::Groth16(groth16_proof) => {
+                let mut bytes = Vec::with_capacity(4 + groth16_proof.encoded_proof.len());
+                bytes.extend_from_slice(&groth16_proof.groth16_vkey_hash[..4]);
+                bytes.extend_from_slice(
+                    &hex::decode(&groth16_proof.encoded_proof).expect("Invalid Groth16 proof"),
+                );
+                bytes
+            }
+            _ => unimplemented!("only Plonk and Groth16 proofs are verifiable onchain"),
         }
     }
 }
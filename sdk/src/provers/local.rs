@@ -1,11 +1,14 @@
 use anyhow::Result;
-use sp1_core::{runtime::SP1Context, utils::SP1ProverOpts};
-use sp1_prover::{components::SP1ProverComponents, SP1Prover, SP1Stdin};
+use sp1_core::{
+    runtime::{ProgressEvent, SP1Context},
+    utils::{SP1CoreProverError, SP1ProverOpts},
+};
+use sp1_prover::{components::SP1ProverComponents, SP1Prover, SP1RecursionProverError, SP1Stdin};
 use sysinfo::System;
 
 use crate::{
-    install::try_install_plonk_bn254_artifacts, Prover, SP1Proof, SP1ProofKind,
-    SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey,
+    install::{ensure_groth16_bn254_artifacts_available, try_install_plonk_bn254_artifacts},
+    Prover, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey,
 };
 
 use super::ProverType;
@@ -50,13 +53,24 @@ impl<C: SP1ProverComponents> Prover<C> for LocalProver<C> {
         kind: SP1ProofKind,
     ) -> Result<SP1ProofWithPublicValues> {
         let total_ram_gb = System::new_all().total_memory() / 1_000_000_000;
-        if kind == SP1ProofKind::Plonk && total_ram_gb <= 120 {
+        if matches!(kind, SP1ProofKind::Plonk | SP1ProofKind::Groth16) && total_ram_gb <= 120 {
             return Err(anyhow::anyhow!(
-                "not enough memory to generate plonk proof. at least 128GB is required."
+                "not enough memory to generate {:?} proof. at least 128GB is required.",
+                kind
             ));
         }
+        if kind == SP1ProofKind::Groth16 {
+            ensure_groth16_bn254_artifacts_available()?;
+        }
 
-        let proof = self.prover.prove_core(pk, &stdin, opts, context)?;
+        let proving_context = context.proving_context();
+        let proof = self
+            .prover
+            .prove_core(pk, &stdin, opts, context)
+            .map_err(|e| match e {
+                SP1CoreProverError::Cancelled(cancelled) => cancelled.into(),
+                e => anyhow::Error::from(e),
+            })?;
         if kind == SP1ProofKind::Core {
             return Ok(SP1ProofWithPublicValues {
                 proof: SP1Proof::Core(proof.proof.0),
@@ -67,7 +81,13 @@ impl<C: SP1ProverComponents> Prover<C> for LocalProver<C> {
         }
         let deferred_proofs = stdin.proofs.iter().map(|p| p.0.clone()).collect();
         let public_values = proof.public_values.clone();
-        let reduce_proof = self.prover.compress(&pk.vk, proof, deferred_proofs, opts)?;
+        let reduce_proof = self
+            .prover
+            .compress_with_context(&pk.vk, proof, deferred_proofs, opts, &proving_context)
+            .map_err(|e| match e {
+                SP1RecursionProverError::Cancelled(cancelled) => cancelled.into(),
+                e => anyhow::Error::from(e),
+            })?;
         if kind == SP1ProofKind::Compressed {
             return Ok(SP1ProofWithPublicValues {
                 proof: SP1Proof::Compressed(reduce_proof.proof),
@@ -76,9 +96,29 @@ impl<C: SP1ProverComponents> Prover<C> for LocalProver<C> {
                 sp1_version: self.version().to_string(),
             });
         }
+        proving_context.check_cancelled()?;
+        proving_context.report_progress(ProgressEvent::WrapStarted);
         let compress_proof = self.prover.shrink(reduce_proof, opts)?;
+        proving_context.check_cancelled()?;
         let outer_proof = self.prover.wrap_bn254(compress_proof, opts)?;
 
+        if kind == SP1ProofKind::Groth16 {
+            let groth16_bn254_artifacts = sp1_prover::build::try_build_groth16_bn254_artifacts_dev(
+                &self.prover.wrap_vk,
+                &outer_proof.proof,
+            );
+            proving_context.check_cancelled()?;
+            let proof = self
+                .prover
+                .wrap_groth16_bn254(outer_proof, &groth16_bn254_artifacts);
+            return Ok(SP1ProofWithPublicValues {
+                proof: SP1Proof::Groth16(proof),
+                stdin,
+                public_values,
+                sp1_version: self.version().to_string(),
+            });
+        }
+
         let plonk_bn254_aritfacts = if sp1_prover::build::sp1_dev_mode() {
             sp1_prover::build::try_build_plonk_bn254_artifacts_dev(
                 &self.prover.wrap_vk,
@@ -87,6 +127,7 @@ impl<C: SP1ProverComponents> Prover<C> for LocalProver<C> {
         } else {
             try_install_plonk_bn254_artifacts()
         };
+        proving_context.check_cancelled()?;
         let proof = self
             .prover
             .wrap_plonk_bn254(outer_proof, &plonk_bn254_aritfacts);
@@ -1,7 +1,10 @@
 mod local;
 mod mock;
 
+use std::sync::Arc;
+
 use anyhow::Result;
+use async_trait::async_trait;
 pub use local::LocalProver;
 pub use mock::MockProver;
 use sp1_core::runtime::SP1Context;
@@ -18,7 +21,7 @@ use sp1_prover::{SP1ProvingKey, SP1Stdin, SP1VerifyingKey};
 use strum_macros::EnumString;
 use thiserror::Error;
 
-use crate::install::try_install_plonk_bn254_artifacts;
+use crate::install::{ensure_groth16_bn254_artifacts_available, try_install_plonk_bn254_artifacts};
 use crate::SP1Proof;
 use crate::SP1ProofKind;
 use crate::SP1ProofWithPublicValues;
@@ -41,9 +44,12 @@ pub enum SP1VerificationError {
     Recursion(MachineVerificationError<InnerSC>),
     #[error("Plonk verification error: {0}")]
     Plonk(anyhow::Error),
+    #[error("Groth16 verification error: {0}")]
+    Groth16(anyhow::Error),
 }
 
 /// An implementation of [crate::ProverClient].
+#[async_trait]
 pub trait Prover<C: SP1ProverComponents>: Send + Sync {
     fn id(&self) -> ProverType;
 
@@ -65,8 +71,27 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
         kind: SP1ProofKind,
     ) -> Result<SP1ProofWithPublicValues>;
 
+    /// Prove the execution of a RISCV ELF with the given inputs, according to the given proof mode,
+    /// without blocking the current task.
+    ///
+    /// By default, proving runs on the blocking thread pool of the tokio runtime.
+    async fn prove_async(
+        self: Arc<Self>,
+        pk: SP1ProvingKey,
+        stdin: SP1Stdin,
+        opts: SP1ProverOpts,
+        context: SP1Context<'static>,
+        kind: SP1ProofKind,
+    ) -> Result<SP1ProofWithPublicValues>
+    where
+        Self: 'static,
+    {
+        tokio::task::spawn_blocking(move || self.prove(&pk, stdin, opts, context.scoped(), kind))
+            .await?
+    }
+
     /// Verify that an SP1 proof is valid given its vkey and metadata.
-    /// For Plonk proofs, verifies that the public inputs of the PlonkBn254 proof match
+    /// For Plonk and Groth16 proofs, verifies that the public inputs of the Bn254 proof match
     /// the hash of the VK and the committed public values of the SP1ProofWithPublicValues.
     fn verify(
         &self,
@@ -87,19 +112,72 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
                 .sp1_prover()
                 .verify_compressed(&SP1ReduceProof { proof }, vkey)
                 .map_err(SP1VerificationError::Recursion),
-            SP1Proof::Plonk(proof) => self
-                .sp1_prover()
-                .verify_plonk_bn254(
+            SP1Proof::Plonk(proof) => {
+                let build_dir = if sp1_prover::build::sp1_dev_mode() {
+                    sp1_prover::build::plonk_bn254_artifacts_dev_dir()
+                } else {
+                    try_install_plonk_bn254_artifacts()
+                };
+                // With `sp1-verifier`, the proof is checked natively instead of through gnark.
+                #[cfg(feature = "verifier")]
+                let result = verify_plonk_bn254_natively(&proof, vkey, bundle, &build_dir);
+                #[cfg(not(feature = "verifier"))]
+                let result = self.sp1_prover().verify_plonk_bn254(
                     &proof,
                     vkey,
                     &bundle.public_values,
-                    &if sp1_prover::build::sp1_dev_mode() {
-                        sp1_prover::build::plonk_bn254_artifacts_dev_dir()
-                    } else {
-                        try_install_plonk_bn254_artifacts()
-                    },
-                )
-                .map_err(SP1VerificationError::Plonk),
+                    &build_dir,
+                );
+                result.map_err(SP1VerificationError::Plonk)
+            }
+            SP1Proof::Groth16(proof) => {
+                ensure_groth16_bn254_artifacts_available()
+                    .map_err(SP1VerificationError::Groth16)?;
+                self.sp1_prover()
+                    .verify_groth16_bn254(
+                        &proof,
+                        vkey,
+                        &bundle.public_values,
+                        &sp1_prover::build::groth16_bn254_artifacts_dev_dir(),
+                    )
+                    .map_err(SP1VerificationError::Groth16)
+            }
         }
     }
 }
+
+/// Verifies a PLONK proof with the verifying key in the circuit artifacts of `build_dir`, using the
+/// native verifier of `sp1-verifier`.
+#[cfg(feature = "verifier")]
+fn verify_plonk_bn254_natively(
+    proof: &sp1_prover::PlonkBn254Proof,
+    vkey: &SP1VerifyingKey,
+    bundle: &SP1ProofWithPublicValues,
+    build_dir: &std::path::Path,
+) -> Result<()> {
+    use std::str::FromStr;
+
+    use num_bigint::BigUint;
+
+    let plonk_vk = std::fs::read(build_dir.join("vk.bin"))?;
+    let vkey_hash = BigUint::from_str(&proof.public_inputs[0])?;
+    let committed_values_digest = BigUint::from_str(&proof.public_inputs[1])?;
+    let native_proof = sp1_verifier::PlonkBn254Proof {
+        public_inputs: proof.public_inputs.clone(),
+        encoded_proof: proof.encoded_proof.clone(),
+        raw_proof: proof.raw_proof.clone(),
+        plonk_vkey_hash: proof.plonk_vkey_hash,
+    };
+    sp1_verifier::plonk::verify_plonk_bn254(
+        &native_proof,
+        &vkey_hash,
+        &committed_values_digest,
+        &plonk_vk,
+    )?;
+
+    sp1_prover::verify::verify_plonk_bn254_public_inputs(
+        vkey,
+        &bundle.public_values,
+        &proof.public_inputs,
+    )
+}
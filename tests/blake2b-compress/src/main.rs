@@ -0,0 +1,54 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use blake2::{Blake2b512, Digest};
+use sp1_zkvm::syscalls::syscall_blake2b_compress;
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// Hashes a message with unkeyed BLAKE2b-512, compressing each block with the precompile.
+fn blake2b_512(message: &[u8]) -> [u8; 64] {
+    let mut state = IV;
+    state[0] ^= 0x01010040;
+
+    let num_blocks = message.len().div_ceil(128).max(1);
+    for i in 0..num_blocks {
+        let chunk = &message[i * 128..message.len().min((i + 1) * 128)];
+        let mut bytes = [0u8; 128];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+
+        // The block is laid out as `m[0..16] || t[0..2] || f[0..2]`.
+        let mut block = [0u64; 20];
+        for (word, bytes) in block.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        block[16] = (i * 128 + chunk.len()) as u64;
+        if i == num_blocks - 1 {
+            block[18] = u64::MAX;
+        }
+        syscall_blake2b_compress(state.as_mut_ptr(), block.as_ptr());
+    }
+
+    let mut digest = [0u8; 64];
+    for (bytes, word) in digest.chunks_exact_mut(8).zip(state) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+pub fn main() {
+    let messages: [&[u8]; 4] = [b"", b"abc", &[0xab; 200], &[0xcd; 256]];
+    for message in messages {
+        let digest = blake2b_512(message);
+        assert_eq!(digest[..], Blake2b512::digest(message)[..]);
+    }
+}
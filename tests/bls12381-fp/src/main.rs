@@ -0,0 +1,57 @@
+#![no_main]
+
+use sp1_zkvm::lib::bls12381::{
+    fp2_add_assign, fp2_mul_assign, fp2_sub_assign, fp_add_assign, fp_mul_assign, fp_sub_assign,
+};
+
+sp1_zkvm::entrypoint!(main);
+
+/// The BLS12-381 base field modulus minus one, as little-endian words.
+const MINUS_ONE: [u32; 12] = [
+    0xffffaaaa, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84,
+    0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+];
+
+const ONE: [u32; 12] = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const TWO: [u32; 12] = [2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const ZERO: [u32; 12] = [0; 12];
+
+fn fp2(c0: [u32; 12], c1: [u32; 12]) -> [u32; 24] {
+    let mut result = [0; 24];
+    result[..12].copy_from_slice(&c0);
+    result[12..].copy_from_slice(&c1);
+    result
+}
+
+pub fn main() {
+    for _ in 0..4 {
+        // (p - 1) + 2 = 1.
+        let mut x = MINUS_ONE;
+        fp_add_assign(&mut x, &TWO);
+        assert_eq!(x, ONE);
+
+        // 1 - 2 = p - 1.
+        fp_sub_assign(&mut x, &TWO);
+        assert_eq!(x, MINUS_ONE);
+
+        // (p - 1) * (p - 1) = 1.
+        fp_mul_assign(&mut x, &MINUS_ONE);
+        assert_eq!(x, ONE);
+
+        // u * u = -1.
+        let u = fp2(ZERO, ONE);
+        let mut x = u;
+        fp2_mul_assign(&mut x, &u);
+        assert_eq!(x, fp2(MINUS_ONE, ZERO));
+
+        // -1 + (1 + u) = u.
+        fp2_add_assign(&mut x, &fp2(ONE, ONE));
+        assert_eq!(x, u);
+
+        // u - u = 0.
+        fp2_sub_assign(&mut x, &u);
+        assert_eq!(x, fp2(ZERO, ZERO));
+    }
+
+    println!("done");
+}
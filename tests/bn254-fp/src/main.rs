@@ -0,0 +1,57 @@
+#![no_main]
+
+use sp1_zkvm::lib::bn254::{
+    fp2_add_assign, fp2_mul_assign, fp2_sub_assign, fp_add_assign, fp_mul_assign, fp_sub_assign,
+};
+
+sp1_zkvm::entrypoint!(main);
+
+/// The BN254 base field modulus minus one, as little-endian words.
+const MINUS_ONE: [u32; 8] = [
+    0xd87cfd46, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029,
+    0x30644e72,
+];
+
+const ONE: [u32; 8] = [1, 0, 0, 0, 0, 0, 0, 0];
+const TWO: [u32; 8] = [2, 0, 0, 0, 0, 0, 0, 0];
+const ZERO: [u32; 8] = [0; 8];
+
+fn fp2(c0: [u32; 8], c1: [u32; 8]) -> [u32; 16] {
+    let mut result = [0; 16];
+    result[..8].copy_from_slice(&c0);
+    result[8..].copy_from_slice(&c1);
+    result
+}
+
+pub fn main() {
+    for _ in 0..4 {
+        // (p - 1) + 2 = 1.
+        let mut x = MINUS_ONE;
+        fp_add_assign(&mut x, &TWO);
+        assert_eq!(x, ONE);
+
+        // 1 - 2 = p - 1.
+        fp_sub_assign(&mut x, &TWO);
+        assert_eq!(x, MINUS_ONE);
+
+        // (p - 1) * (p - 1) = 1.
+        fp_mul_assign(&mut x, &MINUS_ONE);
+        assert_eq!(x, ONE);
+
+        // u * u = -1.
+        let u = fp2(ZERO, ONE);
+        let mut x = u;
+        fp2_mul_assign(&mut x, &u);
+        assert_eq!(x, fp2(MINUS_ONE, ZERO));
+
+        // -1 + (1 + u) = u.
+        fp2_add_assign(&mut x, &fp2(ONE, ONE));
+        assert_eq!(x, u);
+
+        // u - u = 0.
+        fp2_sub_assign(&mut x, &u);
+        assert_eq!(x, fp2(ZERO, ZERO));
+    }
+
+    println!("done");
+}
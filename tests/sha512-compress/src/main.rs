@@ -0,0 +1,59 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sha2::{Digest, Sha512};
+use sp1_zkvm::syscalls::syscall_sha512_compress;
+
+/// The initial hash value of SHA-512.
+const SHA512_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// Hashes a message that fits in a single block with the compress precompile.
+fn sha512(message: &[u8]) -> [u8; 64] {
+    assert!(message.len() < 112);
+
+    // Pad the message into one block.
+    let mut block = [0u8; 128];
+    block[..message.len()].copy_from_slice(message);
+    block[message.len()] = 0x80;
+    block[120..].copy_from_slice(&((message.len() as u64) * 8).to_be_bytes());
+
+    // Compute the message schedule.
+    let mut w = [0u64; 80];
+    for (i, chunk) in block.chunks_exact(8).enumerate() {
+        w[i] = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    for i in 16..80 {
+        let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+        let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    let mut state = SHA512_IV;
+    syscall_sha512_compress(w.as_mut_ptr(), state.as_mut_ptr());
+
+    let mut digest = [0u8; 64];
+    for (chunk, word) in digest.chunks_exact_mut(8).zip(state) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+pub fn main() {
+    let messages: [&[u8]; 4] = [b"", b"abc", b"hello world", &[0xab; 111]];
+    for message in messages {
+        let digest = sha512(message);
+        assert_eq!(digest[..], Sha512::digest(message)[..]);
+    }
+}
@@ -0,0 +1,26 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::syscalls::syscall_sha512_extend;
+
+/// Computes the SHA-512 message schedule in software.
+fn extend(w: &mut [u64; 80]) {
+    for i in 16..80 {
+        let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+        let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+}
+
+pub fn main() {
+    let mut w = [1u64; 80];
+    let mut expected = w;
+    for _ in 0..3 {
+        syscall_sha512_extend(w.as_mut_ptr());
+        extend(&mut expected);
+        assert_eq!(w, expected);
+    }
+}
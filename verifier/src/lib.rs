@@ -0,0 +1,56 @@
+//! A verifier for proofs generated by the SP1 RISC-V zkVM.
+//!
+//! This crate only depends on the machine definitions needed to check proofs, so services that
+//! verify proofs do not need to compile the prover, the recursion compiler or the gnark bindings.
+//!
+//! ```rust,no_run
+//! use sp1_verifier::{SP1ProofWithPublicValues, SP1Verifier, SP1VerifyingKey};
+//!
+//! let proof = SP1ProofWithPublicValues::load("proof.bin").unwrap();
+//! let vk: SP1VerifyingKey =
+//!     bincode::deserialize(&std::fs::read("vk.bin").unwrap()).unwrap();
+//!
+//! let verifier = SP1Verifier::new();
+//! verifier.verify(&proof, &vk).expect("verification failed");
+//! ```
+//!
+//! Compressed proofs can also be verified on `wasm32-unknown-unknown` with
+//! [SP1Verifier::verify_compressed_bytes], which takes the serialized proof and verifying key.
+
+#![allow(clippy::new_without_default)]
+
+pub mod plonk;
+pub mod proof;
+pub mod types;
+pub mod utils;
+pub mod verify;
+
+use sp1_core::utils::BabyBearPoseidon2;
+use sp1_recursion_core::stark::RecursionAir;
+
+pub use proof::*;
+pub use sp1_core::io::{SP1PublicValues, SP1Stdin};
+pub use sp1_core::SP1_CIRCUIT_VERSION;
+pub use types::*;
+pub use verify::*;
+
+/// The configuration for the core prover.
+pub type CoreSC = BabyBearPoseidon2;
+
+/// The configuration for the inner prover.
+pub type InnerSC = BabyBearPoseidon2;
+
+pub const REDUCE_DEGREE: usize = 3;
+
+/// The machine that compressed proofs are proven over.
+pub type ReduceAir<F> = RecursionAir<F, REDUCE_DEGREE>;
+
+/// Verification never needs randomness, so on wasm targets without a source of entropy the
+/// dependencies that ask for it get an error instead of a missing import.
+#[cfg(target_arch = "wasm32")]
+fn unsupported_getrandom(_: &mut [u8]) -> Result<(), getrandom::Error> {
+    Err(getrandom::Error::UNSUPPORTED)
+}
+
+#[cfg(target_arch = "wasm32")]
+getrandom::register_custom_getrandom!(unsupported_getrandom);
@@ -0,0 +1,249 @@
+//! Decoding of the gnark encodings of BN254 PLONK verifying keys and proofs.
+
+use ark_bn254::{g1, g2, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::{short_weierstrass::SWCurveConfig, AffineRepr};
+use ark_ff::{Field, PrimeField, Zero};
+use num_bigint::BigUint;
+
+use super::{PlonkProof, PlonkVerifyingKey};
+
+/// The length of the NAF of the BN254 ate loop count, i.e. the number of precomputed lines gnark
+/// stores per G2 point of the KZG verifying key.
+const LOOP_COUNTER_LEN: usize = 66;
+
+/// The size of the precomputed pairing lines of the two KZG G2 points, which are not used here.
+const KZG_LINES_SIZE: usize = 2 * 2 * LOOP_COUNTER_LEN * 4 * 32;
+
+const MASK: u8 = 0b11 << 6;
+const COMPRESSED_SMALLEST: u8 = 0b10 << 6;
+const COMPRESSED_LARGEST: u8 = 0b11 << 6;
+const COMPRESSED_INFINITY: u8 = 0b01 << 6;
+
+/// Decodes a verifying key written by gnark's `VerifyingKey.WriteTo`, i.e. the `vk.bin` of the
+/// circuit artifacts.
+pub(super) fn decode_verifying_key(bytes: &[u8]) -> Result<PlonkVerifyingKey, &'static str> {
+    let mut reader = Reader { bytes };
+
+    let size = reader.u64()?;
+    let size_inv = reader.fr()?;
+    let generator = reader.fr()?;
+    let nb_public_variables = reader.u64()?;
+    let coset_shift = reader.fr()?;
+    let s = [
+        reader.g1_compressed()?,
+        reader.g1_compressed()?,
+        reader.g1_compressed()?,
+    ];
+    let ql = reader.g1_compressed()?;
+    let qr = reader.g1_compressed()?;
+    let qm = reader.g1_compressed()?;
+    let qo = reader.g1_compressed()?;
+    let qk = reader.g1_compressed()?;
+    let qcp = (0..reader.u32()?)
+        .map(|_| reader.g1_compressed())
+        .collect::<Result<Vec<_>, _>>()?;
+    let g1 = reader.g1_compressed()?;
+    let g2 = [reader.g2_compressed()?, reader.g2_compressed()?];
+    reader.take(KZG_LINES_SIZE)?;
+    let commitment_constraint_indexes = (0..reader.u32()?)
+        .map(|_| reader.u64())
+        .collect::<Result<Vec<_>, _>>()?;
+    reader.finish()?;
+
+    if Fr::from(size) * size_inv != Fr::ONE {
+        return Err("inconsistent domain size");
+    }
+    if qcp.len() != commitment_constraint_indexes.len() {
+        return Err("inconsistent number of commitments");
+    }
+
+    Ok(PlonkVerifyingKey {
+        size,
+        size_inv,
+        generator,
+        nb_public_variables,
+        coset_shift,
+        s,
+        ql,
+        qr,
+        qm,
+        qo,
+        qk,
+        qcp,
+        g1,
+        g2,
+        commitment_constraint_indexes,
+    })
+}
+
+/// Decodes a proof written by gnark's `Proof.MarshalSolidity`, i.e. the `encoded_proof` of a
+/// [crate::PlonkBn254Proof].
+pub(super) fn decode_proof(
+    bytes: &[u8],
+    nb_commitments: usize,
+) -> Result<PlonkProof, &'static str> {
+    let mut reader = Reader { bytes };
+
+    let lro = [
+        reader.g1_uncompressed()?,
+        reader.g1_uncompressed()?,
+        reader.g1_uncompressed()?,
+    ];
+    let h = [
+        reader.g1_uncompressed()?,
+        reader.g1_uncompressed()?,
+        reader.g1_uncompressed()?,
+    ];
+    let l_at_zeta = reader.fr()?;
+    let r_at_zeta = reader.fr()?;
+    let o_at_zeta = reader.fr()?;
+    let s1_at_zeta = reader.fr()?;
+    let s2_at_zeta = reader.fr()?;
+    let z = reader.g1_uncompressed()?;
+    let z_at_zeta_omega = reader.fr()?;
+    let batch_opening_at_zeta = reader.g1_uncompressed()?;
+    let opening_at_zeta_omega = reader.g1_uncompressed()?;
+    let qcp_at_zeta = (0..nb_commitments)
+        .map(|_| reader.fr())
+        .collect::<Result<Vec<_>, _>>()?;
+    let bsb22_commitments = (0..nb_commitments)
+        .map(|_| reader.g1_uncompressed())
+        .collect::<Result<Vec<_>, _>>()?;
+    reader.finish()?;
+
+    Ok(PlonkProof {
+        lro,
+        h,
+        l_at_zeta,
+        r_at_zeta,
+        o_at_zeta,
+        s1_at_zeta,
+        s2_at_zeta,
+        z,
+        z_at_zeta_omega,
+        batch_opening_at_zeta,
+        opening_at_zeta_omega,
+        qcp_at_zeta,
+        bsb22_commitments,
+    })
+}
+
+/// Interprets big-endian bytes as a field element, failing if they are not reduced.
+pub(super) fn field_from_be_bytes<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    field_from_biguint(&BigUint::from_bytes_be(bytes))
+}
+
+/// Converts an integer into a field element, failing if it is not reduced.
+pub(super) fn field_from_biguint<F: PrimeField>(value: &BigUint) -> Option<F> {
+    (*value < F::MODULUS.into()).then(|| F::from(value.clone()))
+}
+
+/// Whether `y` is larger than `-y`, which is how gnark picks the root of a compressed point.
+fn is_lexicographically_largest(y: &Fq) -> bool {
+    y.into_bigint() > Fq::MODULUS_MINUS_ONE_DIV_TWO
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], &'static str> {
+        if self.bytes.len() < len {
+            return Err("unexpected end of input");
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn finish(self) -> Result<(), &'static str> {
+        if !self.bytes.is_empty() {
+            return Err("unexpected trailing bytes");
+        }
+        Ok(())
+    }
+
+    fn u32(&mut self) -> Result<u32, &'static str> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, &'static str> {
+        Ok(u64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn fr(&mut self) -> Result<Fr, &'static str> {
+        field_from_be_bytes(self.take(32)?).ok_or("scalar is not reduced")
+    }
+
+    fn fq(&mut self) -> Result<Fq, &'static str> {
+        field_from_be_bytes(self.take(32)?).ok_or("coordinate is not reduced")
+    }
+
+    /// Reads the EVM encoding of a G1 point, `x || y` with `(0, 0)` for the point at infinity.
+    fn g1_uncompressed(&mut self) -> Result<G1Affine, &'static str> {
+        let x = self.fq()?;
+        let y = self.fq()?;
+        if x.is_zero() && y.is_zero() {
+            return Ok(G1Affine::zero());
+        }
+        let point = G1Affine::new_unchecked(x, y);
+        if !point.is_on_curve() {
+            return Err("point is not on the curve");
+        }
+        Ok(point)
+    }
+
+    fn g1_compressed(&mut self) -> Result<G1Affine, &'static str> {
+        let mut bytes: [u8; 32] = self.take(32)?.try_into().unwrap();
+        let flag = bytes[0] & MASK;
+        bytes[0] &= !MASK;
+        if flag == COMPRESSED_INFINITY {
+            return Ok(G1Affine::zero());
+        }
+        if flag != COMPRESSED_SMALLEST && flag != COMPRESSED_LARGEST {
+            return Err("point is not compressed");
+        }
+
+        let x: Fq = field_from_be_bytes(&bytes).ok_or("coordinate is not reduced")?;
+        let mut y = (x.square() * x + g1::Config::COEFF_B)
+            .sqrt()
+            .ok_or("point is not on the curve")?;
+        if is_lexicographically_largest(&y) != (flag == COMPRESSED_LARGEST) {
+            y = -y;
+        }
+        Ok(G1Affine::new_unchecked(x, y))
+    }
+
+    fn g2_compressed(&mut self) -> Result<G2Affine, &'static str> {
+        let mut bytes: [u8; 64] = self.take(64)?.try_into().unwrap();
+        let flag = bytes[0] & MASK;
+        bytes[0] &= !MASK;
+        if flag == COMPRESSED_INFINITY {
+            return Ok(G2Affine::zero());
+        }
+        if flag != COMPRESSED_SMALLEST && flag != COMPRESSED_LARGEST {
+            return Err("point is not compressed");
+        }
+
+        let c1 = field_from_be_bytes(&bytes[..32]).ok_or("coordinate is not reduced")?;
+        let c0 = field_from_be_bytes(&bytes[32..]).ok_or("coordinate is not reduced")?;
+        let x = Fq2::new(c0, c1);
+        let mut y = (x.square() * x + g2::Config::COEFF_B)
+            .sqrt()
+            .ok_or("point is not on the curve")?;
+        let largest = if y.c1.is_zero() {
+            is_lexicographically_largest(&y.c0)
+        } else {
+            is_lexicographically_largest(&y.c1)
+        };
+        if largest != (flag == COMPRESSED_LARGEST) {
+            y = -y;
+        }
+        let point = G2Affine::new_unchecked(x, y);
+        if !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err("point is not in the prime order subgroup");
+        }
+        Ok(point)
+    }
+}
@@ -0,0 +1,347 @@
+//! A native verifier for the gnark PLONK proofs over BN254 that wrap SP1 proofs.
+//!
+//! This follows the verifier gnark exports to Solidity, so it accepts the same proof encoding as
+//! the onchain verifier and does not need Go or Docker.
+
+mod encoding;
+mod transcript;
+
+use ark_bn254::{Bn254, Fr, G1Affine, G1Projective, G2Affine};
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{Field, One, Zero};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+use self::encoding::{decode_proof, decode_verifying_key, field_from_biguint};
+use self::transcript::{hash_to_fr, reduce, Transcript};
+use crate::{PlonkBn254Proof, PlonkVerificationError};
+
+/// Verifies a [PlonkBn254Proof] against the verifying key of the circuit it was generated with and
+/// the public inputs of the circuit: the hash of the SP1 verifying key and the digest of the
+/// committed public values.
+///
+/// `vk` is the gnark encoding of the verifying key, i.e. the `vk.bin` of the circuit artifacts.
+pub fn verify_plonk_bn254(
+    proof: &PlonkBn254Proof,
+    vkey_hash: &BigUint,
+    committed_values_digest: &BigUint,
+    vk: &[u8],
+) -> Result<(), PlonkVerificationError> {
+    let vk_hash: [u8; 32] = Sha256::digest(vk).into();
+    if vk_hash != proof.plonk_vkey_hash {
+        return Err(PlonkVerificationError::PlonkVerifyingKeyMismatch);
+    }
+
+    let vk = decode_verifying_key(vk).map_err(PlonkVerificationError::MalformedVerifyingKey)?;
+    let proof = hex::decode(&proof.encoded_proof)
+        .map_err(|_| PlonkVerificationError::MalformedProof("invalid hex"))
+        .and_then(|bytes| {
+            decode_proof(&bytes, vk.qcp.len()).map_err(PlonkVerificationError::MalformedProof)
+        })?;
+    let public_inputs = [vkey_hash, committed_values_digest]
+        .into_iter()
+        .map(field_from_biguint)
+        .collect::<Option<Vec<_>>>()
+        .ok_or(PlonkVerificationError::InvalidPublicInputs)?;
+
+    verify(&vk, &proof, &public_inputs)
+}
+
+/// The verifying key of a PLONK circuit with BSB22 commitments.
+struct PlonkVerifyingKey {
+    /// The size of the evaluation domain.
+    size: u64,
+    size_inv: Fr,
+    /// The generator of the evaluation domain.
+    generator: Fr,
+    nb_public_variables: u64,
+    /// The shift of the cosets of the permutation argument.
+    coset_shift: Fr,
+    /// The commitments to the permutation polynomials.
+    s: [G1Affine; 3],
+    ql: G1Affine,
+    qr: G1Affine,
+    qm: G1Affine,
+    qo: G1Affine,
+    qk: G1Affine,
+    /// The commitments to the selectors of the BSB22 commitment gates.
+    qcp: Vec<G1Affine>,
+    /// The KZG setup points `[1]₁`, `[1]₂` and `[τ]₂`.
+    g1: G1Affine,
+    g2: [G2Affine; 2],
+    commitment_constraint_indexes: Vec<u64>,
+}
+
+/// A PLONK proof, with the openings of the polynomials at ζ and ζω.
+struct PlonkProof {
+    /// The commitments to the wire polynomials.
+    lro: [G1Affine; 3],
+    /// The commitments to the three parts of the quotient polynomial.
+    h: [G1Affine; 3],
+    l_at_zeta: Fr,
+    r_at_zeta: Fr,
+    o_at_zeta: Fr,
+    s1_at_zeta: Fr,
+    s2_at_zeta: Fr,
+    /// The commitment to the grand product polynomial.
+    z: G1Affine,
+    z_at_zeta_omega: Fr,
+    batch_opening_at_zeta: G1Affine,
+    opening_at_zeta_omega: G1Affine,
+    qcp_at_zeta: Vec<Fr>,
+    bsb22_commitments: Vec<G1Affine>,
+}
+
+fn verify(
+    vk: &PlonkVerifyingKey,
+    proof: &PlonkProof,
+    public_inputs: &[Fr],
+) -> Result<(), PlonkVerificationError> {
+    if public_inputs.len() as u64 != vk.nb_public_variables {
+        return Err(PlonkVerificationError::InvalidPublicInputs);
+    }
+
+    // Derive the challenges, each binding the previous one before reduction.
+    let mut transcript = Transcript::new("gamma");
+    for commitment in vk.s.iter().chain([&vk.ql, &vk.qr, &vk.qm, &vk.qo, &vk.qk]) {
+        transcript.append_g1(commitment);
+    }
+    vk.qcp.iter().for_each(|c| transcript.append_g1(c));
+    public_inputs.iter().for_each(|x| transcript.append_fr(x));
+    proof.lro.iter().for_each(|c| transcript.append_g1(c));
+    let gamma_challenge = transcript.challenge();
+    let gamma = reduce(&gamma_challenge);
+
+    let mut transcript = Transcript::new("beta");
+    transcript.append_bytes(&gamma_challenge);
+    let beta_challenge = transcript.challenge();
+    let beta = reduce(&beta_challenge);
+
+    let mut transcript = Transcript::new("alpha");
+    transcript.append_bytes(&beta_challenge);
+    proof
+        .bsb22_commitments
+        .iter()
+        .for_each(|c| transcript.append_g1(c));
+    transcript.append_g1(&proof.z);
+    let alpha_challenge = transcript.challenge();
+    let alpha = reduce(&alpha_challenge);
+
+    let mut transcript = Transcript::new("zeta");
+    transcript.append_bytes(&alpha_challenge);
+    proof.h.iter().for_each(|c| transcript.append_g1(c));
+    let zeta = reduce(&transcript.challenge());
+
+    // Lᵢ(ζ) = ωⁱ/n * (ζⁿ-1)/(ζ-ωⁱ)
+    let zeta_power_n = zeta.pow([vk.size]);
+    let zh_zeta = zeta_power_n - Fr::one();
+    let lagrange = |i: u64| -> Result<Fr, PlonkVerificationError> {
+        let omega_i = vk.generator.pow([i]);
+        let den = (zeta - omega_i)
+            .inverse()
+            .ok_or(PlonkVerificationError::InvalidProof)?;
+        Ok(omega_i * vk.size_inv * zh_zeta * den)
+    };
+
+    // The public inputs, including the hashes of the BSB22 commitments.
+    let mut pi = Fr::zero();
+    for (i, x) in public_inputs.iter().enumerate() {
+        pi += lagrange(i as u64)? * x;
+    }
+    for (commitment, index) in proof
+        .bsb22_commitments
+        .iter()
+        .zip(&vk.commitment_constraint_indexes)
+    {
+        pi += hash_to_fr(commitment) * lagrange(vk.nb_public_variables + index)?;
+    }
+
+    let alpha_square_lagrange_0 = alpha.square() * lagrange(0)?;
+
+    let (l, r, o) = (proof.l_at_zeta, proof.r_at_zeta, proof.o_at_zeta);
+    let (s1, s2) = (proof.s1_at_zeta, proof.s2_at_zeta);
+    let z_omega = proof.z_at_zeta_omega;
+
+    // The opening of the linearised polynomial at ζ:
+    // -[PI(ζ) - α²L₁(ζ) + α(l(ζ)+βs₁(ζ)+γ)(r(ζ)+βs₂(ζ)+γ)(o(ζ)+γ)z(ζω)]
+    let opening_linearised = -(pi - alpha_square_lagrange_0
+        + alpha * (l + beta * s1 + gamma) * (r + beta * s2 + gamma) * (o + gamma) * z_omega);
+
+    // -(ζⁿ-1)([H₀] + ζⁿ⁺²[H₁] + ζ²⁽ⁿ⁺²⁾[H₂])
+    let zeta_power_n_plus_two = zeta_power_n * zeta.square();
+    let folded_h = ((proof.h[2] * zeta_power_n_plus_two + proof.h[1]) * zeta_power_n_plus_two
+        + proof.h[0])
+        * (-zh_zeta);
+
+    // The commitment to the linearised polynomial:
+    // l(ζ)[Qₗ] + r(ζ)[Qᵣ] + l(ζ)r(ζ)[Qₘ] + o(ζ)[Qₒ] + [Qₖ] + Σᵢqcpᵢ(ζ)[BSB22ᵢ]
+    // + αβz(ζω)(l(ζ)+βs₁(ζ)+γ)(r(ζ)+βs₂(ζ)+γ)[S₃]
+    // - (α(l(ζ)+βζ+γ)(r(ζ)+βuζ+γ)(o(ζ)+βu²ζ+γ) - α²L₁(ζ))[Z] + folded_h
+    let u = vk.coset_shift;
+    let beta_zeta = beta * zeta;
+    let s3_coeff = alpha * beta * z_omega * (l + beta * s1 + gamma) * (r + beta * s2 + gamma);
+    let z_coeff = alpha_square_lagrange_0
+        - alpha
+            * (l + beta_zeta + gamma)
+            * (r + beta_zeta * u + gamma)
+            * (o + beta_zeta * u.square() + gamma);
+    let mut linearised = vk.ql * l + vk.qr * r + vk.qm * (l * r) + vk.qo * o + vk.qk + folded_h;
+    for (commitment, opening) in proof.bsb22_commitments.iter().zip(&proof.qcp_at_zeta) {
+        linearised += *commitment * opening;
+    }
+    linearised += vk.s[2] * s3_coeff + proof.z * z_coeff;
+    let linearised = linearised.into_affine();
+
+    // Fold the openings at ζ with a challenge binding the digests and the claimed values.
+    let digests = [proof.lro[0], proof.lro[1], proof.lro[2], vk.s[0], vk.s[1]]
+        .into_iter()
+        .chain(vk.qcp.iter().copied())
+        .collect::<Vec<_>>();
+    let claimed_values = [l, r, o, s1, s2]
+        .into_iter()
+        .chain(proof.qcp_at_zeta.iter().copied())
+        .collect::<Vec<_>>();
+
+    let mut transcript = Transcript::new("gamma");
+    transcript.append_fr(&zeta);
+    transcript.append_g1(&linearised);
+    digests.iter().for_each(|d| transcript.append_g1(d));
+    transcript.append_fr(&opening_linearised);
+    claimed_values.iter().for_each(|v| transcript.append_fr(v));
+    transcript.append_fr(&z_omega);
+    let gamma_kzg = reduce(&transcript.challenge());
+
+    let mut folded_digest = G1Projective::from(linearised);
+    let mut folded_claimed_value = opening_linearised;
+    let mut acc_gamma = gamma_kzg;
+    for (digest, value) in digests.iter().zip(&claimed_values) {
+        folded_digest += *digest * acc_gamma;
+        folded_claimed_value += acc_gamma * value;
+        acc_gamma *= gamma_kzg;
+    }
+    let folded_digest = folded_digest.into_affine();
+
+    // Batch the KZG openings at ζ and ζω with a random combination.
+    let mut transcript = Transcript::default();
+    transcript.append_g1(&folded_digest);
+    transcript.append_g1(&proof.batch_opening_at_zeta);
+    transcript.append_g1(&proof.z);
+    transcript.append_g1(&proof.opening_at_zeta_omega);
+    transcript.append_fr(&zeta);
+    transcript.append_fr(&gamma_kzg);
+    let random = reduce(&transcript.challenge());
+
+    let zeta_omega = zeta * vk.generator;
+    let folded_quotients = proof.batch_opening_at_zeta + proof.opening_at_zeta_omega * random;
+    let folded_digests = folded_digest + proof.z * random
+        - vk.g1 * (folded_claimed_value + random * z_omega)
+        + proof.batch_opening_at_zeta * zeta
+        + proof.opening_at_zeta_omega * (random * zeta_omega);
+
+    // e(folded_digests, [1]₂) * e(-folded_quotients, [τ]₂) == 1
+    let result = Bn254::multi_pairing(
+        [
+            folded_digests.into_affine(),
+            (-folded_quotients).into_affine(),
+        ],
+        [vk.g2[0], vk.g2[1]],
+    );
+    if !result.0.is_one() {
+        return Err(PlonkVerificationError::InvalidProof);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core::io::SP1PublicValues;
+
+    use super::*;
+    use crate::verify::PLONK_VK_BYTES;
+
+    /// A proof of the fibonacci example for this circuit version, as verified by its Solidity
+    /// verifier. The proof bytes are the first four bytes of the verifier hash followed by the
+    /// encoded proof.
+    const FIXTURE: &str =
+        include_str!("../../../examples/fibonacci/contracts/src/fixtures/fixture.json");
+
+    fn fixture() -> (PlonkBn254Proof, BigUint, BigUint) {
+        let fixture: serde_json::Value = serde_json::from_str(FIXTURE).unwrap();
+        let decode = |key: &str| hex::decode(&fixture[key].as_str().unwrap()[2..]).unwrap();
+
+        let proof_bytes = decode("proof");
+        let plonk_vkey_hash: [u8; 32] = Sha256::digest(PLONK_VK_BYTES).into();
+        assert_eq!(proof_bytes[..4], plonk_vkey_hash[..4]);
+
+        let vkey_hash = BigUint::from_bytes_be(&decode("vkey"));
+        let public_values = SP1PublicValues::from(&decode("publicValues"));
+        let committed_values_digest = public_values.hash();
+        let proof = PlonkBn254Proof {
+            public_inputs: [vkey_hash.to_string(), committed_values_digest.to_string()],
+            encoded_proof: hex::encode(&proof_bytes[4..]),
+            raw_proof: String::new(),
+            plonk_vkey_hash,
+        };
+        (proof, vkey_hash, committed_values_digest)
+    }
+
+    #[test]
+    fn test_verify_plonk_bn254() {
+        let (proof, vkey_hash, committed_values_digest) = fixture();
+        verify_plonk_bn254(&proof, &vkey_hash, &committed_values_digest, PLONK_VK_BYTES).unwrap();
+    }
+
+    #[test]
+    fn test_verify_plonk_bn254_wrong_public_inputs() {
+        let (proof, vkey_hash, committed_values_digest) = fixture();
+        assert!(matches!(
+            verify_plonk_bn254(
+                &proof,
+                &vkey_hash,
+                &(committed_values_digest + 1u32),
+                PLONK_VK_BYTES
+            ),
+            Err(PlonkVerificationError::InvalidProof)
+        ));
+        assert!(matches!(
+            verify_plonk_bn254(
+                &proof,
+                &(vkey_hash + 1u32),
+                &BigUint::zero(),
+                PLONK_VK_BYTES
+            ),
+            Err(PlonkVerificationError::InvalidProof)
+        ));
+    }
+
+    #[test]
+    fn test_verify_plonk_bn254_tampered_proof() {
+        let (mut proof, vkey_hash, committed_values_digest) = fixture();
+        let mut bytes = hex::decode(&proof.encoded_proof).unwrap();
+        // Change the claimed opening of l at ζ.
+        bytes[0x180 + 31] ^= 1;
+        proof.encoded_proof = hex::encode(&bytes);
+        assert!(matches!(
+            verify_plonk_bn254(&proof, &vkey_hash, &committed_values_digest, PLONK_VK_BYTES),
+            Err(PlonkVerificationError::InvalidProof)
+        ));
+
+        proof.encoded_proof = hex::encode(&bytes[1..]);
+        assert!(matches!(
+            verify_plonk_bn254(&proof, &vkey_hash, &committed_values_digest, PLONK_VK_BYTES),
+            Err(PlonkVerificationError::MalformedProof(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_plonk_bn254_wrong_vk() {
+        let (proof, vkey_hash, committed_values_digest) = fixture();
+        let mut vk = PLONK_VK_BYTES.to_vec();
+        vk[0] ^= 1;
+        assert!(matches!(
+            verify_plonk_bn254(&proof, &vkey_hash, &committed_values_digest, &vk),
+            Err(PlonkVerificationError::PlonkVerifyingKeyMismatch)
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+//! The Fiat-Shamir transcript and hash to field of gnark's BN254 PLONK verifier.
+
+use ark_bn254::{Fr, G1Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use sha2::{Digest, Sha256};
+
+/// The domain separation tag gnark uses to hash the BSB22 commitments into the public inputs.
+const BSB22_DST: &[u8] = b"BSB22-Plonk";
+
+/// A SHA-256 transcript, where each challenge hashes its name and the values bound to it.
+#[derive(Default)]
+pub(super) struct Transcript {
+    hasher: Sha256,
+}
+
+impl Transcript {
+    pub(super) fn new(name: &str) -> Self {
+        let mut transcript = Self::default();
+        transcript.append_bytes(name.as_bytes());
+        transcript
+    }
+
+    pub(super) fn append_bytes(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+
+    pub(super) fn append_fr(&mut self, value: &Fr) {
+        self.hasher.update(fr_to_bytes(value));
+    }
+
+    pub(super) fn append_g1(&mut self, point: &G1Affine) {
+        self.hasher.update(g1_to_bytes(point));
+    }
+
+    /// Returns the challenge before reduction, which is bound to the next challenge.
+    pub(super) fn challenge(self) -> [u8; 32] {
+        self.hasher.finalize().into()
+    }
+}
+
+/// Reduces a challenge into the scalar field.
+pub(super) fn reduce(challenge: &[u8; 32]) -> Fr {
+    Fr::from_be_bytes_mod_order(challenge)
+}
+
+/// Hashes a BSB22 commitment into the scalar field with `expand_message_xmd` (RFC 9380).
+pub(super) fn hash_to_fr(point: &G1Affine) -> Fr {
+    // ℓ = 48 bytes, i.e. two SHA-256 blocks.
+    const LEN_IN_BYTES: u8 = 48;
+    let dst_prime = [BSB22_DST, &[BSB22_DST.len() as u8]].concat();
+
+    let b0 = Sha256::new()
+        .chain_update([0u8; 64])
+        .chain_update(g1_to_bytes(point))
+        .chain_update([0, LEN_IN_BYTES, 0])
+        .chain_update(&dst_prime)
+        .finalize();
+    let b1 = Sha256::new()
+        .chain_update(b0)
+        .chain_update([1])
+        .chain_update(&dst_prime)
+        .finalize();
+    let b0_xor_b1: Vec<u8> = b0.iter().zip(b1.iter()).map(|(a, b)| a ^ b).collect();
+    let b2 = Sha256::new()
+        .chain_update(b0_xor_b1)
+        .chain_update([2])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let uniform_bytes = [&b1[..], &b2[..16]].concat();
+    Fr::from_be_bytes_mod_order(&uniform_bytes)
+}
+
+fn fr_to_bytes(value: &Fr) -> Vec<u8> {
+    value.into_bigint().to_bytes_be()
+}
+
+/// The EVM encoding of a G1 point, `x || y` with `(0, 0)` for the point at infinity.
+fn g1_to_bytes(point: &G1Affine) -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    if let Some((x, y)) = point.xy() {
+        bytes[..32].copy_from_slice(&x.into_bigint().to_bytes_be());
+        bytes[32..].copy_from_slice(&y.into_bigint().to_bytes_be());
+    }
+    bytes
+}
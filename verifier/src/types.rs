@@ -0,0 +1,106 @@
+use p3_baby_bear::BabyBear;
+use p3_bn254_fr::Bn254Fr;
+use p3_commit::{Pcs, TwoAdicMultiplicativeCoset};
+use p3_field::{AbstractField, PrimeField, PrimeField32, TwoAdicField};
+use serde::{Deserialize, Serialize};
+use sp1_core::{
+    stark::{StarkGenericConfig, StarkVerifyingKey},
+    utils::DIGEST_SIZE,
+};
+use sp1_primitives::poseidon2_hash;
+
+use crate::utils::{babybears_to_bn254, words_to_bytes_be};
+use crate::CoreSC;
+
+/// The information necessary to verify a proof for a given RISC-V program.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SP1VerifyingKey {
+    pub vk: StarkVerifyingKey<CoreSC>,
+}
+
+/// A trait for keys that can be hashed into a digest.
+pub trait HashableKey {
+    /// Hash the key into a digest of BabyBear elements.
+    fn hash_babybear(&self) -> [BabyBear; DIGEST_SIZE];
+
+    /// Hash the key into a digest of  u32 elements.
+    fn hash_u32(&self) -> [u32; DIGEST_SIZE];
+
+    fn hash_bn254(&self) -> Bn254Fr {
+        babybears_to_bn254(&self.hash_babybear())
+    }
+
+    fn bytes32(&self) -> String {
+        let vkey_digest_bn254 = self.hash_bn254();
+        format!(
+            "0x{:0>64}",
+            vkey_digest_bn254.as_canonical_biguint().to_str_radix(16)
+        )
+    }
+
+    /// Hash the key into a digest of bytes elements.
+    fn hash_bytes(&self) -> [u8; DIGEST_SIZE * 4] {
+        words_to_bytes_be(&self.hash_u32())
+    }
+}
+
+impl HashableKey for SP1VerifyingKey {
+    fn hash_babybear(&self) -> [BabyBear; DIGEST_SIZE] {
+        self.vk.hash_babybear()
+    }
+
+    fn hash_u32(&self) -> [u32; DIGEST_SIZE] {
+        self.vk.hash_u32()
+    }
+}
+
+impl<SC: StarkGenericConfig<Val = BabyBear, Domain = TwoAdicMultiplicativeCoset<BabyBear>>>
+    HashableKey for StarkVerifyingKey<SC>
+where
+    <SC::Pcs as Pcs<SC::Challenge, SC::Challenger>>::Commitment: AsRef<[BabyBear; DIGEST_SIZE]>,
+{
+    fn hash_babybear(&self) -> [BabyBear; DIGEST_SIZE] {
+        let prep_domains = self.chip_information.iter().map(|(_, domain, _)| domain);
+        let num_inputs = DIGEST_SIZE + 1 + (4 * prep_domains.len());
+        let mut inputs = Vec::with_capacity(num_inputs);
+        inputs.extend(self.commit.as_ref());
+        inputs.push(self.pc_start);
+        for domain in prep_domains {
+            inputs.push(BabyBear::from_canonical_usize(domain.log_n));
+            let size = 1 << domain.log_n;
+            inputs.push(BabyBear::from_canonical_usize(size));
+            let g = BabyBear::two_adic_generator(domain.log_n);
+            inputs.push(domain.shift);
+            inputs.push(g);
+        }
+
+        poseidon2_hash(inputs)
+    }
+
+    fn hash_u32(&self) -> [u32; 8] {
+        self.hash_babybear()
+            .into_iter()
+            .map(|n| n.as_canonical_u32())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+}
+
+/// A zero-knowledge proof generated by the PLONK protocol with a Base64 encoded gnark PLONK proof.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PlonkBn254Proof {
+    pub public_inputs: [String; 2],
+    pub encoded_proof: String,
+    pub raw_proof: String,
+    pub plonk_vkey_hash: [u8; 32],
+}
+
+/// A zero-knowledge proof generated by the Groth16 protocol with a Base64 encoded gnark Groth16 proof.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Groth16Bn254Proof {
+    pub public_inputs: [String; 2],
+    pub encoded_proof: String,
+    pub raw_proof: String,
+    pub groth16_vkey_hash: [u8; 32],
+}
@@ -0,0 +1,675 @@
+use std::{borrow::Borrow, str::FromStr};
+
+use anyhow::Result;
+use num_bigint::BigUint;
+use p3_baby_bear::BabyBear;
+use p3_field::{AbstractField, PrimeField};
+use sp1_core::air::{Word, POSEIDON_NUM_WORDS, PV_DIGEST_NUM_WORDS, WORD_SIZE};
+use sp1_core::cpu::MAX_CPU_LOG_DEGREE;
+use sp1_core::stark::{RiscvAir, ShardProof, StarkMachine, StarkVerifyingKey};
+use sp1_core::{
+    air::PublicValues,
+    io::SP1PublicValues,
+    stark::{MachineProof, MachineVerificationError, StarkGenericConfig},
+};
+use sp1_recursion_core::air::RecursionPublicValues;
+use thiserror::Error;
+
+use crate::plonk::verify_plonk_bn254;
+use crate::{
+    CoreSC, HashableKey, InnerSC, ReduceAir, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues,
+    SP1VerifyingKey, SP1_CIRCUIT_VERSION,
+};
+
+/// The verifying key of the compress program that the embedded compressed proofs are checked
+/// against, serialized with bincode.
+///
+/// Regenerate with `cargo run --release -p sp1-prover --bin build_compress_vk` whenever the
+/// recursion programs change.
+const COMPRESS_VK_BYTES: &[u8] = include_bytes!("../vk/compress_vk.bin");
+
+/// The gnark verifying key of the PLONK circuit of [SP1_CIRCUIT_VERSION], i.e. the `vk.bin` of its
+/// circuit artifacts, whose SHA-256 hash is the `VERIFIER_HASH` of the onchain verifier.
+pub(crate) const PLONK_VK_BYTES: &[u8] = include_bytes!("../vk/plonk_vk.bin");
+
+#[derive(Error, Debug)]
+pub enum SP1VerificationError {
+    #[error("Version mismatch")]
+    VersionMismatch(String),
+    #[error("Core machine verification error: {0}")]
+    Core(MachineVerificationError<CoreSC>),
+    #[error("Recursion verification error: {0}")]
+    Recursion(MachineVerificationError<InnerSC>),
+    #[error("Plonk verification error: {0}")]
+    Plonk(anyhow::Error),
+    #[error("Groth16 verification error: {0}")]
+    Groth16(anyhow::Error),
+    #[error("{0:?} proofs are not supported by this verifier")]
+    Unsupported(SP1ProofKind),
+    #[error("Invalid encoding: {0}")]
+    InvalidEncoding(#[from] bincode::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum PlonkVerificationError {
+    #[error(
+        "the verifying key does not match the inner plonk bn254 proof's committed verifying key"
+    )]
+    InvalidVerificationKey,
+    #[error(
+        "the public values in the sp1 proof do not match the public values in the inner plonk bn254 proof"
+    )]
+    InvalidPublicValues,
+    #[error("the plonk bn254 proof was generated with a different plonk verifying key")]
+    PlonkVerifyingKeyMismatch,
+    #[error("malformed plonk verifying key: {0}")]
+    MalformedVerifyingKey(&'static str),
+    #[error("malformed plonk bn254 proof: {0}")]
+    MalformedProof(&'static str),
+    #[error("the public inputs are not valid for the plonk bn254 circuit")]
+    InvalidPublicInputs,
+    #[error("the plonk bn254 proof is invalid")]
+    InvalidProof,
+}
+
+#[derive(Error, Debug)]
+pub enum Groth16VerificationError {
+    #[error(
+        "the verifying key does not match the inner groth16 bn254 proof's committed verifying key"
+    )]
+    InvalidVerificationKey,
+    #[error(
+        "the public values in the sp1 proof do not match the public values in the inner groth16 bn254 proof"
+    )]
+    InvalidPublicValues,
+}
+
+/// A verifier for [SP1ProofWithPublicValues] which does not depend on the prover.
+pub struct SP1Verifier {
+    core_machine: StarkMachine<CoreSC, RiscvAir<BabyBear>>,
+    compress_machine: StarkMachine<InnerSC, ReduceAir<BabyBear>>,
+    compress_vk: StarkVerifyingKey<InnerSC>,
+}
+
+impl SP1Verifier {
+    /// Creates a new [SP1Verifier] using the compress verifying key of this SP1 version.
+    pub fn new() -> Self {
+        let compress_vk =
+            bincode::deserialize(COMPRESS_VK_BYTES).expect("invalid compress verifying key");
+        Self {
+            core_machine: RiscvAir::machine(CoreSC::default()),
+            compress_machine: ReduceAir::machine(InnerSC::default()),
+            compress_vk,
+        }
+    }
+
+    /// The verifying key of the compress program that compressed proofs are checked against.
+    pub fn compress_vk(&self) -> &StarkVerifyingKey<InnerSC> {
+        &self.compress_vk
+    }
+
+    /// Verify that an SP1 proof is valid given its vkey and metadata.
+    ///
+    /// Plonk proofs are checked against the PLONK circuit of [SP1_CIRCUIT_VERSION]. Proofs from
+    /// locally built circuits can be checked with [crate::plonk::verify_plonk_bn254] and the
+    /// `vk.bin` of their artifacts. Groth16 proofs are not supported yet and need the prover's
+    /// gnark bindings.
+    pub fn verify(
+        &self,
+        bundle: &SP1ProofWithPublicValues,
+        vkey: &SP1VerifyingKey,
+    ) -> Result<(), SP1VerificationError> {
+        if bundle.sp1_version != SP1_CIRCUIT_VERSION {
+            return Err(SP1VerificationError::VersionMismatch(
+                bundle.sp1_version.clone(),
+            ));
+        }
+        match &bundle.proof {
+            SP1Proof::Core(proof) => verify_core_proof(&self.core_machine, proof, vkey)
+                .map_err(SP1VerificationError::Core),
+            SP1Proof::Compressed(proof) => {
+                verify_compressed_proof(&self.compress_machine, &self.compress_vk, proof, vkey)
+                    .map_err(SP1VerificationError::Recursion)
+            }
+            SP1Proof::Plonk(proof) => {
+                verify_plonk_bn254_public_inputs(vkey, &bundle.public_values, &proof.public_inputs)
+                    .and_then(|()| {
+                        let vkey_hash = BigUint::from_str(&proof.public_inputs[0])?;
+                        let committed_values_digest = BigUint::from_str(&proof.public_inputs[1])?;
+                        verify_plonk_bn254(
+                            proof,
+                            &vkey_hash,
+                            &committed_values_digest,
+                            PLONK_VK_BYTES,
+                        )?;
+                        Ok(())
+                    })
+                    .map_err(SP1VerificationError::Plonk)
+            }
+            SP1Proof::Groth16(_) => Err(SP1VerificationError::Unsupported(SP1ProofKind::Groth16)),
+        }
+    }
+
+    /// Verify a compressed proof given the bincode encodings of its [SP1ProofWithPublicValues]
+    /// and [SP1VerifyingKey].
+    ///
+    /// This path does not touch the filesystem, so it can be used from `wasm32-unknown-unknown`.
+    pub fn verify_compressed_bytes(
+        &self,
+        proof: &[u8],
+        vk: &[u8],
+    ) -> Result<(), SP1VerificationError> {
+        let bundle: SP1ProofWithPublicValues = bincode::deserialize(proof)?;
+        let vk: SP1VerifyingKey = bincode::deserialize(vk)?;
+        match SP1ProofKind::from(&bundle.proof) {
+            SP1ProofKind::Compressed => self.verify(&bundle, &vk),
+            kind => Err(SP1VerificationError::Unsupported(kind)),
+        }
+    }
+}
+
+/// Verify a core proof by verifying the shards, verifying lookup bus, verifying that the
+/// shards are contiguous and complete.
+pub fn verify_core_proof(
+    machine: &StarkMachine<CoreSC, RiscvAir<BabyBear>>,
+    proof: &[ShardProof<CoreSC>],
+    vk: &SP1VerifyingKey,
+) -> Result<(), MachineVerificationError<CoreSC>> {
+    // First shard has a "CPU" constraint.
+    //
+    // Assert that the first shard has a "CPU".
+    let first_shard = proof.first().ok_or(MachineVerificationError::EmptyProof)?;
+    if !first_shard.contains_cpu() {
+        return Err(MachineVerificationError::MissingCpuInFirstShard);
+    }
+
+    // CPU log degree bound constraints.
+    //
+    // Assert that the CPU log degree does not exceed `MAX_CPU_LOG_DEGREE`. This is to ensure
+    // that the lookup argument's multiplicities do not overflow.
+    for shard_proof in proof.iter() {
+        if shard_proof.contains_cpu() {
+            let log_degree_cpu = shard_proof.log_degree_cpu();
+            if log_degree_cpu > MAX_CPU_LOG_DEGREE {
+                return Err(MachineVerificationError::CpuLogDegreeTooLarge(
+                    log_degree_cpu,
+                ));
+            }
+        }
+    }
+
+    // Shard constraints.
+    //
+    // Initialization:
+    // - Shard should start at one.
+    //
+    // Transition:
+    // - Shard should increment by one for each shard.
+    let mut current_shard = BabyBear::zero();
+    for shard_proof in proof.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        current_shard += BabyBear::one();
+        if public_values.shard != current_shard {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "shard index should be the previous shard index + 1 and start at 1",
+            ));
+        }
+    }
+
+    // Execution shard constraints.
+    //
+    // Initialization:
+    // - Execution shard should start at one.
+    //
+    // Transition:
+    // - Execution shard should increment by one for each shard with "CPU".
+    // - Execution shard should stay the same for non-CPU shards.
+    // - For the other shards, execution shard does not matter.
+    let mut current_execution_shard = BabyBear::zero();
+    for shard_proof in proof.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if shard_proof.contains_cpu() {
+            current_execution_shard += BabyBear::one();
+            if public_values.execution_shard != current_execution_shard {
+                return Err(MachineVerificationError::InvalidPublicValues(
+                    "execution shard index should be the previous execution shard index + 1 if cpu exists and start at 1",
+                ));
+            }
+        }
+    }
+
+    // Program counter constraints.
+    //
+    // Initialization:
+    // - `start_pc` should start as `vk.start_pc`.
+    //
+    // Transition:
+    // - `next_pc` of the previous shard should equal `start_pc`.
+    // - If it's not a shard with "CPU", then `start_pc` equals `next_pc`.
+    // - If it's a shard with "CPU", then `start_pc` should never equal zero.
+    //
+    // Finalization:
+    // - `next_pc` should equal zero.
+    let mut prev_next_pc = BabyBear::zero();
+    for (i, shard_proof) in proof.iter().enumerate() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if i == 0 && public_values.start_pc != vk.vk.pc_start {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "start_pc != vk.start_pc: program counter should start at vk.start_pc",
+            ));
+        } else if i != 0 && public_values.start_pc != prev_next_pc {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "start_pc != next_pc_prev: start_pc should equal next_pc_prev for all shards",
+            ));
+        } else if !shard_proof.contains_cpu() && public_values.start_pc != public_values.next_pc {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "start_pc != next_pc: start_pc should equal next_pc for non-cpu shards",
+            ));
+        } else if shard_proof.contains_cpu() && public_values.start_pc == BabyBear::zero() {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "start_pc == 0: execution should never start at halted state",
+            ));
+        } else if i == proof.len() - 1 && public_values.next_pc != BabyBear::zero() {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "next_pc != 0: execution should have halted",
+            ));
+        }
+        prev_next_pc = public_values.next_pc;
+    }
+
+    // Exit code constraints.
+    //
+    // - In every shard, the exit code should be zero.
+    for shard_proof in proof.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if public_values.exit_code != BabyBear::zero() {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "exit_code != 0: exit code should be zero for all shards",
+            ));
+        }
+    }
+
+    // Memory initialization & finalization constraints.
+    //
+    // Initialization:
+    // - `previous_init_addr_bits` should be zero.
+    // - `previous_finalize_addr_bits` should be zero.
+    //
+    // Transition:
+    // - For all shards, `previous_init_addr_bits` should equal `last_init_addr_bits` of the previous shard.
+    // - For all shards, `previous_finalize_addr_bits` should equal `last_finalize_addr_bits` of the previous shard.
+    // - For shards without "MemoryInit", `previous_init_addr_bits` should equal `last_init_addr_bits`.
+    // - For shards without "MemoryFinalize", `previous_finalize_addr_bits` should equal `last_finalize_addr_bits`.
+    let mut last_init_addr_bits_prev = [BabyBear::zero(); 32];
+    let mut last_finalize_addr_bits_prev = [BabyBear::zero(); 32];
+    for shard_proof in proof.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if public_values.previous_init_addr_bits != last_init_addr_bits_prev {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "previous_init_addr_bits != last_init_addr_bits_prev",
+            ));
+        } else if public_values.previous_finalize_addr_bits != last_finalize_addr_bits_prev {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "last_init_addr_bits != last_finalize_addr_bits_prev",
+            ));
+        } else if !shard_proof.contains_memory_init()
+            && public_values.previous_init_addr_bits != public_values.last_init_addr_bits
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "previous_init_addr_bits != last_init_addr_bits",
+            ));
+        } else if !shard_proof.contains_memory_finalize()
+            && public_values.previous_finalize_addr_bits != public_values.last_finalize_addr_bits
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "previous_finalize_addr_bits != last_finalize_addr_bits",
+            ));
+        }
+        last_init_addr_bits_prev = public_values.last_init_addr_bits;
+        last_finalize_addr_bits_prev = public_values.last_finalize_addr_bits;
+    }
+
+    // Digest constraints.
+    //
+    // Initialization:
+    // - `committed_value_digest` should be zero.
+    // - `deferred_proofs_digest` should be zero.
+    //
+    // Transition:
+    // - If `commited_value_digest_prev` is not zero, then `committed_value_digest` should equal
+    //  `commited_value_digest_prev`. Otherwise, `committed_value_digest` should equal zero.
+    // - If `deferred_proofs_digest_prev` is not zero, then `deferred_proofs_digest` should equal
+    //  `deferred_proofs_digest_prev`. Otherwise, `deferred_proofs_digest` should equal zero.
+    // - If it's not a shard with "CPU", then `commited_value_digest` should not change from the
+    //  previous shard.
+    // - If it's not a shard with "CPU", then `deferred_proofs_digest` should not change from the
+    //  previous shard.
+    let zero_commited_value_digest = [Word([BabyBear::zero(); WORD_SIZE]); PV_DIGEST_NUM_WORDS];
+    let zero_deferred_proofs_digest = [BabyBear::zero(); POSEIDON_NUM_WORDS];
+    let mut commited_value_digest_prev = zero_commited_value_digest;
+    let mut deferred_proofs_digest_prev = zero_deferred_proofs_digest;
+    for shard_proof in proof.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if commited_value_digest_prev != zero_commited_value_digest
+            && public_values.committed_value_digest != commited_value_digest_prev
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "committed_value_digest != commited_value_digest_prev",
+            ));
+        } else if deferred_proofs_digest_prev != zero_deferred_proofs_digest
+            && public_values.deferred_proofs_digest != deferred_proofs_digest_prev
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "deferred_proofs_digest != deferred_proofs_digest_prev",
+            ));
+        } else if !shard_proof.contains_cpu()
+            && public_values.committed_value_digest != commited_value_digest_prev
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "committed_value_digest != commited_value_digest_prev",
+            ));
+        } else if !shard_proof.contains_cpu()
+            && public_values.deferred_proofs_digest != deferred_proofs_digest_prev
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "deferred_proofs_digest != deferred_proofs_digest_prev",
+            ));
+        }
+        commited_value_digest_prev = public_values.committed_value_digest;
+        deferred_proofs_digest_prev = public_values.deferred_proofs_digest;
+    }
+
+    // Verify that the number of shards is not too large.
+    if proof.len() > 1 << 16 {
+        return Err(MachineVerificationError::TooManyShards);
+    }
+
+    // Verify the shard proof.
+    let mut challenger = machine.config().challenger();
+    let machine_proof = MachineProof {
+        shard_proofs: proof.to_vec(),
+    };
+    machine.verify(&vk.vk, &machine_proof, &mut challenger)?;
+
+    Ok(())
+}
+
+/// Verify a compressed proof against the verifying key of the compress program.
+pub fn verify_compressed_proof(
+    machine: &StarkMachine<InnerSC, ReduceAir<BabyBear>>,
+    compress_vk: &StarkVerifyingKey<InnerSC>,
+    proof: &ShardProof<InnerSC>,
+    vk: &SP1VerifyingKey,
+) -> Result<(), MachineVerificationError<InnerSC>> {
+    let mut challenger = machine.config().challenger();
+    let machine_proof = MachineProof {
+        shard_proofs: vec![proof.clone()],
+    };
+    machine.verify(compress_vk, &machine_proof, &mut challenger)?;
+
+    // Validate public values
+    let public_values: &RecursionPublicValues<_> = proof.public_values.as_slice().borrow();
+
+    // `is_complete` should be 1. In the reduce program, this ensures that the proof is fully reduced.
+    if public_values.is_complete != BabyBear::one() {
+        return Err(MachineVerificationError::InvalidPublicValues(
+            "is_complete is not 1",
+        ));
+    }
+
+    // Verify that the proof is for the sp1 vkey we are expecting.
+    let vkey_hash = vk.hash_babybear();
+    if public_values.sp1_vk_digest != vkey_hash {
+        return Err(MachineVerificationError::InvalidPublicValues(
+            "sp1 vk hash mismatch",
+        ));
+    }
+
+    // Verify that the reduce program is the one we are expecting.
+    let recursion_vkey_hash = compress_vk.hash_babybear();
+    if public_values.compress_vk_digest != recursion_vkey_hash {
+        return Err(MachineVerificationError::InvalidPublicValues(
+            "recursion vk hash mismatch",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Verify the vk_hash and public_values_hash in the public inputs of the PlonkBn254Proof match the expected values.
+pub fn verify_plonk_bn254_public_inputs(
+    vk: &SP1VerifyingKey,
+    public_values: &SP1PublicValues,
+    plonk_bn254_public_inputs: &[String],
+) -> Result<()> {
+    let expected_vk_hash = BigUint::from_str(&plonk_bn254_public_inputs[0])?;
+    let expected_public_values_hash = BigUint::from_str(&plonk_bn254_public_inputs[1])?;
+
+    let vk_hash = vk.hash_bn254().as_canonical_biguint();
+    if vk_hash != expected_vk_hash {
+        return Err(PlonkVerificationError::InvalidVerificationKey.into());
+    }
+
+    let public_values_hash = public_values.hash();
+    if public_values_hash != expected_public_values_hash {
+        return Err(PlonkVerificationError::InvalidPublicValues.into());
+    }
+
+    Ok(())
+}
+
+/// Verify the vk_hash and public_values_hash in the public inputs of the Groth16Bn254Proof match the expected values.
+pub fn verify_groth16_bn254_public_inputs(
+    vk: &SP1VerifyingKey,
+    public_values: &SP1PublicValues,
+    groth16_bn254_public_inputs: &[String],
+) -> Result<()> {
+    let expected_vk_hash = BigUint::from_str(&groth16_bn254_public_inputs[0])?;
+    let expected_public_values_hash = BigUint::from_str(&groth16_bn254_public_inputs[1])?;
+
+    let vk_hash = vk.hash_bn254().as_canonical_biguint();
+    if vk_hash != expected_vk_hash {
+        return Err(Groth16VerificationError::InvalidVerificationKey.into());
+    }
+
+    let public_values_hash = public_values.hash();
+    if public_values_hash != expected_public_values_hash {
+        return Err(Groth16VerificationError::InvalidPublicValues.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core::runtime::{Program, SP1Context};
+    use sp1_core::utils::{setup_logger, SP1ProverOpts};
+    use sp1_prover::SP1Prover;
+
+    use super::*;
+    use crate::{PlonkBn254Proof, SP1Stdin};
+
+    const FIBONACCI_ELF: &[u8] =
+        include_bytes!("../../tests/fibonacci/elf/riscv32im-succinct-zkvm-elf");
+
+    fn setup(verifier: &SP1Verifier) -> SP1VerifyingKey {
+        let program = Program::from(FIBONACCI_ELF);
+        let (_, vk) = verifier.core_machine.setup(&program);
+        SP1VerifyingKey { vk }
+    }
+
+    fn plonk_bundle(public_inputs: [String; 2], sp1_version: &str) -> SP1ProofWithPublicValues {
+        SP1ProofWithPublicValues {
+            proof: SP1Proof::Plonk(PlonkBn254Proof {
+                public_inputs,
+                ..Default::default()
+            }),
+            stdin: Default::default(),
+            public_values: SP1PublicValues::from(&[1, 2, 3]),
+            sp1_version: sp1_version.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_verify_version_mismatch() {
+        let verifier = SP1Verifier::new();
+        let vk = setup(&verifier);
+        let bundle = plonk_bundle(Default::default(), "v0.0.0");
+        assert!(matches!(
+            verifier.verify(&bundle, &vk),
+            Err(SP1VerificationError::VersionMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_plonk_public_inputs() {
+        let verifier = SP1Verifier::new();
+        let vk = setup(&verifier);
+        let public_values = SP1PublicValues::from(&[1, 2, 3]);
+        let public_inputs = [
+            vk.hash_bn254().as_canonical_biguint().to_string(),
+            public_values.hash().to_string(),
+        ];
+
+        let plonk_error = |bundle| match verifier.verify(&bundle, &vk) {
+            Err(SP1VerificationError::Plonk(e)) => e.downcast::<PlonkVerificationError>().unwrap(),
+            _ => panic!("expected a plonk verification error"),
+        };
+
+        // The public inputs match, so the proof itself is checked.
+        let bundle = plonk_bundle(public_inputs.clone(), SP1_CIRCUIT_VERSION);
+        assert!(matches!(
+            plonk_error(bundle),
+            PlonkVerificationError::PlonkVerifyingKeyMismatch
+        ));
+
+        let bundle = plonk_bundle(
+            [public_inputs[0].clone(), "1".to_string()],
+            SP1_CIRCUIT_VERSION,
+        );
+        assert!(matches!(
+            plonk_error(bundle),
+            PlonkVerificationError::InvalidPublicValues
+        ));
+    }
+
+    #[test]
+    fn test_verify_groth16_public_inputs() {
+        let verifier = SP1Verifier::new();
+        let vk = setup(&verifier);
+        let public_values = SP1PublicValues::from(&[1, 2, 3]);
+        let vk_hash = vk.hash_bn254().as_canonical_biguint().to_string();
+
+        let public_inputs = [vk_hash.clone(), public_values.hash().to_string()];
+        verify_groth16_bn254_public_inputs(&vk, &public_values, &public_inputs).unwrap();
+
+        let public_inputs = [vk_hash, "1".to_string()];
+        let err = verify_groth16_bn254_public_inputs(&vk, &public_values, &public_inputs)
+            .unwrap_err()
+            .downcast::<Groth16VerificationError>()
+            .unwrap();
+        assert!(matches!(err, Groth16VerificationError::InvalidPublicValues));
+    }
+
+    #[test]
+    fn test_verify_core_empty_proof() {
+        let verifier = SP1Verifier::new();
+        let vk = setup(&verifier);
+        assert!(matches!(
+            verify_core_proof(&verifier.core_machine, &[], &vk),
+            Err(MachineVerificationError::EmptyProof)
+        ));
+    }
+
+    /// Tests that the embedded compress verifying key is the one derived by the prover, and that
+    /// core proofs of the prover pass the verifier.
+    #[test]
+    fn test_verify_prover_core_proof() {
+        setup_logger();
+        let prover: SP1Prover = SP1Prover::new();
+        let verifier = SP1Verifier::new();
+        assert_eq!(
+            verifier.compress_vk().hash_babybear(),
+            prover.compress_vk.hash_babybear(),
+            "run `cargo run --release -p sp1-prover --bin build_compress_vk` to update it"
+        );
+
+        let (pk, vk) = prover.setup(FIBONACCI_ELF);
+        let core_proof = prover
+            .prove_core(
+                &pk,
+                &SP1Stdin::new(),
+                SP1ProverOpts::default(),
+                SP1Context::default(),
+            )
+            .unwrap();
+        let bundle = SP1ProofWithPublicValues {
+            proof: SP1Proof::Core(core_proof.proof.0),
+            stdin: core_proof.stdin,
+            public_values: core_proof.public_values,
+            sp1_version: SP1_CIRCUIT_VERSION.to_string(),
+        };
+        verifier
+            .verify(&bundle, &SP1VerifyingKey { vk: vk.vk })
+            .unwrap();
+    }
+
+    /// Tests that a compressed proof of the prover passes the verifier from its bincode encoding,
+    /// and that tampering with its public values is rejected.
+    #[test]
+    fn test_verify_prover_compressed_proof() {
+        setup_logger();
+        let prover: SP1Prover = SP1Prover::new();
+        let verifier = SP1Verifier::new();
+
+        let opts = SP1ProverOpts::default();
+        let (pk, vk) = prover.setup(FIBONACCI_ELF);
+        let core_proof = prover
+            .prove_core(&pk, &SP1Stdin::new(), opts, SP1Context::default())
+            .unwrap();
+        let stdin = core_proof.stdin.clone();
+        let public_values = core_proof.public_values.clone();
+        let reduce_proof = prover.compress(&vk, core_proof, vec![], opts).unwrap();
+
+        let mut bundle = SP1ProofWithPublicValues {
+            proof: SP1Proof::Compressed(reduce_proof.proof),
+            stdin,
+            public_values,
+            sp1_version: SP1_CIRCUIT_VERSION.to_string(),
+        };
+        let vk_bytes = bincode::serialize(&SP1VerifyingKey { vk: vk.vk }).unwrap();
+        verifier
+            .verify_compressed_bytes(&bincode::serialize(&bundle).unwrap(), &vk_bytes)
+            .unwrap();
+
+        bundle.public_values = SP1PublicValues::from(&[1, 2, 3]);
+        assert!(verifier
+            .verify_compressed_bytes(&bincode::serialize(&bundle).unwrap(), &vk_bytes)
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify_compressed_bytes() {
+        let verifier = SP1Verifier::new();
+        let vk = setup(&verifier);
+        let vk_bytes = bincode::serialize(&vk).unwrap();
+
+        assert!(matches!(
+            verifier.verify_compressed_bytes(&[1, 2, 3], &vk_bytes),
+            Err(SP1VerificationError::InvalidEncoding(_))
+        ));
+
+        let bundle = plonk_bundle(Default::default(), SP1_CIRCUIT_VERSION);
+        let proof_bytes = bincode::serialize(&bundle).unwrap();
+        assert!(matches!(
+            verifier.verify_compressed_bytes(&proof_bytes, &vk_bytes),
+            Err(SP1VerificationError::Unsupported(SP1ProofKind::Plonk))
+        ));
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Executes the BLAKE2b compression function on the given chaining value and block.
+///
+/// The block is laid out as the 16 message words, followed by the two halves of the offset counter
+/// and the two finalization flags. The result is written back into `state`.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_blake2b_compress(state: *mut u64, block: *const u64) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLAKE2B_COMPRESS,
+            in("a0") state,
+            in("a1") block,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -1,4 +1,5 @@
 mod bigint;
+mod blake2b_compress;
 mod bls12381;
 mod bn254;
 mod ed25519;
@@ -7,6 +8,9 @@ mod io;
 mod keccak_permute;
 mod memory;
 mod secp256k1;
+mod secp256r1;
+mod sha512_compress;
+mod sha512_extend;
 mod sha_compress;
 mod sha_extend;
 mod sys;
@@ -16,6 +20,7 @@ mod unconstrained;
 mod verify;
 
 pub use bigint::*;
+pub use blake2b_compress::*;
 pub use bls12381::*;
 pub use bn254::*;
 pub use ed25519::*;
@@ -24,6 +29,9 @@ pub use io::*;
 pub use keccak_permute::*;
 pub use memory::*;
 pub use secp256k1::*;
+pub use secp256r1::*;
+pub use sha512_compress::*;
+pub use sha512_extend::*;
 pub use sha_compress::*;
 pub use sha_extend::*;
 pub use sys::*;
@@ -103,3 +111,57 @@ pub const BLS12381_ADD: u32 = 0x00_01_01_1E;
 
 /// Executes the `BLS12381_DOUBLE` precompile.
 pub const BLS12381_DOUBLE: u32 = 0x00_00_01_1F;
+
+/// Executes the `SHA512_EXTEND` precompile.
+pub const SHA512_EXTEND: u32 = 0x00_40_01_20;
+
+/// Executes the `SHA512_COMPRESS` precompile.
+pub const SHA512_COMPRESS: u32 = 0x00_01_01_21;
+
+/// Executes the `BLAKE2B_COMPRESS` precompile.
+pub const BLAKE2B_COMPRESS: u32 = 0x00_01_01_22;
+
+/// Executes `SECP256R1_ADD`.
+pub const SECP256R1_ADD: u32 = 0x00_01_01_23;
+
+/// Executes `SECP256R1_DOUBLE`.
+pub const SECP256R1_DOUBLE: u32 = 0x00_00_01_24;
+
+/// Executes `SECP256R1_DECOMPRESS`.
+pub const SECP256R1_DECOMPRESS: u32 = 0x00_00_01_25;
+
+/// Executes the `BN254_FP_ADD` precompile.
+pub const BN254_FP_ADD: u32 = 0x00_01_01_26;
+
+/// Executes the `BN254_FP_SUB` precompile.
+pub const BN254_FP_SUB: u32 = 0x00_01_01_27;
+
+/// Executes the `BN254_FP_MUL` precompile.
+pub const BN254_FP_MUL: u32 = 0x00_01_01_28;
+
+/// Executes the `BN254_FP2_ADD` precompile.
+pub const BN254_FP2_ADD: u32 = 0x00_01_01_29;
+
+/// Executes the `BN254_FP2_SUB` precompile.
+pub const BN254_FP2_SUB: u32 = 0x00_01_01_2A;
+
+/// Executes the `BN254_FP2_MUL` precompile.
+pub const BN254_FP2_MUL: u32 = 0x00_01_01_2B;
+
+/// Executes the `BLS12381_FP_ADD` precompile.
+pub const BLS12381_FP_ADD: u32 = 0x00_01_01_2C;
+
+/// Executes the `BLS12381_FP_SUB` precompile.
+pub const BLS12381_FP_SUB: u32 = 0x00_01_01_2D;
+
+/// Executes the `BLS12381_FP_MUL` precompile.
+pub const BLS12381_FP_MUL: u32 = 0x00_01_01_2E;
+
+/// Executes the `BLS12381_FP2_ADD` precompile.
+pub const BLS12381_FP2_ADD: u32 = 0x00_01_01_2F;
+
+/// Executes the `BLS12381_FP2_SUB` precompile.
+pub const BLS12381_FP2_SUB: u32 = 0x00_01_01_30;
+
+/// Executes the `BLS12381_FP2_MUL` precompile.
+pub const BLS12381_FP2_MUL: u32 = 0x00_01_01_31;
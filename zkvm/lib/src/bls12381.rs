@@ -1,6 +1,10 @@
 #![allow(unused_imports)]
 use crate::utils::CurveOperations;
-use crate::{syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double};
+use crate::{
+    syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double,
+    syscall_bls12381_fp2_addmod, syscall_bls12381_fp2_mulmod, syscall_bls12381_fp2_submod,
+    syscall_bls12381_fp_addmod, syscall_bls12381_fp_mulmod, syscall_bls12381_fp_submod,
+};
 
 use amcl::bls381::bls381::utils::deserialize_g1;
 use anyhow::Result;
@@ -61,3 +65,55 @@ pub fn decompress_pubkey(compressed_key: &[u8; 48]) -> Result<[u8; 96]> {
         }
     }
 }
+
+/// The number of words in a BLS12-381 base field element.
+pub const FP_NUM_WORDS: usize = 12;
+
+/// The number of words in an element `c0 + c1 * u` of the BLS12-381 quadratic extension field,
+/// stored as `c0` followed by `c1`.
+pub const FP2_NUM_WORDS: usize = 2 * FP_NUM_WORDS;
+
+// Field elements are represented as little-endian words, and must be reduced modulo the base field
+// modulus. The quadratic extension is defined by `u^2 = -1`.
+
+/// Sets `x = x + y` in the BLS12-381 base field.
+pub fn fp_add_assign(x: &mut [u32; FP_NUM_WORDS], y: &[u32; FP_NUM_WORDS]) {
+    unsafe {
+        syscall_bls12381_fp_addmod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x - y` in the BLS12-381 base field.
+pub fn fp_sub_assign(x: &mut [u32; FP_NUM_WORDS], y: &[u32; FP_NUM_WORDS]) {
+    unsafe {
+        syscall_bls12381_fp_submod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x * y` in the BLS12-381 base field.
+pub fn fp_mul_assign(x: &mut [u32; FP_NUM_WORDS], y: &[u32; FP_NUM_WORDS]) {
+    unsafe {
+        syscall_bls12381_fp_mulmod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x + y` in the BLS12-381 quadratic extension field.
+pub fn fp2_add_assign(x: &mut [u32; FP2_NUM_WORDS], y: &[u32; FP2_NUM_WORDS]) {
+    unsafe {
+        syscall_bls12381_fp2_addmod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x - y` in the BLS12-381 quadratic extension field.
+pub fn fp2_sub_assign(x: &mut [u32; FP2_NUM_WORDS], y: &[u32; FP2_NUM_WORDS]) {
+    unsafe {
+        syscall_bls12381_fp2_submod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x * y` in the BLS12-381 quadratic extension field.
+pub fn fp2_mul_assign(x: &mut [u32; FP2_NUM_WORDS], y: &[u32; FP2_NUM_WORDS]) {
+    unsafe {
+        syscall_bls12381_fp2_mulmod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
@@ -1,5 +1,9 @@
 use crate::utils::CurveOperations;
-use crate::{syscall_bn254_add, syscall_bn254_double};
+use crate::{
+    syscall_bn254_add, syscall_bn254_double, syscall_bn254_fp2_addmod, syscall_bn254_fp2_mulmod,
+    syscall_bn254_fp2_submod, syscall_bn254_fp_addmod, syscall_bn254_fp_mulmod,
+    syscall_bn254_fp_submod,
+};
 
 #[derive(Copy, Clone)]
 pub struct Bn254;
@@ -24,3 +28,55 @@ impl CurveOperations<NUM_WORDS> for Bn254 {
         }
     }
 }
+
+/// The number of words in a BN254 base field element.
+pub const FP_NUM_WORDS: usize = 8;
+
+/// The number of words in an element `c0 + c1 * u` of the BN254 quadratic extension field, stored
+/// as `c0` followed by `c1`.
+pub const FP2_NUM_WORDS: usize = 2 * FP_NUM_WORDS;
+
+// Field elements are represented as little-endian words, and must be reduced modulo the base field
+// modulus. The quadratic extension is defined by `u^2 = -1`.
+
+/// Sets `x = x + y` in the BN254 base field.
+pub fn fp_add_assign(x: &mut [u32; FP_NUM_WORDS], y: &[u32; FP_NUM_WORDS]) {
+    unsafe {
+        syscall_bn254_fp_addmod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x - y` in the BN254 base field.
+pub fn fp_sub_assign(x: &mut [u32; FP_NUM_WORDS], y: &[u32; FP_NUM_WORDS]) {
+    unsafe {
+        syscall_bn254_fp_submod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x * y` in the BN254 base field.
+pub fn fp_mul_assign(x: &mut [u32; FP_NUM_WORDS], y: &[u32; FP_NUM_WORDS]) {
+    unsafe {
+        syscall_bn254_fp_mulmod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x + y` in the BN254 quadratic extension field.
+pub fn fp2_add_assign(x: &mut [u32; FP2_NUM_WORDS], y: &[u32; FP2_NUM_WORDS]) {
+    unsafe {
+        syscall_bn254_fp2_addmod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x - y` in the BN254 quadratic extension field.
+pub fn fp2_sub_assign(x: &mut [u32; FP2_NUM_WORDS], y: &[u32; FP2_NUM_WORDS]) {
+    unsafe {
+        syscall_bn254_fp2_submod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
+
+/// Sets `x = x * y` in the BN254 quadratic extension field.
+pub fn fp2_mul_assign(x: &mut [u32; FP2_NUM_WORDS], y: &[u32; FP2_NUM_WORDS]) {
+    unsafe {
+        syscall_bn254_fp2_mulmod(x.as_mut_ptr(), y.as_ptr());
+    }
+}
@@ -7,6 +7,8 @@ pub mod bn254;
 pub mod io;
 #[cfg(feature = "secp256k1")]
 pub mod secp256k1;
+#[cfg(feature = "secp256r1")]
+pub mod secp256r1;
 pub mod unconstrained;
 pub mod utils;
 #[cfg(feature = "verify")]
@@ -28,6 +30,15 @@ extern "C" {
     /// Executes the SHA-256 compress operation on the given word array and a given state.
     pub fn syscall_sha256_compress(w: *mut u32, state: *mut u32);
 
+    /// Executes the SHA-512 extend operation on the given word array.
+    pub fn syscall_sha512_extend(w: *mut u64);
+
+    /// Executes the SHA-512 compress operation on the given word array and a given state.
+    pub fn syscall_sha512_compress(w: *mut u64, state: *mut u64);
+
+    /// Executes the BLAKE2b compression function on the given chaining value and block.
+    pub fn syscall_blake2b_compress(state: *mut u64, block: *const u64);
+
     /// Executes an Ed25519 curve addition on the given points.
     pub fn syscall_ed_add(p: *mut u32, q: *mut u32);
 
@@ -43,18 +54,63 @@ extern "C" {
     /// Executes an Secp256k1 curve decompression on the given point.
     pub fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool);
 
+    /// Executes a Secp256r1 curve addition on the given points.
+    pub fn syscall_secp256r1_add(p: *mut u32, q: *const u32);
+
+    /// Executes a Secp256r1 curve doubling on the given point.
+    pub fn syscall_secp256r1_double(p: *mut u32);
+
+    /// Executes a Secp256r1 curve decompression on the given point.
+    pub fn syscall_secp256r1_decompress(point: &mut [u8; 64], is_odd: bool);
+
     /// Executes a Bn254 curve addition on the given points.
     pub fn syscall_bn254_add(p: *mut u32, q: *const u32);
 
     /// Executes a Bn254 curve doubling on the given point.
     pub fn syscall_bn254_double(p: *mut u32);
 
+    /// Adds two BN254 base field elements.
+    pub fn syscall_bn254_fp_addmod(x: *mut u32, y: *const u32);
+
+    /// Subtracts two BN254 base field elements.
+    pub fn syscall_bn254_fp_submod(x: *mut u32, y: *const u32);
+
+    /// Multiplies two BN254 base field elements.
+    pub fn syscall_bn254_fp_mulmod(x: *mut u32, y: *const u32);
+
+    /// Adds two BN254 quadratic extension field elements.
+    pub fn syscall_bn254_fp2_addmod(x: *mut u32, y: *const u32);
+
+    /// Subtracts two BN254 quadratic extension field elements.
+    pub fn syscall_bn254_fp2_submod(x: *mut u32, y: *const u32);
+
+    /// Multiplies two BN254 quadratic extension field elements.
+    pub fn syscall_bn254_fp2_mulmod(x: *mut u32, y: *const u32);
+
     /// Executes a BLS12-381 curve addition on the given points.
     pub fn syscall_bls12381_add(p: *mut u32, q: *const u32);
 
     /// Executes a BLS12-381 curve doubling on the given point.
     pub fn syscall_bls12381_double(p: *mut u32);
 
+    /// Adds two BLS12-381 base field elements.
+    pub fn syscall_bls12381_fp_addmod(x: *mut u32, y: *const u32);
+
+    /// Subtracts two BLS12-381 base field elements.
+    pub fn syscall_bls12381_fp_submod(x: *mut u32, y: *const u32);
+
+    /// Multiplies two BLS12-381 base field elements.
+    pub fn syscall_bls12381_fp_mulmod(x: *mut u32, y: *const u32);
+
+    /// Adds two BLS12-381 quadratic extension field elements.
+    pub fn syscall_bls12381_fp2_addmod(x: *mut u32, y: *const u32);
+
+    /// Subtracts two BLS12-381 quadratic extension field elements.
+    pub fn syscall_bls12381_fp2_submod(x: *mut u32, y: *const u32);
+
+    /// Multiplies two BLS12-381 quadratic extension field elements.
+    pub fn syscall_bls12381_fp2_mulmod(x: *mut u32, y: *const u32);
+
     /// Executes the Keccak-256 permutation on the given state.
     pub fn syscall_keccak_permute(state: *mut u64);
 
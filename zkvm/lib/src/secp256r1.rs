@@ -0,0 +1,169 @@
+#![allow(unused)]
+
+use crate::utils::{AffinePoint, CurveOperations};
+use crate::{syscall_secp256r1_add, syscall_secp256r1_decompress, syscall_secp256r1_double};
+
+use anyhow::Context;
+use anyhow::{anyhow, Result};
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use p256::ecdsa::{Signature, VerifyingKey};
+use p256::elliptic_curve::ff::PrimeFieldBits;
+use p256::elliptic_curve::ops::{Invert, Reduce};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::{FieldBytes, PublicKey, Scalar, U256};
+
+const NUM_WORDS: usize = 16;
+
+#[derive(Copy, Clone)]
+pub struct Secp256r1Operations;
+
+impl CurveOperations<NUM_WORDS> for Secp256r1Operations {
+    // The values are taken from https://neuromancer.sk/std/secg/secp256r1.
+    const GENERATOR: [u32; NUM_WORDS] = [
+        3633889942, 4104206661, 770388896, 1996717441, 1671708914, 4173129445, 3777774151,
+        1796723186, 935285237, 3417718888, 1798397646, 734933847, 2081398294, 2397563722,
+        4263149467, 1340293858,
+    ];
+    fn add_assign(limbs: &mut [u32; NUM_WORDS], other: &[u32; NUM_WORDS]) {
+        unsafe {
+            syscall_secp256r1_add(limbs.as_mut_ptr(), other.as_ptr());
+        }
+    }
+
+    fn double(limbs: &mut [u32; NUM_WORDS]) {
+        unsafe {
+            syscall_secp256r1_double(limbs.as_mut_ptr());
+        }
+    }
+}
+
+/// Decompresses a compressed public key using secp256r1_decompress precompile.
+pub fn decompress_pubkey(compressed_key: &[u8; 33]) -> Result<[u8; 65]> {
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_os = "zkvm", target_vendor = "succinct"))] {
+            let mut decompressed_key: [u8; 64] = [0; 64];
+            decompressed_key[..32].copy_from_slice(&compressed_key[1..]);
+            let is_odd = match compressed_key[0] {
+                2 => false,
+                3 => true,
+                _ => return Err(anyhow!("Invalid compressed key")),
+            };
+            unsafe {
+                syscall_secp256r1_decompress(&mut decompressed_key, is_odd);
+            }
+
+            let mut result: [u8; 65] = [0; 65];
+            result[0] = 4;
+            result[1..].copy_from_slice(&decompressed_key);
+            Ok(result)
+        } else {
+            let public_key = PublicKey::from_sec1_bytes(compressed_key).context("invalid pubkey")?;
+            let bytes = public_key.to_encoded_point(false).to_bytes();
+            let mut result: [u8; 65] = [0; 65];
+            result.copy_from_slice(&bytes);
+            Ok(result)
+        }
+    }
+}
+
+/// Verifies a secp256r1 signature using the public key and the message hash. If the s_inverse is
+/// provided, it will be validated and used to verify the signature. Otherwise, the inverse of s
+/// will be computed and used.
+///
+/// Warning: this function does not check if the key is actually on the curve.
+pub fn verify_signature(
+    pubkey: &[u8; 65],
+    msg_hash: &[u8; 32],
+    signature: &Signature,
+    s_inverse: Option<&Scalar>,
+) -> bool {
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_os = "zkvm", target_vendor = "succinct"))] {
+            // The coordinates of the public key are base field elements, which may not fit in a
+            // scalar since the base field of secp256r1 is larger than its scalar field.
+            let mut pubkey_x_le_bytes: [u8; 32] = pubkey[1..33].try_into().unwrap();
+            pubkey_x_le_bytes.reverse();
+            let mut pubkey_y_le_bytes: [u8; 32] = pubkey[33..].try_into().unwrap();
+            pubkey_y_le_bytes.reverse();
+
+            // Convert the public key to an affine point
+            let affine = AffinePoint::<Secp256r1Operations, NUM_WORDS>::from(&pubkey_x_le_bytes, &pubkey_y_le_bytes);
+
+            const GENERATOR: AffinePoint<Secp256r1Operations, NUM_WORDS> = AffinePoint::<Secp256r1Operations, NUM_WORDS>::generator_in_affine();
+
+            let z = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(msg_hash));
+            let (r, s) = signature.split_scalars();
+            let computed_s_inv;
+            let s_inv = match s_inverse {
+                Some(s_inv) => {
+                    assert_eq!(s_inv * s.as_ref(), Scalar::ONE);
+                    s_inv
+                }
+                None => {
+                    computed_s_inv = s.invert();
+                    &computed_s_inv
+                }
+            };
+
+            let u1 = z * s_inv;
+            let u2 = *r * s_inv;
+
+            let res = double_and_add_base(&u1, &GENERATOR, &u2, &affine).unwrap();
+            let mut x_bytes_be = [0u8; 32];
+            for i in 0..8 {
+                x_bytes_be[i * 4..(i * 4) + 4].copy_from_slice(&res.limbs[i].to_le_bytes());
+            }
+            x_bytes_be.reverse();
+
+            *r == <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(&x_bytes_be))
+        } else {
+            let public_key = PublicKey::from_sec1_bytes(pubkey);
+            if public_key.is_err() {
+                return false;
+            }
+            let public_key = public_key.unwrap();
+
+            let verify_key = VerifyingKey::from(&public_key);
+            let res = verify_key
+                .verify_prehash(msg_hash, signature)
+                .context("invalid signature");
+
+            res.is_ok()
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+fn double_and_add_base(
+    a: &Scalar,
+    A: &AffinePoint<Secp256r1Operations, NUM_WORDS>,
+    b: &Scalar,
+    B: &AffinePoint<Secp256r1Operations, NUM_WORDS>,
+) -> Option<AffinePoint<Secp256r1Operations, NUM_WORDS>> {
+    let mut res: Option<AffinePoint<Secp256r1Operations, NUM_WORDS>> = None;
+    let mut temp_A = *A;
+    let mut temp_B = *B;
+
+    let a_bits = a.to_le_bits();
+    let b_bits = b.to_le_bits();
+    for (a_bit, b_bit) in a_bits.iter().zip(b_bits) {
+        if *a_bit {
+            match res.as_mut() {
+                Some(res) => res.add_assign(&temp_A),
+                None => res = Some(temp_A),
+            };
+        }
+
+        if b_bit {
+            match res.as_mut() {
+                Some(res) => res.add_assign(&temp_B),
+                None => res = Some(temp_B),
+            };
+        }
+
+        temp_A.double();
+        temp_B.double();
+    }
+
+    res
+}